@@ -1,9 +1,22 @@
 forc clean
 rm -rf ./spark-market-sdk/spark-market/out 
+rm -rf ./spark-market-sdk/spark-market/src
 rm -rf ./spark-registry-sdk/spark-registry/out 
+rm -rf ./spark-multisig-sdk/spark-multisig/out 
+rm -rf ./spark-proxy-sdk/spark-proxy/out 
+rm -rf ./spark-market-sdk/spark-proxy/out 
+rm -rf ./spark-registry-sdk/spark-proxy/out 
 forc build --release
 cp -r ./spark-market/out ./spark-market-sdk/spark-market/
+# The storage block of the source declares the storage field types checked on upgrades
+mkdir -p ./spark-market-sdk/spark-market/src
+cp ./spark-market/src/main.sw ./spark-market-sdk/spark-market/src/
 cp -r ./spark-registry/out ./spark-registry-sdk/spark-registry/
+mkdir -p ./spark-multisig-sdk/spark-multisig
+cp -r ./spark-multisig/out ./spark-multisig-sdk/spark-multisig/
+cp -r ./spark-proxy/out ./spark-proxy-sdk/spark-proxy/
+cp -r ./spark-proxy/out ./spark-market-sdk/spark-proxy/
+cp -r ./spark-proxy/out ./spark-registry-sdk/spark-proxy/
 
 cargo clean
 cargo build --release
\ No newline at end of file
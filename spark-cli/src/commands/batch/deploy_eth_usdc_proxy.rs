@@ -54,7 +54,7 @@ impl DeployEthUsdcProxyCommand {
 
         assert!(proxy.proxy_owner().await?.value == State::Initialized(wallet.address().into()));
 
-        let market = SparkMarketContract::new(proxy.contract_id().into(), wallet.clone()).await;
+        let market = SparkMarketContract::new(proxy.contract_id().into(), wallet.clone()).await?;
         let _ = market.initialize_ownership(wallet.address().into()).await?;
 
         let epoch = 4611686020157800000; // 11/01/2024
@@ -53,7 +53,7 @@ impl DeployEzethUsdcProxyCommand {
 
         assert!(proxy.proxy_owner().await?.value == State::Initialized(wallet.address().into()));
 
-        let market = SparkMarketContract::new(proxy.contract_id().into(), wallet.clone()).await;
+        let market = SparkMarketContract::new(proxy.contract_id().into(), wallet.clone()).await?;
         let _ = market.initialize_ownership(wallet.address().into()).await?;
 
         let epoch = 4611686020163100000; // 01/01/2025
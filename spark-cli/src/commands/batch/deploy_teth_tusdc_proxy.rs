@@ -63,7 +63,7 @@ impl DeployTethTusdcProxyCommand {
         let target: ContractId = contract.contract_id().into();
         let proxy = SparkProxyContract::deploy(target, wallet.clone()).await?;
 
-        let market = SparkMarketContract::new(proxy.contract_id().into(), wallet.clone()).await;
+        let market = SparkMarketContract::new(proxy.contract_id().into(), wallet.clone()).await?;
         let _ = market.initialize_ownership(wallet.address().into()).await?;
 
         let epoch = 4611686020157800000; // 11/01/2024
@@ -1,12 +1,22 @@
 use crate::commands::{
     batch::cli::BatchCommands, core::cli::CoreCommands, info::cli::InfoCommands,
-    registry::cli::RegistryCommands,
+    keystore::cli::KeystoreCommands, multisig::cli::MultisigCommands,
+    registry::cli::RegistryCommands, tui::command::TuiCommand,
 };
 use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(about = "")] // TODO: about
 pub(crate) struct Cli {
+    /// The profile of the config file to take the defaults from
+    #[clap(long, global = true)]
+    pub(crate) profile: Option<String>,
+
+    /// The encrypted JSON keystore to sign with, overrides the key source of the profile
+    #[clap(long, global = true)]
+    pub(crate) keystore: Option<PathBuf>,
+
     #[command(subcommand)]
     pub(crate) command: Command,
 }
@@ -25,9 +35,21 @@ pub(crate) enum Command {
     #[clap(short_flag = 'I')]
     Info(Info),
 
+    ///
+    #[clap(short_flag = 'K')]
+    Keystore(Keystore),
+
+    ///
+    #[clap(short_flag = 'M')]
+    Multisig(Multisig),
+
     ///
     #[clap(short_flag = 'R')]
     Registry(Registry),
+
+    ///
+    #[clap(short_flag = 'T')]
+    Tui(TuiCommand),
 }
 
 #[derive(Args, Clone)]
@@ -48,6 +70,18 @@ pub(crate) struct Info {
     pub(crate) commands: InfoCommands,
 }
 
+#[derive(Args, Clone)]
+pub(crate) struct Keystore {
+    #[clap(subcommand)]
+    pub(crate) commands: KeystoreCommands,
+}
+
+#[derive(Args, Clone)]
+pub(crate) struct Multisig {
+    #[clap(subcommand)]
+    pub(crate) commands: MultisigCommands,
+}
+
 #[derive(Args, Clone)]
 pub(crate) struct Registry {
     #[clap(subcommand)]
@@ -0,0 +1,25 @@
+use crate::utils::check_upgrade_build;
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(
+    about = "Compares the storage slots and the ABI of the deployed market implementation with a new build"
+)]
+pub(crate) struct CheckUpgradeCommand {
+    /// The build output directory of the deployed implementation
+    /// Ex. spark-market/out/release of the deployed release
+    #[clap(long)]
+    pub(crate) previous_build: PathBuf,
+
+    /// The build output directory of the new implementation
+    /// Defaults to the build the sdk deploys
+    #[clap(long)]
+    pub(crate) new_build: Option<PathBuf>,
+}
+
+impl CheckUpgradeCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        check_upgrade_build(&self.previous_build, self.new_build.as_deref(), false)
+    }
+}
@@ -1,11 +1,18 @@
 use crate::commands::core::{
-    cancel_order::CancelCommand, deploy::DeployCommand, deposit::DepositCommand,
-    deposit_for::DepositForCommand, fulfill_many::FulfillManyCommand, match_many::MatchManyCommand,
-    match_pair::MatchPairCommand, open_order::OpenCommand, set_epoch::SetEpochCommand,
+    cancel_order::CancelCommand, cancel_proxy_target::CancelProxyTargetCommand,
+    check_upgrade::CheckUpgradeCommand, deploy::DeployCommand, deposit::DepositCommand,
+    deposit_for::DepositForCommand, emergency_exit::EmergencyExitCommand,
+    enable_emergency::EnableEmergencyCommand, execute_proxy_target::ExecuteProxyTargetCommand,
+    fulfill_many::FulfillManyCommand, grant_role::GrantRoleCommand, match_many::MatchManyCommand,
+    match_pair::MatchPairCommand, open_order::OpenCommand,
+    propose_proxy_target::ProposeProxyTargetCommand, revoke_role::RevokeRoleCommand,
+    set_epoch::SetEpochCommand, set_maker_rebate::SetMakerRebateCommand,
     set_matcher_fee::SetMatcherFeeCommand, set_min_order_price::SetMinOrderPriceCommand,
     set_min_order_size::SetMinOrderSizeCommand, set_paused::SetPausedCommand,
     set_protocol_fee::SetProtocolFeeCommand, set_proxy_target::SetProxyTargetCommand,
-    set_store_order_change_info::SetStoreOrderChangeInfoCommand, withdraw::WithdrawCommand,
+    set_rolling_volume::SetRollingVolumeCommand,
+    set_store_order_change_info::SetStoreOrderChangeInfoCommand,
+    set_upgrade_delay::SetUpgradeDelayCommand, withdraw::WithdrawCommand,
     withdraw_to_market::WithdrawToMarketCommand,
 };
 use clap::Subcommand;
@@ -16,6 +23,14 @@ pub(crate) enum CoreCommands {
     #[clap(short_flag = 'C')]
     Cancel(CancelCommand),
 
+    /// Cancel the proposed proxy target
+    #[clap(short_flag = 'N')]
+    CancelProxyTarget(CancelProxyTargetCommand),
+
+    /// Check the compatibility of a new market implementation with the deployed one
+    #[clap(short_flag = 'K')]
+    CheckUpgrade(CheckUpgradeCommand),
+
     /// Deploy a new market contract
     #[clap(short_flag = 'D')]
     Deploy(DeployCommand),
@@ -28,10 +43,26 @@ pub(crate) enum CoreCommands {
     #[clap(short_flag = 'P')]
     Deposit(DepositCommand),
 
+    /// Cancel all orders and withdraw the whole balance from a market in emergency mode
+    #[clap(short_flag = 'I')]
+    EmergencyExit(EmergencyExitCommand),
+
+    /// Enable the emergency mode of the market
+    #[clap(short_flag = 'B')]
+    EnableEmergency(EnableEmergencyCommand),
+
+    /// Set the proposed proxy target once the upgrade delay has elapsed
+    #[clap(short_flag = 'J')]
+    ExecuteProxyTarget(ExecuteProxyTargetCommand),
+
     /// Fulfill multiple orders
     #[clap(short_flag = 'F')]
     FulfillMany(FulfillManyCommand),
 
+    /// Grant an administrative role of the market
+    #[clap(short_flag = 'G')]
+    GrantRole(GrantRoleCommand),
+
     /// Match multiple orders
     #[clap(short_flag = 'M')]
     MatchMany(MatchManyCommand),
@@ -44,10 +75,21 @@ pub(crate) enum CoreCommands {
     #[clap(short_flag = 'O')]
     Open(OpenCommand),
 
+    /// Propose a new proxy target behind the upgrade delay
+    #[clap(short_flag = 'Y')]
+    ProposeProxyTarget(ProposeProxyTargetCommand),
+
+    /// Revoke an administrative role of the market
+    #[clap(short_flag = 'H')]
+    RevokeRole(RevokeRoleCommand),
+
     /// Set a protocol fee
     #[clap(short_flag = 'E')]
     SetEpoch(SetEpochCommand),
 
+    /// Set the maker rebates of the protocol fee tiers
+    SetMakerRebate(SetMakerRebateCommand),
+
     /// Set a minimum order price for the market
     #[clap(short_flag = 'P')]
     SetMinOrderPrice(SetMinOrderPriceCommand),
@@ -64,10 +106,18 @@ pub(crate) enum CoreCommands {
     #[clap(short_flag = 'T')]
     SetMatcherFee(SetMatcherFeeCommand),
 
+    /// Set a rolling window user volume accounting for the market
+    #[clap(short_flag = 'R')]
+    SetRollingVolume(SetRollingVolumeCommand),
+
     /// Set a matcher fee for the market
     #[clap(short_flag = 'U')]
     SetStoreOrderChangeInfo(SetStoreOrderChangeInfoCommand),
 
+    /// Set the delay between proposing and executing a proxy target
+    #[clap(short_flag = 'L')]
+    SetUpgradeDelay(SetUpgradeDelayCommand),
+
     /// Set a minimum order size for the market
     #[clap(short_flag = 'V')]
     SetMinOrderSize(SetMinOrderSizeCommand),
@@ -1,4 +1,4 @@
-use crate::utils::setup;
+use crate::utils::{rpc_url, setup};
 use clap::Args;
 use fuels::{accounts::ViewOnlyAccount, types::AssetId};
 use spark_market_sdk::SparkMarketContract;
@@ -29,13 +29,14 @@ pub(crate) struct DeployCommand {
 
     /// The URL to deploy to
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl DeployCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
 
         if self.base_asset.len() as u64 != 66 {
             anyhow::bail!("Invalid base asset length");
@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id, AccountType, AssetType};
+use crate::utils::{market_id, print_estimate, rpc_url, setup, AccountType, AssetType};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use fuels::types::{Address, ContractId, Identity};
@@ -24,20 +24,27 @@ pub(crate) struct DepositForCommand {
     #[clap(long)]
     pub(crate) account_type: AccountType,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl DepositForCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         let asset_type = match self.asset_type {
             AssetType::Base => ContractAssetType::Base,
@@ -61,7 +68,7 @@ impl DepositForCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
 
         let config = contract.config().await?.value;
         let asset = if asset_type == ContractAssetType::Base {
@@ -71,6 +78,14 @@ impl DepositForCommand {
         };
         let asset_balance = wallet.get_asset_balance(&asset).await?;
 
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.deposit_for_call_handler(self.amount, asset, account))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
         let _ = contract.deposit_for(self.amount, asset, account).await?;
 
         // Balance post-call
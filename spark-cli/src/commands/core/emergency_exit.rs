@@ -0,0 +1,73 @@
+use crate::utils::{market_id, print_estimate, rpc_url, setup};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, types::Identity};
+use spark_market_sdk::SparkMarketContract;
+
+#[derive(Args, Clone)]
+#[command(
+    about = "Cancels all of the wallet orders and withdraws its whole balance from a market in emergency mode"
+)]
+pub(crate) struct EmergencyExitCommand {
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl EmergencyExitCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+        if !contract.emergency().await?.value {
+            anyhow::bail!("Market 0x{} is not in emergency mode", contract_id);
+        }
+
+        let user: Identity = wallet.address().into();
+        let account = contract.account(user).await?.value;
+        let orders = contract.user_orders_count(user).await?.value;
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.emergency_exit_call_handler())
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.emergency_exit().await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nCancelled {} orders", orders);
+        println!(
+            "Withdrawn {} base and {} quote",
+            account.liquid.base + account.locked.base,
+            account.liquid.quote + account.locked.quote
+        );
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
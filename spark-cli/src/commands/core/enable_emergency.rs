@@ -0,0 +1,70 @@
+use crate::utils::{market_id, print_estimate, propose_to_multisig, rpc_url, setup};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::SparkMarketContract;
+
+#[derive(Args, Clone)]
+#[command(
+    about = "Enables the emergency mode, disabling deposits, orders and matching of the market for good"
+)]
+pub(crate) struct EnableEmergencyCommand {
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl EnableEmergencyCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if let Some(multisig) = &self.multisig {
+            let call_handler = contract.enable_emergency_call_handler();
+            return propose_to_multisig(multisig, &wallet, &call_handler).await;
+        }
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.enable_emergency_call_handler())
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.enable_emergency().await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nEmergency mode enabled, users leave the market with `core emergency-exit`");
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
-use crate::utils::{setup, validate_contract_id, /*AssetType,*/ LimitType, OrderType};
+use crate::utils::{
+    market_id, print_estimate, rpc_url, setup, /*AssetType,*/ LimitType, OrderType,
+};
 use clap::Args;
 use fuels::{
     accounts::ViewOnlyAccount,
@@ -40,20 +42,27 @@ pub(crate) struct FulfillManyCommand {
     #[clap(long)]
     pub(crate) orders: Vec<String>,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) dry_run: bool,
 }
 
 impl FulfillManyCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         if self.orders.len() == 0 {
             anyhow::bail!("Invalid order array length == 0");
@@ -79,7 +88,22 @@ impl FulfillManyCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.fulfill_many_call_handler(
+                    self.amount,
+                    order_type.clone(),
+                    limit_type.clone(),
+                    self.price,
+                    self.slippage,
+                    order_ids,
+                ))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         let order_id = contract
             .fulfill_many(
@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::utils::{market_id, print_estimate, rpc_url, setup};
 use clap::Args;
 use fuels::{accounts::ViewOnlyAccount, types::Bits256};
 use spark_market_sdk::SparkMarketContract;
@@ -10,20 +10,27 @@ pub(crate) struct MatchPairCommand {
     #[clap(long)]
     pub(crate) orders: Vec<String>,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl MatchPairCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         if self.orders.len() != 2 {
             anyhow::bail!("Invalid order array length <> 2");
@@ -40,7 +47,15 @@ impl MatchPairCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.match_order_pair_call_handler(order_ids[0], order_ids[1]))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         let _ = contract
             .match_order_pair(order_ids[0], order_ids[1])
@@ -1,19 +1,30 @@
 pub(crate) mod cancel_order;
+pub(crate) mod cancel_proxy_target;
+pub(crate) mod check_upgrade;
 pub(crate) mod cli;
 pub(crate) mod deploy;
 pub(crate) mod deposit;
 pub(crate) mod deposit_for;
+pub(crate) mod emergency_exit;
+pub(crate) mod enable_emergency;
+pub(crate) mod execute_proxy_target;
 pub(crate) mod fulfill_many;
+pub(crate) mod grant_role;
 pub(crate) mod match_many;
 pub(crate) mod match_pair;
 pub(crate) mod open_order;
+pub(crate) mod propose_proxy_target;
+pub(crate) mod revoke_role;
 pub(crate) mod set_epoch;
+pub(crate) mod set_maker_rebate;
 pub(crate) mod set_matcher_fee;
 pub(crate) mod set_min_order_price;
 pub(crate) mod set_min_order_size;
 pub(crate) mod set_paused;
 pub(crate) mod set_protocol_fee;
 pub(crate) mod set_proxy_target;
+pub(crate) mod set_rolling_volume;
 pub(crate) mod set_store_order_change_info;
+pub(crate) mod set_upgrade_delay;
 pub(crate) mod withdraw;
 pub(crate) mod withdraw_to_market;
@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id, /*AssetType,*/ OrderType};
+use crate::utils::{market_id, print_estimate, rpc_url, setup, /*AssetType,*/ OrderType};
 use clap::Args;
 use fuels::{accounts::ViewOnlyAccount, types::ContractId};
 use spark_market_sdk::{
@@ -24,20 +24,27 @@ pub(crate) struct OpenCommand {
     #[clap(long)]
     pub(crate) price: u64,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl OpenCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         let order_type = match self.order_type {
             OrderType::Buy => ContractOrderType::Buy,
@@ -50,7 +57,19 @@ impl OpenCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.open_order_call_handler(
+                    self.amount,
+                    order_type.clone(),
+                    self.price,
+                ))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         let order_id = contract
             .open_order(self.amount, order_type.clone(), self.price)
@@ -0,0 +1,104 @@
+use crate::utils::{
+    check_upgrade_required, market_id, print_estimate, propose_to_multisig, rpc_url, setup,
+    validate_contract_id,
+};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
+use spark_proxy_sdk::SparkProxyContract;
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Propose a new proxy target, executable once the upgrade delay has elapsed")]
+pub(crate) struct ProposeProxyTargetCommand {
+    /// The target to propose
+    #[clap(long)]
+    pub(crate) target: String,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// The build output directory of the deployed implementation to check the target against
+    /// Required unless --force is passed
+    #[clap(long)]
+    pub(crate) previous_build: Option<PathBuf>,
+
+    /// The build output directory of the target, defaults to the build the sdk deploys
+    #[clap(long, requires = "previous_build")]
+    pub(crate) new_build: Option<PathBuf>,
+
+    /// Proposes the target even when the upgrade check finds breaking changes or without the check
+    #[clap(long)]
+    pub(crate) force: bool,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl ProposeProxyTargetCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+        let target = validate_contract_id(&self.target)?;
+
+        check_upgrade_required(
+            self.previous_build.as_deref(),
+            self.new_build.as_deref(),
+            self.force,
+        )?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkProxyContract::new(contract_id, wallet.clone()).await;
+
+        if let Some(multisig) = &self.multisig {
+            return propose_to_multisig(
+                multisig,
+                &wallet,
+                &contract.propose_proxy_target_call_handler(target),
+            )
+            .await;
+        }
+
+        if self.dry_run {
+            let estimate =
+                estimate_call(contract.propose_proxy_target_call_handler(target)).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.propose_proxy_target(target).await?;
+        let pending = contract.pending_proxy_target().await?.value;
+
+        // Balance post-deployment
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nThe proxy target {:?} has been proposed", self.target);
+        if let Some(pending) = pending {
+            println!("Executable at: {}", pending.executable_at);
+        }
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
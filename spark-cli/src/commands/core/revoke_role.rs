@@ -0,0 +1,101 @@
+use crate::utils::{
+    market_id, print_estimate, propose_to_multisig, rpc_url, setup, AccountType, Role,
+};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use fuels::types::{Address, ContractId, Identity};
+use spark_market_sdk::{Role as ContractRole, SparkMarketContract};
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Revokes an administrative role of the market from an account")]
+pub(crate) struct RevokeRoleCommand {
+    /// The role of the market administration
+    #[clap(long)]
+    pub(crate) role: Role,
+
+    /// The b256 id of the account
+    #[clap(long)]
+    pub(crate) account_id: String,
+
+    /// The type of account
+    #[clap(long)]
+    pub(crate) account_type: AccountType,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl RevokeRoleCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+
+        let role = match self.role {
+            Role::Pauser => ContractRole::Pauser,
+            Role::FeeManager => ContractRole::FeeManager,
+            Role::ParameterManager => ContractRole::ParameterManager,
+        };
+
+        let account = match self.account_type {
+            AccountType::Address => {
+                let address = Address::from_str(&self.account_id).expect("Invalid address");
+                Identity::Address(address)
+            }
+            AccountType::Contract => {
+                let address = ContractId::from_str(&self.account_id).expect("Invalid contract id");
+                Identity::ContractId(address)
+            }
+        };
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if let Some(multisig) = &self.multisig {
+            let call_handler = contract.revoke_role_call_handler(role, account);
+            return propose_to_multisig(multisig, &wallet, &call_handler).await;
+        }
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.revoke_role_call_handler(role, account))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.revoke_role(role.clone(), account).await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nRole {:?} revoked from: {}", role, self.account_id);
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
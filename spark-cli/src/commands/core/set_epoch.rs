@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::utils::{market_id, print_estimate, rpc_url, setup};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use spark_market_sdk::SparkMarketContract;
@@ -14,20 +14,27 @@ pub(crate) struct SetEpochCommand {
     #[clap(long)]
     pub(crate) epoch_duration: u64,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl SetEpochCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
@@ -35,7 +42,15 @@ impl SetEpochCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.set_epoch_call_handler(self.epoch, self.epoch_duration))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         let _ = contract.set_epoch(self.epoch, self.epoch_duration).await?;
 
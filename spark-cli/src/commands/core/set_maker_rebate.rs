@@ -0,0 +1,76 @@
+use crate::utils::{market_id, print_estimate, propose_to_multisig, rpc_url, setup};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::SparkMarketContract;
+
+#[derive(Args, Clone)]
+#[command(about = "Change the maker rebates of the protocol fee tiers")]
+pub(crate) struct SetMakerRebateCommand {
+    /// The maker rebate of each protocol fee tier, in the tier order
+    #[clap(long)]
+    pub(crate) rebate: Vec<u64>,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl SetMakerRebateCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if let Some(multisig) = &self.multisig {
+            return propose_to_multisig(
+                multisig,
+                &wallet,
+                &contract.set_maker_rebate_call_handler(self.rebate.clone()),
+            )
+            .await;
+        }
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.set_maker_rebate_call_handler(self.rebate.clone()))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.set_maker_rebate(self.rebate.clone()).await?;
+
+        // Balance post-deployment
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nThe maker rebates have been set to: {:?}", self.rebate);
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::utils::{market_id, print_estimate, propose_to_multisig, rpc_url, setup};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use spark_market_sdk::SparkMarketContract;
@@ -10,20 +10,31 @@ pub(crate) struct SetPausedCommand {
     #[clap(long)]
     pub(crate) paused: bool,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl SetPausedCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
@@ -31,7 +42,22 @@ impl SetPausedCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        let call_handler = if self.paused {
+            contract.pause_call_handler()
+        } else {
+            contract.unpause_call_handler()
+        };
+        if let Some(multisig) = &self.multisig {
+            return propose_to_multisig(multisig, &wallet, &call_handler).await;
+        }
+
+        if self.dry_run {
+            let estimate = contract.estimate(call_handler).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         if self.paused {
             let _ = contract.pause().await?;
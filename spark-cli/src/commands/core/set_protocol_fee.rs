@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::utils::{market_id, print_estimate, propose_to_multisig, rpc_url, setup};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use spark_market_sdk::{ProtocolFee, SparkMarketContract};
@@ -6,24 +6,35 @@ use spark_market_sdk::{ProtocolFee, SparkMarketContract};
 #[derive(Args, Clone)]
 #[command(about = "Change the protocol fee")]
 pub(crate) struct SetProtocolFeeCommand {
-    /// The fee to set
+    /// The fee tier to set as "maker_fee,taker_fee,volume_threshold"
     #[clap(long)]
     pub(crate) fee: Vec<String>,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl SetProtocolFeeCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
 
         let mut protocol_fee: Vec<ProtocolFee> = Vec::new();
         for fee in self.fee.clone() {
@@ -47,7 +58,24 @@ impl SetProtocolFeeCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+
+        if let Some(multisig) = &self.multisig {
+            return propose_to_multisig(
+                multisig,
+                &wallet,
+                &contract.set_protocol_fee_call_handler(protocol_fee.clone()),
+            )
+            .await;
+        }
+
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.set_protocol_fee_call_handler(protocol_fee))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         let _ = contract.set_protocol_fee(protocol_fee).await?;
 
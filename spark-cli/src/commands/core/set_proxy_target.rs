@@ -1,7 +1,12 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::utils::{
+    check_upgrade_required, market_id, print_estimate, propose_to_multisig, rpc_url, setup,
+    validate_contract_id,
+};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
 use spark_proxy_sdk::SparkProxyContract;
+use std::path::PathBuf;
 
 #[derive(Args, Clone)]
 #[command(about = "Change the proxy target")]
@@ -10,22 +15,52 @@ pub(crate) struct SetProxyTargetCommand {
     #[clap(long)]
     pub(crate) target: String,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// The build output directory of the deployed implementation to check the target against
+    /// Required unless --force is passed
+    #[clap(long)]
+    pub(crate) previous_build: Option<PathBuf>,
+
+    /// The build output directory of the target, defaults to the build the sdk deploys
+    #[clap(long, requires = "previous_build")]
+    pub(crate) new_build: Option<PathBuf>,
+
+    /// Sets the target even when the upgrade check finds breaking changes or without the check
+    #[clap(long)]
+    pub(crate) force: bool,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl SetProxyTargetCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
         let target = validate_contract_id(&self.target)?;
 
+        check_upgrade_required(
+            self.previous_build.as_deref(),
+            self.new_build.as_deref(),
+            self.force,
+        )?;
+
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
             .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
@@ -34,6 +69,21 @@ impl SetProxyTargetCommand {
         // Connect to the deployed contract via the rpc
         let contract = SparkProxyContract::new(contract_id, wallet.clone()).await;
 
+        if let Some(multisig) = &self.multisig {
+            return propose_to_multisig(
+                multisig,
+                &wallet,
+                &contract.set_proxy_target_call_handler(target),
+            )
+            .await;
+        }
+
+        if self.dry_run {
+            let estimate = estimate_call(contract.set_proxy_target_call_handler(target)).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
         let _ = contract.set_proxy_target(target).await?;
 
         // Balance post-deployment
@@ -0,0 +1,67 @@
+use crate::utils::{market_id, print_estimate, rpc_url, setup};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
+use spark_proxy_sdk::SparkProxyContract;
+
+#[derive(Args, Clone)]
+#[command(about = "Change the delay between proposing and executing a proxy target")]
+pub(crate) struct SetUpgradeDelayCommand {
+    /// The delay to set in seconds, a shorter delay takes effect after the current one
+    #[clap(long)]
+    pub(crate) delay: u64,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl SetUpgradeDelayCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkProxyContract::new(contract_id, wallet.clone()).await;
+
+        if self.dry_run {
+            let estimate =
+                estimate_call(contract.set_upgrade_delay_call_handler(self.delay)).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.set_upgrade_delay(self.delay).await?;
+
+        // Balance post-deployment
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nThe upgrade delay has been set to: {}", self.delay);
+        println!(
+            "Current upgrade delay: {}",
+            contract.upgrade_delay().await?.value
+        );
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
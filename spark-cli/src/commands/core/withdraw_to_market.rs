@@ -1,4 +1,4 @@
-use crate::utils::{setup, validate_contract_id, AssetType};
+use crate::utils::{market_id, print_estimate, rpc_url, setup, AssetType};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use spark_market_sdk::{AssetType as ContractAssetType, SparkMarketContract};
@@ -18,21 +18,28 @@ pub(crate) struct WithdrawToMarketCommand {
     #[clap(long)]
     pub(crate) market_id: String,
 
-    /// The contract id of the withdraw market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl WithdrawToMarketCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
-        let market_id = validate_contract_id(&self.market_id)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+        let market_id = market_id(Some(&self.market_id), wallet.provider().unwrap()).await?;
 
         let asset_type = match self.asset_type {
             AssetType::Base => ContractAssetType::Base,
@@ -45,7 +52,7 @@ impl WithdrawToMarketCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
         let config = contract.config().await?.value;
         let asset = if asset_type == ContractAssetType::Base {
             config.0
@@ -54,6 +61,18 @@ impl WithdrawToMarketCommand {
         };
         let asset_balance = wallet.get_asset_balance(&asset).await?;
 
+        if self.dry_run {
+            let estimate = contract
+                .estimate(contract.withdraw_to_market_call_handler(
+                    self.amount,
+                    asset_type.clone(),
+                    &market_id.into(),
+                ))
+                .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
         let _ = contract
             .withdraw_to_market(self.amount, asset_type.clone(), &market_id.into())
             .await?;
@@ -1,8 +1,13 @@
-use crate::utils::{setup, validate_contract_id, AccountType};
+use crate::{
+    config::OutputFormat,
+    utils::{account_json, connect, market_id, output_format, rpc_url, AccountType},
+};
 use clap::Args;
-use fuels::accounts::ViewOnlyAccount;
-use fuels::types::{Address, ContractId, Identity};
-use spark_market_sdk::SparkMarketContract;
+use fuels::types::{
+    bech32::{Bech32Address, Bech32ContractId},
+    Address, ContractId, Identity,
+};
+use spark_market_sdk::SparkMarketReader;
 use std::str::FromStr;
 
 #[derive(Args, Clone)]
@@ -16,39 +21,67 @@ pub(crate) struct AccountCommand {
     #[clap(long)]
     pub(crate) account_type: AccountType,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl AccountCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkMarketReader::read_only(contract_id, provider.clone()).await?;
 
-        let account = match self.account_type {
+        let identity = match self.account_type {
             AccountType::Address => {
                 let address = Address::from_str(&self.account_id).expect("Invalid address");
-                contract.account(Identity::Address(address)).await?.value
+                Identity::Address(address)
             }
             AccountType::Contract => {
                 let address = ContractId::from_str(&self.account_id).expect("Invalid contract id");
-                contract.account(Identity::ContractId(address)).await?.value
+                Identity::ContractId(address)
             }
         };
+        let account = contract.account(identity).await?.value;
 
-        let balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+        let base_asset_id = *provider.base_asset_id();
+        let account_balance = match identity {
+            Identity::Address(address) => {
+                provider
+                    .get_asset_balance(&Bech32Address::from(address), base_asset_id)
+                    .await?
+            }
+            Identity::ContractId(id) => {
+                provider
+                    .get_contract_asset_balance(&Bech32ContractId::from(id), base_asset_id)
+                    .await?
+            }
+        };
+        let contract_balance = provider
+            .get_contract_asset_balance(contract.contract_id(), base_asset_id)
             .await?;
-        println!("\nContract base asset balance: {}", balance);
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "account_base_asset_balance": account_balance,
+                "contract_base_asset_balance": contract_balance,
+                "account": account_json(&account),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("\nAccount base asset balance: {}", account_balance);
+        println!("Contract base asset balance: {}", contract_balance);
 
         println!("\n{:#?}", account);
 
@@ -0,0 +1,151 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, identity_json, market_id, output_format, rpc_url},
+};
+use anyhow::Context;
+use clap::Args;
+use fuels::types::{Address, ContractId, Identity};
+use serde_json::Value;
+use spark_market_sdk::{audit, AuditIssue, SparkMarketReader};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[derive(Args, Clone)]
+#[command(
+    about = "Reconciles the account balances and the open order locks of a market with its asset balances"
+)]
+pub(crate) struct AuditCommand {
+    /// The JSON snapshot of the market users, taken from an indexer or the deposit and trade events
+    /// Ex. [{"Address": "0x..."}, {"ContractId": "0x..."}]
+    #[clap(long)]
+    pub(crate) users: Option<PathBuf>,
+
+    /// The b256 address of a user to audit, in addition to the snapshot
+    #[clap(long)]
+    pub(crate) user: Vec<String>,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl AuditCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        let mut users = match &self.users {
+            Some(path) => read_users(path)?,
+            None => Vec::new(),
+        };
+        for user in self.user.iter() {
+            users.push(Identity::Address(
+                Address::from_str(user).map_err(|_| anyhow::anyhow!("Invalid address {}", user))?,
+            ));
+        }
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
+
+        let report = audit(&contract, &users).await?;
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "solvent": report.is_solvent(),
+                "users": report.users,
+                "orders": report.orders,
+                "assets": report.assets.iter().map(|(asset, asset_audit)| {
+                    serde_json::json!({
+                        "asset": format!("0x{}", asset),
+                        "liquid": asset_audit.liquid,
+                        "locked": asset_audit.locked,
+                        "orders_locked": asset_audit.orders_locked,
+                        "contract": asset_audit.contract,
+                    })
+                }).collect::<Vec<_>>(),
+                "issues": report.issues.iter().map(|issue| {
+                    serde_json::json!({
+                        "breaking": issue.is_breaking(),
+                        "user": issue_user(issue).map(identity_json),
+                        "message": issue.to_string(),
+                    })
+                }).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        } else {
+            println!(
+                "\nAudited {} users with {} open orders",
+                report.users, report.orders
+            );
+            for (asset, asset_audit) in report.assets.iter() {
+                println!(
+                    "0x{} liquid: {} locked: {} orders locked: {} contract: {}",
+                    asset,
+                    asset_audit.liquid,
+                    asset_audit.locked,
+                    asset_audit.orders_locked,
+                    asset_audit.contract
+                );
+            }
+            for issue in report.issues.iter() {
+                let level = if issue.is_breaking() {
+                    "Error"
+                } else {
+                    "Warning"
+                };
+                println!("{}: {}", level, issue);
+            }
+        }
+
+        if !report.is_solvent() {
+            anyhow::bail!("Market 0x{} failed the audit", contract_id);
+        }
+        println!("\nMarket 0x{} is solvent", contract_id);
+
+        Ok(())
+    }
+}
+
+/// Reads the users of a snapshot in the `{"Address": "0x..."}` format the json output uses
+fn read_users(path: &Path) -> anyhow::Result<Vec<Identity>> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let users: Vec<Value> =
+        serde_json::from_str(&json).with_context(|| format!("Invalid {}", path.display()))?;
+    users
+        .iter()
+        .map(|user| {
+            if let Some(address) = user.get("Address").and_then(Value::as_str) {
+                Ok(Identity::Address(Address::from_str(address).map_err(
+                    |_| anyhow::anyhow!("Invalid address {}", address),
+                )?))
+            } else if let Some(contract_id) = user.get("ContractId").and_then(Value::as_str) {
+                Ok(Identity::ContractId(
+                    ContractId::from_str(contract_id)
+                        .map_err(|_| anyhow::anyhow!("Invalid contract id {}", contract_id))?,
+                ))
+            } else {
+                anyhow::bail!("Invalid user {}", user)
+            }
+        })
+        .collect()
+}
+
+fn issue_user(issue: &AuditIssue) -> Option<&Identity> {
+    match issue {
+        AuditIssue::LockShortfall { user, .. }
+        | AuditIssue::LockSurplus { user, .. }
+        | AuditIssue::OrderNotFound { user, .. } => Some(user),
+        AuditIssue::Insolvent { .. } | AuditIssue::Surplus { .. } => None,
+    }
+}
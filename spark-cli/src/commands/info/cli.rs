@@ -1,12 +1,15 @@
 use crate::commands::info::{
-    account::AccountCommand, config::ConfigCommand, epoch::EpochCommand,
+    account::AccountCommand, audit::AuditCommand, config::ConfigCommand,
+    emergency::EmergencyCommand, epoch::EpochCommand, has_role::HasRoleCommand,
     matcher_fee::MatcherFeeCommand, min_order_price::MinOrderPriceCommand,
     min_order_size::MinOrderSizeCommand, order::OrderCommand, order_id::OrderIdCommand,
-    paused::PausedCommand, protocol_fee::ProtocolFeeCommand,
-    protocol_fee_user::ProtocolFeeUserCommand,
+    orders::OrdersCommand, paused::PausedCommand, portfolio::PortfolioCommand,
+    protocol_fee::ProtocolFeeCommand, protocol_fee_user::ProtocolFeeUserCommand,
     protocol_fee_user_amount::ProtocolFeeUserAmountCommand, proxy_owner::ProxyOwnerCommand,
-    proxy_target::ProxyTargetCommand, store_order_change_info::StoreOrderChangeInfoCommand,
-    user_orders::UserOrdersCommand,
+    proxy_target::ProxyTargetCommand, proxy_upgrade::ProxyUpgradeCommand,
+    rolling_volume::RollingVolumeCommand, store_order_change_info::StoreOrderChangeInfoCommand,
+    user_fee_tier::UserFeeTierCommand, user_orders::UserOrdersCommand,
+    user_volume::UserVolumeCommand,
 };
 use clap::Subcommand;
 
@@ -16,14 +19,26 @@ pub(crate) enum InfoCommands {
     #[clap(short_flag = 'A')]
     Account(AccountCommand),
 
+    /// Reconcile the account balances and order locks with the market asset balances
+    #[clap(short_flag = 'X')]
+    Audit(AuditCommand),
+
     /// Query configuration information for a market contract
     #[clap(short_flag = 'C')]
     Config(ConfigCommand),
 
+    /// Query the emergency mode
+    #[clap(short_flag = 'D')]
+    Emergency(EmergencyCommand),
+
     /// Query epoch information of the market contract
     #[clap(short_flag = 'W')]
     Epoch(EpochCommand),
 
+    /// Query whether an account has an administrative role
+    #[clap(short_flag = 'K')]
+    HasRole(HasRoleCommand),
+
     /// Query protocol fee information
     #[clap(short_flag = 'F')]
     ProtocolFee(ProtocolFeeCommand),
@@ -56,22 +71,46 @@ pub(crate) enum InfoCommands {
     #[clap(short_flag = 'O')]
     Order(OrderCommand),
 
+    /// Query all open orders of a user
+    #[clap(short_flag = 'Q')]
+    Orders(OrdersCommand),
+
     /// Query paused market state
     #[clap(short_flag = 'P')]
     Paused(PausedCommand),
 
+    /// Query balances and open orders of a user across the registered markets
+    #[clap(short_flag = 'H')]
+    Portfolio(PortfolioCommand),
+
     /// Query orders associated with an
     #[clap(short_flag = 'S')]
     StoreOrderChangeInfo(StoreOrderChangeInfoCommand),
 
+    /// Query rolling volume market state
+    #[clap(short_flag = 'R')]
+    RollingVolume(RollingVolumeCommand),
+
     /// Query information for a proxy target
     #[clap(short_flag = 'T')]
     ProxyTarget(ProxyTargetCommand),
 
+    /// Query the upgrade delay and the proposed target of a proxy
+    #[clap(short_flag = 'Y')]
+    ProxyUpgrade(ProxyUpgradeCommand),
+
     /// Query orders associated with an
     #[clap(short_flag = 'U')]
     UserOrders(UserOrdersCommand),
 
+    /// Query protocol fee tier of a user
+    #[clap(short_flag = 'G')]
+    UserFeeTier(UserFeeTierCommand),
+
+    /// Query trade volume of a user
+    #[clap(short_flag = 'L')]
+    UserVolume(UserVolumeCommand),
+
     /// Query information for a proxy owner
     #[clap(short_flag = 'V')]
     ProxyOwner(ProxyOwnerCommand),
@@ -1,27 +1,32 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::{
+    config::OutputFormat,
+    utils::{connect, identity_json, market_id, output_format, rpc_url},
+};
 use clap::Args;
-use spark_market_sdk::SparkMarketContract;
+use spark_market_sdk::SparkMarketReader;
 
 #[derive(Args, Clone)]
 #[command(about = "Query the market for its configurable variables")]
 pub(crate) struct ConfigCommand {
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl ConfigCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet).await;
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
 
         let (
             base_asset,
@@ -33,6 +38,20 @@ impl ConfigCommand {
             version,
         ) = contract.config().await?.value;
 
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "base_asset": format!("0x{}", base_asset),
+                "base_asset_decimals": base_asset_decimals,
+                "quote_asset": format!("0x{}", quote_asset),
+                "quote_asset_decimals": quote_asset_decimals,
+                "owner": owner.as_ref().map(identity_json),
+                "price_decimals": price_decimals,
+                "version": contract.contract_str_version().await?,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
         println!("\nBase Asset: 0x{}", base_asset);
         println!("Base Asset Decimals: {}", base_asset_decimals);
         println!("Quote Asset: 0x{}", quote_asset);
@@ -0,0 +1,34 @@
+use crate::utils::{connect, market_id, rpc_url};
+use clap::Args;
+use spark_market_sdk::SparkMarketReader;
+
+#[derive(Args, Clone)]
+#[command(about = "Query the emergency mode")]
+pub(crate) struct EmergencyCommand {
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl EmergencyCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
+
+        let emergency = contract.emergency().await?.value;
+
+        println!("Emergency: {:?}", emergency);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, market_id, output_format, rpc_url, AccountType, Role},
+};
+use clap::Args;
+use fuels::types::{Address, ContractId, Identity};
+use spark_market_sdk::{Role as ContractRole, SparkMarketReader};
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Query whether an account was granted an administrative role")]
+pub(crate) struct HasRoleCommand {
+    /// The role of the market administration
+    #[clap(long)]
+    pub(crate) role: Role,
+
+    /// The b256 id of the account
+    #[clap(long)]
+    pub(crate) account_id: String,
+
+    /// The type of account
+    #[clap(long)]
+    pub(crate) account_type: AccountType,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl HasRoleCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
+
+        let role = match self.role {
+            Role::Pauser => ContractRole::Pauser,
+            Role::FeeManager => ContractRole::FeeManager,
+            Role::ParameterManager => ContractRole::ParameterManager,
+        };
+
+        let account = match self.account_type {
+            AccountType::Address => {
+                let address = Address::from_str(&self.account_id).expect("Invalid address");
+                Identity::Address(address)
+            }
+            AccountType::Contract => {
+                let address = ContractId::from_str(&self.account_id).expect("Invalid contract id");
+                Identity::ContractId(address)
+            }
+        };
+
+        let has_role = contract.has_role(role.clone(), account).await?.value;
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "role": format!("{:?}", role),
+                "has_role": has_role,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("\n{:?}: {}", role, has_role);
+
+        Ok(())
+    }
+}
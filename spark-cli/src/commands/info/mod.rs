@@ -1,17 +1,26 @@
 pub(crate) mod account;
+pub(crate) mod audit;
 pub(crate) mod cli;
 pub(crate) mod config;
+pub(crate) mod emergency;
 pub(crate) mod epoch;
+pub(crate) mod has_role;
 pub(crate) mod matcher_fee;
 pub(crate) mod min_order_price;
 pub(crate) mod min_order_size;
 pub(crate) mod order;
 pub(crate) mod order_id;
+pub(crate) mod orders;
 pub(crate) mod paused;
+pub(crate) mod portfolio;
 pub(crate) mod protocol_fee;
 pub(crate) mod protocol_fee_user;
 pub(crate) mod protocol_fee_user_amount;
 pub(crate) mod proxy_owner;
 pub(crate) mod proxy_target;
+pub(crate) mod proxy_upgrade;
+pub(crate) mod rolling_volume;
 pub(crate) mod store_order_change_info;
+pub(crate) mod user_fee_tier;
 pub(crate) mod user_orders;
+pub(crate) mod user_volume;
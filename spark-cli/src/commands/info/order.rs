@@ -1,7 +1,10 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::{
+    config::OutputFormat,
+    utils::{connect, market_id, order_json, output_format, rpc_url},
+};
 use clap::Args;
 use fuels::types::Bits256;
-use spark_market_sdk::SparkMarketContract;
+use spark_market_sdk::SparkMarketReader;
 
 #[derive(Args, Clone)]
 #[command(about = "Query the market for information about a specific open order")]
@@ -10,20 +13,22 @@ pub(crate) struct OrderCommand {
     #[clap(long)]
     pub(crate) order_id: String,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl OrderCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
         let order_id = Bits256::from_hex_str(&self.order_id)?;
 
         if self.order_id.len() as u64 != 64 {
@@ -31,10 +36,16 @@ impl OrderCommand {
         }
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet).await;
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
 
         let order = contract.order(order_id).await?.value;
 
+        if output_format()? == OutputFormat::Json {
+            let json = order.as_ref().map(order_json);
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
         match order {
             Some(order) => {
                 println!("{:#?}", order);
@@ -0,0 +1,90 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, market_id, order_json, output_format, rpc_url, AccountType},
+};
+use clap::Args;
+use fuels::types::{Address, ContractId, Identity};
+use spark_market_sdk::SparkMarketReader;
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Query the market for all currently open orders of the user")]
+pub(crate) struct OrdersCommand {
+    /// The b256 id of the account
+    #[clap(long, alias = "user")]
+    pub(crate) account_id: String,
+
+    /// The type of account
+    #[clap(long)]
+    pub(crate) account_type: AccountType,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The number of orders requested per contract call
+    #[clap(long, default_value_t = 100)]
+    pub(crate) page_size: u64,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl OrdersCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        if self.page_size == 0 {
+            anyhow::bail!("Page size must be greater than zero");
+        }
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
+
+        let account = match self.account_type {
+            AccountType::Address => {
+                let address = Address::from_str(&self.account_id).expect("Invalid address");
+                Identity::Address(address)
+            }
+            AccountType::Contract => {
+                let address = ContractId::from_str(&self.account_id).expect("Invalid contract id");
+                Identity::ContractId(address)
+            }
+        };
+
+        let ids = contract.user_orders_all(account, self.page_size).await?;
+
+        if ids.is_empty() {
+            anyhow::bail!("User has no open orders");
+        }
+
+        let json = output_format()? == OutputFormat::Json;
+        let mut json_orders = serde_json::Map::new();
+
+        for page in ids.chunks(self.page_size as usize) {
+            let orders = contract.orders(page.to_vec()).await?.value;
+            for (id, order) in page.iter().zip(orders) {
+                if json {
+                    let key = format!("{:?}", Address::new(id.0));
+                    json_orders.insert(key, order.as_ref().map(order_json).into());
+                    continue;
+                }
+                match order {
+                    Some(order) => println!("{:?}: {:#?}", Address::new(id.0), order),
+                    None => println!("{:?}: order not found", Address::new(id.0)),
+                }
+            }
+        }
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&json_orders)?);
+        }
+
+        Ok(())
+    }
+}
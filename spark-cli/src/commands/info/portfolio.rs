@@ -0,0 +1,147 @@
+use crate::{
+    config::{profile, OutputFormat},
+    utils::{account_json, connect, output_format, registry_id, rpc_url, AccountType},
+};
+use clap::Args;
+use fuels::types::{Address, AssetId, ContractId, Identity};
+use spark_market_sdk::{portfolio, SparkMarketReader};
+use spark_registry_sdk::SparkRegistryReader;
+use std::{collections::BTreeMap, str::FromStr};
+
+/// Number of the registered markets requested per call
+const MARKETS_PER_CALL: u64 = 50;
+
+#[derive(Args, Clone)]
+#[command(about = "Query the balances and open orders of a user across the registered markets")]
+pub(crate) struct PortfolioCommand {
+    /// The b256 id of the account
+    #[clap(long)]
+    pub(crate) user: String,
+
+    /// The type of account
+    #[clap(long, default_value = "address")]
+    pub(crate) account_type: AccountType,
+
+    /// The asset ids or profile asset symbols to limit the markets to
+    /// Defaults to all the registered markets
+    #[clap(long)]
+    pub(crate) assets: Vec<String>,
+
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
+    #[clap(long)]
+    pub(crate) registry: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl PortfolioCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let registry_id = registry_id(self.registry.as_deref())?;
+        let profile = profile()?;
+
+        let user = match self.account_type {
+            AccountType::Address => {
+                Identity::Address(Address::from_str(&self.user).expect("Invalid address"))
+            }
+            AccountType::Contract => {
+                Identity::ContractId(ContractId::from_str(&self.user).expect("Invalid contract id"))
+            }
+        };
+
+        let assets = self
+            .assets
+            .iter()
+            .map(|symbol| {
+                let asset = profile.assets.get(symbol).unwrap_or(symbol);
+                AssetId::from_str(asset).map_err(|_| anyhow::anyhow!("Invalid asset {}", symbol))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        // Connect to the deployed contracts via the rpc
+        let registry = SparkRegistryReader::read_only(registry_id, provider.clone()).await?;
+        let mut markets = Vec::new();
+        let mut symbols = BTreeMap::new();
+        for info in registry.markets_all(MARKETS_PER_CALL).await? {
+            if !assets.is_empty() && !assets.contains(&info.base) && !assets.contains(&info.quote) {
+                continue;
+            }
+            symbols.insert(info.base, info.base_symbol);
+            symbols.insert(info.quote, info.quote_symbol);
+            markets.push(SparkMarketReader::read_only(info.market, provider.clone()).await?);
+        }
+
+        let portfolio = portfolio(&markets, user).await?;
+
+        // Shows the registry or the profile symbol of the asset when there is one
+        let name = |asset: &AssetId| {
+            let registry_symbol = symbols.get(asset).filter(|symbol| !symbol.is_empty());
+            let profile_symbol = profile
+                .assets
+                .iter()
+                .find(|(_, id)| AssetId::from_str(id).ok().as_ref() == Some(asset))
+                .map(|(symbol, _)| symbol);
+            registry_symbol
+                .or(profile_symbol)
+                .cloned()
+                .unwrap_or_else(|| format!("0x{}", asset))
+        };
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "assets": portfolio.assets.iter().map(|(asset, position)| {
+                    serde_json::json!({
+                        "asset": name(asset),
+                        "liquid": position.liquid,
+                        "locked": position.locked,
+                        "open_orders": position.open_orders,
+                    })
+                }).collect::<Vec<_>>(),
+                "markets": portfolio.markets.iter().map(|position| {
+                    serde_json::json!({
+                        "market": format!("0x{}", position.market),
+                        "base": name(&position.base),
+                        "quote": name(&position.quote),
+                        "account": account_json(&position.account),
+                        "open_orders": position.open_orders,
+                    })
+                }).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("\nAssets:");
+        for (asset, position) in portfolio.assets.iter() {
+            println!(
+                "{} liquid: {} locked: {} open orders: {}",
+                name(asset),
+                position.liquid,
+                position.locked,
+                position.open_orders
+            );
+        }
+
+        println!("\nMarkets:");
+        for position in portfolio.markets.iter() {
+            println!(
+                "0x{} {}/{} liquid: {}/{} locked: {}/{} open orders: {}",
+                position.market,
+                name(&position.base),
+                name(&position.quote),
+                position.account.liquid.base,
+                position.account.liquid.quote,
+                position.account.locked.base,
+                position.account.locked.quote,
+                position.open_orders
+            );
+        }
+
+        Ok(())
+    }
+}
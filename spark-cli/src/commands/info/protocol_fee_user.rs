@@ -1,7 +1,7 @@
-use crate::utils::{setup, validate_contract_id, AccountType};
+use crate::utils::{connect, market_id, rpc_url, AccountType};
 use clap::Args;
 use fuels::types::{Address, ContractId, Identity};
-use spark_market_sdk::SparkMarketContract;
+use spark_market_sdk::SparkMarketReader;
 use std::str::FromStr;
 
 #[derive(Args, Clone)]
@@ -15,23 +15,25 @@ pub(crate) struct ProtocolFeeUserCommand {
     #[clap(long)]
     pub(crate) account_type: AccountType,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl ProtocolFeeUserCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet).await;
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
 
         let account = match self.account_type {
             AccountType::Address => {
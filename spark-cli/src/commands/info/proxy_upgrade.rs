@@ -0,0 +1,57 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, market_id, output_format, rpc_url},
+};
+use clap::Args;
+use spark_proxy_sdk::SparkProxyReader;
+
+#[derive(Args, Clone)]
+#[command(about = "Query the upgrade delay and the proposed target of the proxy")]
+pub(crate) struct ProxyUpgradeCommand {
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl ProxyUpgradeCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkProxyReader::read_only(contract_id, provider).await;
+
+        let upgrade_delay = contract.upgrade_delay().await?.value;
+        let pending = contract.pending_proxy_target().await?.value;
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "upgrade_delay": upgrade_delay,
+                "pending_target": pending.as_ref().map(|pending| serde_json::json!({
+                    "target": format!("0x{}", pending.target),
+                    "executable_at": pending.executable_at,
+                })),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("Upgrade delay: {}", upgrade_delay);
+        match pending {
+            Some(pending) => {
+                println!("Proposed target: 0x{}", pending.target);
+                println!("Executable at: {}", pending.executable_at);
+            }
+            None => println!("Proposed target: None"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::utils::{connect, market_id, rpc_url};
+use clap::Args;
+use spark_market_sdk::SparkMarketReader;
+
+#[derive(Args, Clone)]
+#[command(about = "Query whether the market uses rolling window user volumes")]
+pub(crate) struct RollingVolumeCommand {
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl RollingVolumeCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
+
+        let rolling_volume = contract.rolling_volume().await?.value;
+
+        println!("\nRolling volume: {}", rolling_volume);
+
+        Ok(())
+    }
+}
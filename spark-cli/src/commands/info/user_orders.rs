@@ -1,7 +1,7 @@
-use crate::utils::{setup, validate_contract_id, AccountType};
+use crate::utils::{connect, market_id, rpc_url, AccountType};
 use clap::Args;
 use fuels::types::{Address, ContractId, Identity};
-use spark_market_sdk::SparkMarketContract;
+use spark_market_sdk::SparkMarketReader;
 use std::str::FromStr;
 
 #[derive(Args, Clone)]
@@ -15,23 +15,33 @@ pub(crate) struct UserOrdersCommand {
     #[clap(long)]
     pub(crate) account_type: AccountType,
 
-    /// The contract id of the market
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
+
+    /// The number of order ids requested per contract call
+    #[clap(long, default_value_t = 100)]
+    pub(crate) page_size: u64,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl UserOrdersCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        if self.page_size == 0 {
+            anyhow::bail!("Page size must be greater than zero");
+        }
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkMarketContract::new(contract_id, wallet).await;
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
 
         let account = match self.account_type {
             AccountType::Address => {
@@ -44,7 +54,7 @@ impl UserOrdersCommand {
             }
         };
 
-        let orders = contract.user_orders(account).await?.value;
+        let orders = contract.user_orders_all(account, self.page_size).await?;
 
         if orders.is_empty() {
             anyhow::bail!("User has no open orders");
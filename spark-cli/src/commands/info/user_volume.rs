@@ -0,0 +1,58 @@
+use crate::utils::{connect, market_id, rpc_url, AccountType};
+use clap::Args;
+use fuels::types::{Address, ContractId, Identity};
+use spark_market_sdk::SparkMarketReader;
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Query the user trade volume used for the protocol fee tiers")]
+pub(crate) struct UserVolumeCommand {
+    /// The b256 id of the account
+    #[clap(long)]
+    pub(crate) account_id: String,
+
+    /// The type of account
+    #[clap(long)]
+    pub(crate) account_type: AccountType,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl UserVolumeCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = market_id(self.contract_id.as_deref(), &provider).await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketReader::read_only(contract_id, provider).await?;
+
+        let account = match self.account_type {
+            AccountType::Address => {
+                let address = Address::from_str(&self.account_id).expect("Invalid address");
+                Identity::Address(address)
+            }
+            AccountType::Contract => {
+                let address = ContractId::from_str(&self.account_id).expect("Invalid contract id");
+                Identity::ContractId(address)
+            }
+        };
+
+        let (start, volume) = contract.user_volume(account).await?.value;
+
+        println!(
+            "User Volume: for {:?} (since, volume) ({}, {})",
+            account, start, volume
+        );
+
+        Ok(())
+    }
+}
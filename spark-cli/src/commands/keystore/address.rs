@@ -0,0 +1,26 @@
+use crate::utils::read_password;
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, prelude::WalletUnlocked};
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Decrypts an encrypted JSON keystore and prints its address")]
+pub(crate) struct AddressCommand {
+    /// The keystore file
+    #[clap(long)]
+    pub(crate) path: PathBuf,
+}
+
+impl AddressCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let password = read_password(&format!("Password for {}: ", self.path.display()))?;
+
+        let wallet = WalletUnlocked::load_keystore(&self.path, password, None)
+            .map_err(|err| anyhow::anyhow!("Failed to decrypt keystore: {}", err))?;
+
+        println!("\nAddress: {}", wallet.address());
+        println!("         0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::commands::keystore::{address::AddressCommand, import::ImportCommand, new::NewCommand};
+use clap::Subcommand;
+
+#[derive(Clone, Subcommand)]
+pub(crate) enum KeystoreCommands {
+    /// Print the address of an encrypted keystore
+    #[clap(short_flag = 'A')]
+    Address(AddressCommand),
+
+    /// Import a private key or a mnemonic into an encrypted keystore
+    #[clap(short_flag = 'I')]
+    Import(ImportCommand),
+
+    /// Create a new random key in an encrypted keystore
+    #[clap(short_flag = 'N')]
+    New(NewCommand),
+}
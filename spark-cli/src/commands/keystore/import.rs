@@ -0,0 +1,45 @@
+use crate::utils::{read_new_password, read_secret, wallet_from_mnemonic};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, prelude::WalletUnlocked};
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Imports a private key or a mnemonic into an encrypted JSON keystore")]
+pub(crate) struct ImportCommand {
+    /// Import a mnemonic phrase instead of a private key
+    #[clap(long)]
+    pub(crate) mnemonic: bool,
+
+    /// The derivation path of the mnemonic key
+    /// Ex. m/44'/1179993420'/1'/0/0
+    #[clap(long, requires = "mnemonic")]
+    pub(crate) derivation_path: Option<String>,
+
+    /// The directory to write the keystore file to
+    #[clap(long, default_value = ".")]
+    pub(crate) dir: PathBuf,
+}
+
+impl ImportCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        // The secret is prompted for so it does not end up in the shell history
+        let wallet = if self.mnemonic {
+            let mnemonic = read_secret("Mnemonic phrase: ")?;
+            wallet_from_mnemonic(mnemonic.trim(), self.derivation_path.as_deref(), None)?
+        } else {
+            let secret = read_secret("Private key: ")?;
+            WalletUnlocked::new_from_private_key(secret.trim().parse()?, None)
+        };
+
+        let password = read_new_password()?;
+
+        std::fs::create_dir_all(&self.dir)?;
+        let uuid = wallet.encrypt(&self.dir, password)?;
+
+        println!("\nKeystore written to: {}", self.dir.join(uuid).display());
+        println!("Address: {}", wallet.address());
+        println!("         0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod address;
+pub(crate) mod cli;
+pub(crate) mod import;
+pub(crate) mod new;
@@ -0,0 +1,29 @@
+use crate::utils::read_new_password;
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, prelude::WalletUnlocked};
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Creates a new random key in an encrypted JSON keystore")]
+pub(crate) struct NewCommand {
+    /// The directory to write the keystore file to
+    #[clap(long, default_value = ".")]
+    pub(crate) dir: PathBuf,
+}
+
+impl NewCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let password = read_new_password()?;
+
+        let wallet = WalletUnlocked::new_random(None);
+
+        std::fs::create_dir_all(&self.dir)?;
+        let uuid = wallet.encrypt(&self.dir, password)?;
+
+        println!("\nKeystore written to: {}", self.dir.join(uuid).display());
+        println!("Address: {}", wallet.address());
+        println!("         0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
@@ -2,4 +2,7 @@ pub(crate) mod batch;
 pub(crate) mod cli;
 pub(crate) mod core;
 pub(crate) mod info;
+pub(crate) mod keystore;
+pub(crate) mod multisig;
 pub(crate) mod registry;
+pub(crate) mod tui;
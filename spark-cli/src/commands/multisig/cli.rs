@@ -0,0 +1,54 @@
+use crate::commands::multisig::{
+    add_owner::AddOwnerCommand, approve::ApproveCommand, cancel::CancelCommand,
+    deploy::DeployCommand, execute::ExecuteCommand, info::InfoCommand, proposal::ProposalCommand,
+    remove_owner::RemoveOwnerCommand, revoke::RevokeCommand, set_threshold::SetThresholdCommand,
+    transfer::TransferCommand,
+};
+use clap::Subcommand;
+
+#[derive(Clone, Subcommand)]
+pub(crate) enum MultisigCommands {
+    /// Propose adding an owner to the multisig
+    #[clap(short_flag = 'O')]
+    AddOwner(AddOwnerCommand),
+
+    /// Approve a proposal
+    #[clap(short_flag = 'A')]
+    Approve(ApproveCommand),
+
+    /// Cancel a proposal
+    #[clap(short_flag = 'C')]
+    Cancel(CancelCommand),
+
+    /// Deploy a new multisig contract
+    #[clap(short_flag = 'D')]
+    Deploy(DeployCommand),
+
+    /// Execute an approved proposal
+    #[clap(short_flag = 'E')]
+    Execute(ExecuteCommand),
+
+    /// Query the owners and the threshold of the multisig
+    #[clap(short_flag = 'I')]
+    Info(InfoCommand),
+
+    /// Query a proposal
+    #[clap(short_flag = 'P')]
+    Proposal(ProposalCommand),
+
+    /// Propose removing an owner from the multisig
+    #[clap(short_flag = 'X')]
+    RemoveOwner(RemoveOwnerCommand),
+
+    /// Withdraw the approval of a proposal
+    #[clap(short_flag = 'R')]
+    Revoke(RevokeCommand),
+
+    /// Propose changing the threshold of the multisig
+    #[clap(short_flag = 'T')]
+    SetThreshold(SetThresholdCommand),
+
+    /// Propose transferring an asset held by the multisig
+    #[clap(short_flag = 'W')]
+    Transfer(TransferCommand),
+}
@@ -0,0 +1,63 @@
+use crate::utils::{rpc_url, setup, validate_address};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_multisig_sdk::SparkMultisigContract;
+
+#[derive(Args, Clone)]
+#[command(about = "Deploys a multisig to set as the owner of markets, registries and proxies")]
+pub(crate) struct DeployCommand {
+    /// The b256 address of an owner, repeated for every owner
+    #[clap(long = "owner", required = true)]
+    pub(crate) owners: Vec<String>,
+
+    /// The number of owner approvals required to execute a proposal
+    #[clap(long)]
+    pub(crate) threshold: u64,
+
+    /// The URL to deploy to
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl DeployCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let owners = self
+            .owners
+            .iter()
+            .map(|owner| validate_address(owner))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if self.threshold == 0 || self.threshold > owners.len() as u64 {
+            anyhow::bail!(
+                "The threshold must be between 1 and the {} owners",
+                owners.len()
+            );
+        }
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        let version = SparkMultisigContract::sdk_version();
+        let contract =
+            SparkMultisigContract::deploy(wallet.clone(), owners, self.threshold, version).await?;
+
+        // Balance post-deployment
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!(
+            "\nMultisig version {} ({}) deployed to: 0x{}",
+            SparkMultisigContract::sdk_str_version(),
+            version,
+            contract.id()
+        );
+        println!("Deployment cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
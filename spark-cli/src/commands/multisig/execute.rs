@@ -0,0 +1,60 @@
+use crate::utils::{print_estimate, rpc_url, setup, validate_contract_id};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
+use spark_multisig_sdk::SparkMultisigContract;
+
+#[derive(Args, Clone)]
+#[command(about = "Execute a multisig proposal approved by the threshold of owners")]
+pub(crate) struct ExecuteCommand {
+    /// The id of the proposal
+    #[clap(long)]
+    pub(crate) id: u64,
+
+    /// The contract id of the multisig
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl ExecuteCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMultisigContract::new(contract_id, wallet.clone()).await;
+
+        if self.dry_run {
+            let estimate = estimate_call(contract.execute_call_handler(self.id).await?).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.execute(self.id).await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nProposal {} executed", self.id);
+        println!("Contract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, identity_json, output_format, rpc_url, validate_contract_id},
+};
+use clap::Args;
+use spark_multisig_sdk::SparkMultisigReader;
+
+#[derive(Args, Clone)]
+#[command(about = "Query the owners, the threshold and the proposal count of a multisig")]
+pub(crate) struct InfoCommand {
+    /// The contract id of the multisig
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl InfoCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMultisigReader::read_only(contract_id, provider).await;
+
+        let owners = contract.owners().await?.value;
+        let threshold = contract.threshold().await?.value;
+        let proposals_count = contract.proposals_count().await?.value;
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "owners": owners.iter().map(identity_json).collect::<Vec<_>>(),
+                "threshold": threshold,
+                "proposals_count": proposals_count,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("\nOwners:");
+        for owner in owners.iter() {
+            println!("  {:?}", owner);
+        }
+        println!("Threshold: {} of {}", threshold, owners.len());
+        println!("Proposals: {}", proposals_count);
+
+        Ok(())
+    }
+}
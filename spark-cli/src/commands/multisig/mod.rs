@@ -0,0 +1,12 @@
+pub(crate) mod add_owner;
+pub(crate) mod approve;
+pub(crate) mod cancel;
+pub(crate) mod cli;
+pub(crate) mod deploy;
+pub(crate) mod execute;
+pub(crate) mod info;
+pub(crate) mod proposal;
+pub(crate) mod remove_owner;
+pub(crate) mod revoke;
+pub(crate) mod set_threshold;
+pub(crate) mod transfer;
@@ -0,0 +1,84 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, identity_json, output_format, rpc_url, validate_contract_id},
+};
+use clap::Args;
+use spark_multisig_sdk::SparkMultisigReader;
+
+#[derive(Args, Clone)]
+#[command(about = "Query a multisig proposal with its approvals")]
+pub(crate) struct ProposalCommand {
+    /// The id of the proposal
+    #[clap(long)]
+    pub(crate) id: u64,
+
+    /// The contract id of the multisig
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl ProposalCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMultisigReader::read_only(contract_id, provider).await;
+
+        let proposal = match contract.proposal(self.id).await?.value {
+            Some(proposal) => proposal,
+            None => anyhow::bail!("Proposal {} not found", self.id),
+        };
+        let mut approved_by = Vec::new();
+        for owner in contract.owners().await?.value {
+            if contract.approved(self.id, owner).await?.value {
+                approved_by.push(owner);
+            }
+        }
+        let threshold = contract.threshold().await?.value;
+
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "id": proposal.id,
+                "target": format!("0x{}", proposal.target),
+                "function": function_name(&proposal.function_selector.0),
+                "calldata": format!("0x{}", hex(&proposal.calldata.0)),
+                "proposer": identity_json(&proposal.proposer),
+                "status": format!("{:?}", proposal.status),
+                "approvals": proposal.approvals,
+                "approved_by": approved_by.iter().map(identity_json).collect::<Vec<_>>(),
+                "threshold": threshold,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("\nProposal: {}", proposal.id);
+        println!("Target: 0x{}", proposal.target);
+        println!("Function: {}", function_name(&proposal.function_selector.0));
+        println!("Calldata: 0x{}", hex(&proposal.calldata.0));
+        println!("Proposer: {:?}", proposal.proposer);
+        println!("Status: {:?}", proposal.status);
+        println!("Approvals: {} of {}", proposal.approvals, threshold);
+        for owner in approved_by.iter() {
+            println!("  {:?}", owner);
+        }
+
+        Ok(())
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The selector is the function name prefixed by its length as a big endian u64
+fn function_name(selector: &[u8]) -> String {
+    String::from_utf8_lossy(selector.get(8..).unwrap_or_default()).into_owned()
+}
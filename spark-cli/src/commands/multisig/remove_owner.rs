@@ -0,0 +1,39 @@
+use crate::utils::{propose_to_multisig, rpc_url, setup, validate_address, validate_contract_id};
+use clap::Args;
+use spark_multisig_sdk::SparkMultisigContract;
+
+#[derive(Args, Clone)]
+#[command(about = "Propose removing an owner from a multisig")]
+pub(crate) struct RemoveOwnerCommand {
+    /// The b256 address of the owner to remove
+    #[clap(long)]
+    pub(crate) owner: String,
+
+    /// The contract id of the multisig
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl RemoveOwnerCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMultisigContract::new(contract_id, wallet.clone()).await;
+
+        // The multisig changes its owners by executing a proposal on itself
+        propose_to_multisig(
+            &self.contract_id,
+            &wallet,
+            &contract.remove_owner_call_handler(validate_address(&self.owner)?),
+        )
+        .await
+    }
+}
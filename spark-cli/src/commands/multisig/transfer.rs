@@ -0,0 +1,50 @@
+use crate::utils::{propose_to_multisig, rpc_url, setup, validate_address, validate_contract_id};
+use clap::Args;
+use fuels::types::AssetId;
+use spark_multisig_sdk::SparkMultisigContract;
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Propose transferring an asset held by a multisig")]
+pub(crate) struct TransferCommand {
+    /// The asset id of the asset to transfer
+    #[clap(long)]
+    pub(crate) asset: String,
+
+    /// The amount to transfer
+    #[clap(long)]
+    pub(crate) amount: u64,
+
+    /// The b256 address of the recipient
+    #[clap(long)]
+    pub(crate) recipient: String,
+
+    /// The contract id of the multisig
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl TransferCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+        let asset = AssetId::from_str(&self.asset).expect("Invalid asset");
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMultisigContract::new(contract_id, wallet.clone()).await;
+
+        // The multisig transfers its assets by executing a proposal on itself
+        propose_to_multisig(
+            &self.contract_id,
+            &wallet,
+            &contract.transfer_call_handler(asset, self.amount, validate_address(&self.recipient)?),
+        )
+        .await
+    }
+}
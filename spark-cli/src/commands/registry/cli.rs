@@ -1,6 +1,7 @@
 use crate::commands::registry::{
-    config::ConfigCommand, deploy::DeployCommand, markets::MarketsCommand,
-    register::RegisterCommand, unregister::UnregisterCommand,
+    config::ConfigCommand, deploy::DeployCommand, list::ListCommand, markets::MarketsCommand,
+    migrate::MigrateCommand, register::RegisterCommand, set_primary::SetPrimaryCommand,
+    set_status::SetStatusCommand, set_symbols::SetSymbolsCommand, unregister::UnregisterCommand,
 };
 use clap::Subcommand;
 
@@ -14,14 +15,34 @@ pub(crate) enum RegistryCommands {
     #[clap(short_flag = 'D')]
     Deploy(DeployCommand),
 
+    /// List the registered markets with their metadata
+    #[clap(short_flag = 'L')]
+    List(ListCommand),
+
     /// Unregister a market in the market registry contract
     #[clap(short_flag = 'M')]
     Markets(MarketsCommand),
 
+    /// Upgrade a market proxy to a new implementation and update the registry
+    #[clap(short_flag = 'G')]
+    Migrate(MigrateCommand),
+
     /// Register a new market in the market registry contract
     #[clap(short_flag = 'R')]
     Register(RegisterCommand),
 
+    /// Set the primary market of an asset pair
+    #[clap(short_flag = 'P')]
+    SetPrimary(SetPrimaryCommand),
+
+    /// Set the status of a registered market
+    #[clap(short_flag = 'S')]
+    SetStatus(SetStatusCommand),
+
+    /// Set the asset symbols of a registered market
+    #[clap(short_flag = 'Y')]
+    SetSymbols(SetSymbolsCommand),
+
     /// Unregister a market in the market registry contract
     #[clap(short_flag = 'U')]
     Unregister(UnregisterCommand),
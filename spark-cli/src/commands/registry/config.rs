@@ -1,30 +1,44 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::{
+    config::OutputFormat,
+    utils::{connect, identity_json, output_format, registry_id, rpc_url},
+};
 use clap::Args;
-use spark_registry_sdk::SparkRegistryContract;
+use spark_registry_sdk::SparkRegistryReader;
 
 #[derive(Args, Clone)]
 #[command(about = "Query the MarketRegistry for its configurable variables")]
 pub(crate) struct ConfigCommand {
-    /// The contract id of the market
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl ConfigCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = registry_id(self.contract_id.as_deref())?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkRegistryContract::new(contract_id, wallet).await;
+        let contract = SparkRegistryReader::read_only(contract_id, provider).await?;
 
         let (owner, version) = contract.config().await?.value;
 
+        if output_format()? == OutputFormat::Json {
+            let json = serde_json::json!({
+                "owner": owner.as_ref().map(identity_json),
+                "version": contract.contract_str_version().await?,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
         println!("\nOwner: 0x{:?}", owner);
         println!(
             "Version: {} ({})",
@@ -1,4 +1,4 @@
-use crate::utils::setup;
+use crate::utils::{rpc_url, setup};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use spark_registry_sdk::SparkRegistryContract;
@@ -8,13 +8,14 @@ use spark_registry_sdk::SparkRegistryContract;
 pub(crate) struct DeployCommand {
     /// The URL to deploy to
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl DeployCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
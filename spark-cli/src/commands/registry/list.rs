@@ -0,0 +1,65 @@
+use crate::{
+    config::OutputFormat,
+    utils::{connect, market_info_json, output_format, registry_id, rpc_url},
+};
+use clap::Args;
+use spark_registry_sdk::SparkRegistryReader;
+
+#[derive(Args, Clone)]
+#[command(about = "Lists the registered markets with their metadata")]
+pub(crate) struct ListCommand {
+    /// The number of markets requested per contract call
+    #[clap(long, default_value_t = 50)]
+    pub(crate) page_size: u64,
+
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl ListCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = registry_id(self.contract_id.as_deref())?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkRegistryReader::read_only(contract_id, provider).await?;
+
+        let markets = contract.markets_all(self.page_size).await?;
+
+        if output_format()? == OutputFormat::Json {
+            let json = markets.iter().map(market_info_json).collect::<Vec<_>>();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
+        println!("\nMarkets: {}", markets.len());
+        for info in markets.iter() {
+            let primary = if info.primary { " primary" } else { "" };
+            println!(
+                "\n0x{} {}/{} {:?} v{}{}",
+                info.market,
+                info.base_symbol,
+                info.quote_symbol,
+                info.status,
+                info.version,
+                primary
+            );
+            println!("  Base: 0x{} ({} decimals)", info.base, info.base_decimals);
+            println!(
+                "  Quote: 0x{} ({} decimals)",
+                info.quote, info.quote_decimals
+            );
+            println!("  Price decimals: {}", info.price_decimals);
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::{
+    config::OutputFormat,
+    utils::{connect, output_format, registry_id, rpc_url},
+};
 use clap::Args;
 use fuels::types::AssetId;
-use spark_registry_sdk::SparkRegistryContract;
+use spark_registry_sdk::SparkRegistryReader;
 use std::str::FromStr;
 
 #[derive(Args, Clone)]
@@ -15,20 +18,22 @@ pub(crate) struct MarketsCommand {
     #[clap(long)]
     pub(crate) quote: String,
 
-    /// The contract id of the market
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
 }
 
 impl MarketsCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
+        let provider = connect(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = registry_id(self.contract_id.as_deref())?;
 
         let mut asset_ids: Vec<(AssetId, AssetId)> = Vec::new();
         asset_ids.push((
@@ -37,10 +42,25 @@ impl MarketsCommand {
         ));
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkRegistryContract::new(contract_id, wallet).await;
+        let contract = SparkRegistryReader::read_only(contract_id, provider).await?;
 
         let markets = contract.markets(asset_ids).await?.value;
 
+        if output_format()? == OutputFormat::Json {
+            let json = markets
+                .iter()
+                .map(|(base, quote, market)| {
+                    serde_json::json!({
+                        "base": format!("0x{}", base),
+                        "quote": format!("0x{}", quote),
+                        "market": market.map(|market| format!("0x{}", market)),
+                    })
+                })
+                .collect::<Vec<_>>();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+
         println!("\nMarkets: {:?}", markets);
 
         Ok(())
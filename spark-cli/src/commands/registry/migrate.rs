@@ -0,0 +1,246 @@
+use crate::{
+    config::OutputFormat,
+    utils::{
+        check_upgrade_required, market_id, output_format, print_estimate, registry_id, rpc_url,
+        setup, validate_contract_id,
+    },
+};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, types::ContractId};
+use spark_market_sdk::{estimate_call, MarketSettings, SparkMarketContract};
+use spark_proxy_sdk::SparkProxyContract;
+use spark_registry_sdk::SparkRegistryContract;
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Upgrades a market proxy to a new implementation and updates the registry")]
+pub(crate) struct MigrateCommand {
+    /// The contract id or the pair symbol of the market proxy
+    /// Ex. ETH/USDC
+    #[clap(long)]
+    pub(crate) market: String,
+
+    /// The version of the new implementation, must be greater than the current one
+    /// Defaults to the sdk version
+    #[clap(long)]
+    pub(crate) version: Option<u32>,
+
+    /// The contract id of an implementation deployed by a previous run
+    /// Used to execute the upgrade proposed by that run once the upgrade delay of the proxy has elapsed
+    #[clap(long, conflicts_with = "version")]
+    pub(crate) implementation: Option<String>,
+
+    /// The build output directory of the deployed implementation to check the new one against
+    /// Required unless --force is passed
+    #[clap(long)]
+    pub(crate) previous_build: Option<PathBuf>,
+
+    /// Migrates even when the upgrade check finds breaking changes or without the check
+    #[clap(long)]
+    pub(crate) force: bool,
+
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the proxy and registry calls without deploying the implementation
+    /// or submitting the transactions
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl MigrateCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let registry_id = registry_id(self.contract_id.as_deref())?;
+        let market_id = market_id(Some(&self.market), wallet.provider().unwrap()).await?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contracts via the rpc, the current implementation may be of an older major version
+        let market = SparkMarketContract::connect_unchecked(market_id, wallet.clone()).await?;
+        let proxy = SparkProxyContract::new(market_id, wallet.clone()).await;
+        let registry = SparkRegistryContract::new(registry_id, wallet.clone()).await?;
+
+        let previous_target = match proxy.proxy_target().await?.value {
+            Some(target) => target,
+            None => anyhow::bail!("Market 0x{} is not a proxy", market_id),
+        };
+        let (base, base_decimals, quote, quote_decimals, _, price_decimals, previous_version) =
+            market.config().await?.value;
+        // Older implementations lack the getters of the settings added since
+        let settings = MarketSettings::read(&market).await?;
+
+        check_upgrade_required(self.previous_build.as_deref(), None, self.force)?;
+
+        let (target, version) = match &self.implementation {
+            // Resumes a migration with the implementation deployed by the previous run
+            Some(implementation) => {
+                let target = validate_contract_id(implementation)?;
+                let implementation =
+                    SparkMarketContract::connect_unchecked(target, wallet.clone()).await?;
+                let version = implementation.config().await?.value.6;
+                if version <= previous_version {
+                    anyhow::bail!(
+                        "Version {} is not greater than the current version {}",
+                        version,
+                        previous_version
+                    );
+                }
+                (target, version)
+            }
+            None => {
+                let version = self
+                    .version
+                    .unwrap_or_else(SparkMarketContract::sdk_version);
+                if version <= previous_version {
+                    anyhow::bail!(
+                        "Version {} is not greater than the current version {}",
+                        version,
+                        previous_version
+                    );
+                }
+
+                // A dry run estimates the proxy call with the current target in place of the new implementation
+                if self.dry_run {
+                    (previous_target, version)
+                } else {
+                    // Deploy the new implementation with the config of the market
+                    let implementation = SparkMarketContract::deploy(
+                        base,
+                        base_decimals,
+                        quote,
+                        quote_decimals,
+                        wallet.clone(),
+                        price_decimals,
+                        version,
+                    )
+                    .await?;
+                    let target: ContractId = implementation.contract_id().into();
+                    println!(
+                        "\nImplementation version {} deployed to: 0x{}",
+                        version, target
+                    );
+                    (target, version)
+                }
+            }
+        };
+
+        // A proxy with an upgrade delay is upgraded by a proposal executed by a later run
+        let delay = proxy.upgrade_delay().await?.value;
+        let (proxy_call, proposed) = if delay == 0 {
+            (proxy.set_proxy_target_call_handler(target), false)
+        } else {
+            match proxy.pending_proxy_target().await?.value {
+                Some(pending) if pending.target == target => {
+                    (proxy.execute_proxy_target_call_handler(), false)
+                }
+                Some(pending) => anyhow::bail!(
+                    "An upgrade to 0x{} is already proposed, cancel it first",
+                    pending.target
+                ),
+                None => (proxy.propose_proxy_target_call_handler(target), true),
+            }
+        };
+
+        // Markets registered before the market list are only stored as the market of their pair
+        let registered = registry.market_info(market_id).await?.value.is_some()
+            || registry.markets(vec![(base, quote)]).await?.value[0].2 == Some(market_id);
+
+        if self.dry_run {
+            let text = output_format()? != OutputFormat::Json;
+            if text && self.implementation.is_none() {
+                println!("\nThe implementation deployment is not estimated");
+            }
+            if text {
+                println!("\nProxy call:");
+            }
+            print_estimate(&estimate_call(proxy_call).await?)?;
+            // The settings replayed depend on the new implementation and are not estimated
+            if registered && !proposed {
+                if text {
+                    println!("\nRegistry refresh:");
+                }
+                print_estimate(
+                    &estimate_call(registry.refresh_market_call_handler(market_id).await).await?,
+                )?;
+            }
+            return Ok(());
+        }
+
+        let _ = proxy_call.call().await?;
+        if proposed {
+            println!(
+                "Upgrade proposed with a delay of {} seconds, run again with --implementation 0x{} once it has elapsed",
+                delay, target
+            );
+            return Ok(());
+        }
+        println!(
+            "Proxy target set from 0x{} to 0x{}",
+            previous_target, target
+        );
+
+        // Reconnect to load the new implementation of the proxy
+        let market = SparkMarketContract::connect_unchecked(market_id, wallet.clone()).await?;
+        let config = market.config().await?.value;
+        if (config.0, config.1, config.2, config.3, config.5, config.6)
+            != (
+                base,
+                base_decimals,
+                quote,
+                quote_decimals,
+                price_decimals,
+                version,
+            )
+        {
+            if delay == 0 {
+                let _ = proxy.set_proxy_target(previous_target).await?;
+                anyhow::bail!(
+                    "The config of the new implementation does not match, the proxy target was restored to 0x{}",
+                    previous_target
+                );
+            }
+            anyhow::bail!(
+                "The config of the new implementation does not match, propose the previous target 0x{}",
+                previous_target
+            );
+        }
+
+        for replayed in settings.replay(&market).await? {
+            println!("Replayed {}", replayed);
+        }
+        if !settings.is_kept_by(&MarketSettings::read(&market).await?) {
+            anyhow::bail!("The owner settings of the market could not be replayed");
+        }
+
+        if registered {
+            let _ = registry.refresh_market(market_id).await?;
+            println!("Registry metadata of 0x{} updated", market_id);
+        } else {
+            println!(
+                "Market 0x{} is not registered, skipping the registry",
+                market_id
+            );
+        }
+
+        // Balance post-migration
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("Migration cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 pub(crate) mod cli;
 pub(crate) mod config;
 pub(crate) mod deploy;
+pub(crate) mod list;
 pub(crate) mod markets;
+pub(crate) mod migrate;
 pub(crate) mod register;
+pub(crate) mod set_primary;
+pub(crate) mod set_status;
+pub(crate) mod set_symbols;
 pub(crate) mod unregister;
@@ -0,0 +1,71 @@
+use crate::utils::{market_id, print_estimate, registry_id, rpc_url, setup, MarketStatus};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
+use spark_registry_sdk::{MarketStatus as ContractMarketStatus, SparkRegistryContract};
+
+#[derive(Args, Clone)]
+#[command(about = "Sets the status of a registered market")]
+pub(crate) struct SetStatusCommand {
+    /// The contract id of the market
+    #[clap(long)]
+    pub(crate) market: String,
+
+    /// The new status of the market
+    #[clap(long)]
+    pub(crate) status: MarketStatus,
+
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl SetStatusCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = registry_id(self.contract_id.as_deref())?;
+        let market = market_id(Some(&self.market), wallet.provider().unwrap()).await?;
+
+        let status = match self.status {
+            MarketStatus::Active => ContractMarketStatus::Active,
+            MarketStatus::Deprecated => ContractMarketStatus::Deprecated,
+        };
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkRegistryContract::new(contract_id, wallet.clone()).await?;
+
+        if self.dry_run {
+            let estimate =
+                estimate_call(contract.set_market_status_call_handler(market, status)).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract.set_market_status(market, status).await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nContract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::utils::{market_id, print_estimate, registry_id, rpc_url, setup};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
+use spark_registry_sdk::SparkRegistryContract;
+
+#[derive(Args, Clone)]
+#[command(about = "Sets the asset symbols of a registered market")]
+pub(crate) struct SetSymbolsCommand {
+    /// The contract id of the market
+    #[clap(long)]
+    pub(crate) market: String,
+
+    /// The symbol of the base asset
+    /// Ex. ETH
+    #[clap(long)]
+    pub(crate) base_symbol: String,
+
+    /// The symbol of the quote asset
+    /// Ex. USDC
+    #[clap(long)]
+    pub(crate) quote_symbol: String,
+
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+impl SetSymbolsCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = registry_id(self.contract_id.as_deref())?;
+        let market = market_id(Some(&self.market), wallet.provider().unwrap()).await?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkRegistryContract::new(contract_id, wallet.clone()).await?;
+
+        if self.dry_run {
+            let estimate = estimate_call(contract.set_market_symbols_call_handler(
+                market,
+                &self.base_symbol,
+                &self.quote_symbol,
+            ))
+            .await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
+
+        let _ = contract
+            .set_market_symbols(market, &self.base_symbol, &self.quote_symbol)
+            .await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("\nContract call cost: {}", balance - new_balance);
+
+        Ok(())
+    }
+}
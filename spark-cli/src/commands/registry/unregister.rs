@@ -1,6 +1,7 @@
-use crate::utils::{setup, validate_contract_id};
+use crate::utils::{market_id, print_estimate, propose_to_multisig, registry_id, rpc_url, setup};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
+use spark_market_sdk::estimate_call;
 use spark_registry_sdk::SparkRegistryContract;
 
 #[derive(Args, Clone)]
@@ -10,21 +11,31 @@ pub(crate) struct UnregisterCommand {
     #[clap(long)]
     pub(crate) market: String,
 
-    /// The contract id of the MarketRegistry
+    /// The contract id of the market registry
+    /// Defaults to the profile registry
     #[clap(long)]
-    pub(crate) contract_id: String,
+    pub(crate) contract_id: Option<String>,
 
     /// The URL to query
     /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
     #[clap(long)]
-    pub(crate) rpc: String,
+    pub(crate) rpc: Option<String>,
+
+    /// The contract id of the multisig owning the contract, proposes the call to it instead
+    #[clap(long, conflicts_with = "dry_run")]
+    pub(crate) multisig: Option<String>,
+
+    /// Estimates the gas and fee of the call without submitting the transaction
+    #[clap(long)]
+    pub(crate) dry_run: bool,
 }
 
 impl UnregisterCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
-        let wallet = setup(&self.rpc).await?;
-        let contract_id = validate_contract_id(&self.contract_id)?;
-        let market = validate_contract_id(&self.market)?;
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id = registry_id(self.contract_id.as_deref())?;
+        let market = market_id(Some(&self.market), wallet.provider().unwrap()).await?;
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
@@ -32,7 +43,23 @@ impl UnregisterCommand {
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = SparkRegistryContract::new(contract_id, wallet.clone()).await;
+        let contract = SparkRegistryContract::new(contract_id, wallet.clone()).await?;
+
+        if let Some(multisig) = &self.multisig {
+            return propose_to_multisig(
+                multisig,
+                &wallet,
+                &contract.unregister_market_call_handler(market).await,
+            )
+            .await;
+        }
+
+        if self.dry_run {
+            let estimate =
+                estimate_call(contract.unregister_market_call_handler(market).await).await?;
+            print_estimate(&estimate)?;
+            return Ok(());
+        }
 
         let _ = contract.unregister_market(market).await?;
 
@@ -0,0 +1,197 @@
+use fuels::{
+    client::{PageDirection, PaginationRequest},
+    prelude::{ContractId, Provider},
+    tx::Receipt,
+    types::{block::Block, tx_status::TxStatus, Bits256},
+};
+use spark_market_sdk::{
+    CancelOrderEvent, OpenOrderEvent, Order, OrderType, SparkMarketContract, TradeOrderEvent,
+};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Number of the recent trades kept for display
+const TRADES: usize = 50;
+/// Number of the blocks requested per call while scanning for events
+const BLOCKS_PER_PAGE: i32 = 100;
+/// Number of the blocks scanned per sync so a long lookback is caught up over several refreshes
+const BLOCKS_PER_SYNC: u32 = 500;
+/// Number of the orders requested per call while reading the book
+const ORDERS_PER_CALL: usize = 100;
+
+/// Trade decoded from a `TradeOrderEvent`
+#[derive(Clone, Debug)]
+pub(crate) struct Trade {
+    pub(crate) block_height: u32,
+    pub(crate) price: u64,
+    pub(crate) size: u64,
+    /// Side of the taker of the trade
+    pub(crate) taker: OrderType,
+}
+
+/// Order book of a market reconstructed from its open, cancel and trade events.
+///
+/// The events only discover the order ids, the orders opened or traded in the scanned blocks
+/// are read back from the market and cached until an event touches them again.
+pub(crate) struct OrderBook {
+    next_height: u32,
+    orders: BTreeMap<[u8; 32], Order>,
+    stale: BTreeSet<[u8; 32]>,
+    trades: VecDeque<Trade>,
+}
+
+impl OrderBook {
+    /// Starts the reconstruction from the block at `from_height`
+    pub(crate) fn new(from_height: u32) -> Self {
+        Self {
+            next_height: from_height,
+            orders: BTreeMap::new(),
+            stale: BTreeSet::new(),
+            trades: VecDeque::new(),
+        }
+    }
+
+    /// Adds orders known from elsewhere, such as the open orders of the user, which may have
+    /// been opened before the scanned blocks
+    pub(crate) fn track(&mut self, order_ids: &[Bits256]) {
+        for id in order_ids {
+            if !self.orders.contains_key(&id.0) {
+                self.stale.insert(id.0);
+            }
+        }
+    }
+
+    /// Scans up to `BLOCKS_PER_SYNC` blocks produced since the last sync and reads the orders
+    /// their events touched
+    pub(crate) async fn sync(
+        &mut self,
+        market: &SparkMarketContract,
+        provider: &Provider,
+    ) -> anyhow::Result<()> {
+        let latest = provider
+            .latest_block_height()
+            .await?
+            .min(self.next_height.saturating_add(BLOCKS_PER_SYNC - 1));
+        while self.next_height <= latest {
+            let request = PaginationRequest {
+                cursor: self
+                    .next_height
+                    .checked_sub(1)
+                    .map(|height| height.to_string()),
+                results: BLOCKS_PER_PAGE.min((latest - self.next_height + 1) as i32),
+                direction: PageDirection::Forward,
+            };
+            let blocks = provider.get_blocks(request).await?.results;
+            if blocks.is_empty() {
+                break;
+            }
+            for block in blocks {
+                self.apply_block(market, provider, &block).await?;
+                self.next_height = block.header.height + 1;
+            }
+        }
+
+        let ids = self.stale.iter().copied().collect::<Vec<_>>();
+        for chunk in ids.chunks(ORDERS_PER_CALL) {
+            let ids = chunk.iter().map(|id| Bits256(*id)).collect::<Vec<_>>();
+            let values = market.orders(ids).await?.value;
+            for (id, order) in chunk.iter().zip(values) {
+                self.stale.remove(id);
+                match order {
+                    Some(order) => {
+                        self.orders.insert(*id, order);
+                    }
+                    // Filled or cancelled
+                    None => {
+                        self.orders.remove(id);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn apply_block(
+        &mut self,
+        market: &SparkMarketContract,
+        provider: &Provider,
+        block: &Block,
+    ) -> anyhow::Result<()> {
+        let contract_id = ContractId::from(market.contract_id());
+        for tx_id in block.transactions.iter() {
+            let receipts = match provider.tx_status(tx_id).await? {
+                TxStatus::Success { receipts, .. } => receipts,
+                _ => continue,
+            };
+            let logs_market = receipts.iter().any(
+                |receipt| matches!(receipt, Receipt::LogData { id, .. } if *id == contract_id),
+            );
+            if logs_market {
+                self.apply_receipts(market, &receipts, block.header.height)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Orders opened and traded in the same transaction are discovered before the cancels,
+    /// the traded orders are read again as the trades change their amounts
+    fn apply_receipts(
+        &mut self,
+        market: &SparkMarketContract,
+        receipts: &[Receipt],
+        block_height: u32,
+    ) -> anyhow::Result<()> {
+        let decoder = market.get_instance().log_decoder();
+
+        for event in decoder.decode_logs_with_type::<OpenOrderEvent>(receipts)? {
+            self.stale.insert(event.order_id.0);
+        }
+        for event in decoder.decode_logs_with_type::<TradeOrderEvent>(receipts)? {
+            let taker = if event.seller_is_maker {
+                OrderType::Buy
+            } else {
+                OrderType::Sell
+            };
+            self.stale.insert(event.base_sell_order_id.0);
+            self.stale.insert(event.base_buy_order_id.0);
+            self.trades.push_front(Trade {
+                block_height,
+                price: event.trade_price,
+                size: event.trade_size,
+                taker,
+            });
+        }
+        for event in decoder.decode_logs_with_type::<CancelOrderEvent>(receipts)? {
+            self.orders.remove(&event.order_id.0);
+            self.stale.remove(&event.order_id.0);
+        }
+        self.trades.truncate(TRADES);
+
+        Ok(())
+    }
+
+    /// Returns up to `depth` price levels of the side with the summed order amounts,
+    /// best price first
+    pub(crate) fn levels(&self, order_type: OrderType, depth: usize) -> Vec<(u64, u64)> {
+        let mut levels = BTreeMap::new();
+        for order in self
+            .orders
+            .values()
+            .filter(|order| order.order_type == order_type)
+        {
+            *levels.entry(order.price).or_insert(0u64) += order.amount;
+        }
+        match order_type {
+            OrderType::Buy => levels.into_iter().rev().take(depth).collect(),
+            OrderType::Sell => levels.into_iter().take(depth).collect(),
+        }
+    }
+
+    pub(crate) fn trades(&self) -> impl Iterator<Item = &Trade> {
+        self.trades.iter()
+    }
+
+    pub(crate) fn scanned_height(&self) -> u32 {
+        self.next_height.saturating_sub(1)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    commands::tui::{
+        session::Session,
+        view::{Action, View},
+    },
+    utils::{market_id, rpc_url, setup},
+};
+use clap::Args;
+use console::Term;
+use fuels::{accounts::ViewOnlyAccount, prelude::ContractId};
+use spark_market_sdk::SparkMarketContract;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Args, Clone)]
+#[command(about = "Interactive terminal UI to watch a market and trade on it")]
+pub(crate) struct TuiCommand {
+    /// The number of blocks before the latest one to reconstruct the order book from
+    /// Orders opened earlier are not shown in the book
+    #[clap(long, default_value_t = 10_000)]
+    pub(crate) lookback: u32,
+
+    /// The interval in seconds between the refreshes
+    #[clap(long, default_value_t = 5)]
+    pub(crate) refresh: u64,
+
+    /// The contract id or the pair symbol of the market
+    /// Ex. ETH/USDC, defaults to the profile market
+    #[clap(long)]
+    pub(crate) contract_id: Option<String>,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    /// Defaults to the profile rpc
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl TuiCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&rpc_url(self.rpc.as_deref())?).await?;
+        let contract_id =
+            market_id(self.contract_id.as_deref(), wallet.provider().unwrap()).await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = SparkMarketContract::new(contract_id, wallet.clone()).await?;
+        let mut session = Session::new(contract, &wallet, self.lookback).await?;
+        let mut view = View::default();
+
+        // Keys are read on a thread of their own as the reads block
+        let (sender, mut keys) = mpsc::unbounded_channel();
+        std::thread::spawn(move || {
+            let term = Term::stdout();
+            while let Ok(key) = term.read_key() {
+                if sender.send(key).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let term = Term::stdout();
+        let _restore = RestoreTerminal(term.clone());
+        term.hide_cursor()?;
+        let mut interval = tokio::time::interval(Duration::from_secs(self.refresh.max(1)));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    if let Err(err) = session.refresh().await {
+                        view.status = format!("Refresh failed: {}", err);
+                    }
+                }
+                key = keys.recv() => {
+                    let Some(key) = key else { break };
+                    match view.handle_key(key, &session) {
+                        Action::None => {}
+                        Action::Quit => break,
+                        Action::Refresh => interval.reset_immediately(),
+                        Action::Open { amount, order_type, price } => {
+                            view.status = match session.open_order(amount, order_type, price).await {
+                                Ok(order_id) => format!("Opened order 0x{}", ContractId::from(order_id.0)),
+                                Err(err) => format!("Open failed: {}", err),
+                            };
+                            interval.reset_immediately();
+                        }
+                        Action::Cancel(order_id) => {
+                            view.status = match session.cancel_order(order_id).await {
+                                Ok(()) => format!("Cancelled order 0x{}", ContractId::from(order_id.0)),
+                                Err(err) => format!("Cancel failed: {}", err),
+                            };
+                            interval.reset_immediately();
+                        }
+                    }
+                }
+            }
+
+            term.clear_screen()?;
+            for line in view.render(&session) {
+                term.write_line(&line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Clears the screen and shows the cursor again when the UI exits, also on an error
+struct RestoreTerminal(Term);
+
+impl Drop for RestoreTerminal {
+    fn drop(&mut self) {
+        let _ = self.0.clear_screen();
+        let _ = self.0.show_cursor();
+    }
+}
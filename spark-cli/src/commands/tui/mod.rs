@@ -0,0 +1,4 @@
+pub(crate) mod book;
+pub(crate) mod command;
+pub(crate) mod session;
+pub(crate) mod view;
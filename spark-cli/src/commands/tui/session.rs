@@ -0,0 +1,86 @@
+use crate::commands::tui::book::OrderBook;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::{Provider, WalletUnlocked},
+    types::{Bits256, Identity},
+};
+use spark_market_sdk::{Account, Order, OrderType, SparkMarketContract};
+
+/// Number of the user orders requested per call
+const ORDERS_PER_CALL: u64 = 100;
+
+/// Market state shown by the terminal UI and the calls made on behalf of the user
+pub(crate) struct Session {
+    pub(crate) market: SparkMarketContract,
+    pub(crate) provider: Provider,
+    pub(crate) user: Identity,
+    pub(crate) book: OrderBook,
+    pub(crate) account: Option<Account>,
+    pub(crate) orders: Vec<(Bits256, Order)>,
+    pub(crate) block_height: u32,
+}
+
+impl Session {
+    pub(crate) async fn new(
+        market: SparkMarketContract,
+        wallet: &WalletUnlocked,
+        lookback: u32,
+    ) -> anyhow::Result<Self> {
+        let provider = wallet.provider().unwrap().clone();
+        let block_height = provider.latest_block_height().await?;
+        Ok(Self {
+            market,
+            provider,
+            user: wallet.address().into(),
+            book: OrderBook::new(block_height.saturating_sub(lookback)),
+            account: None,
+            orders: Vec::new(),
+            block_height,
+        })
+    }
+
+    /// Reloads the account and its orders and syncs the order book with the new blocks
+    pub(crate) async fn refresh(&mut self) -> anyhow::Result<()> {
+        self.account = Some(self.market.account(self.user).await?.value);
+
+        let ids = self
+            .market
+            .user_orders_all(self.user, ORDERS_PER_CALL)
+            .await?;
+        self.book.track(&ids);
+        self.book.sync(&self.market, &self.provider).await?;
+        self.block_height = self.book.scanned_height();
+
+        let mut orders = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(ORDERS_PER_CALL as usize) {
+            let values = self.market.orders(chunk.to_vec()).await?.value;
+            orders.extend(
+                chunk
+                    .iter()
+                    .zip(values)
+                    .filter_map(|(id, order)| order.map(|order| (*id, order))),
+            );
+        }
+        self.orders = orders;
+
+        Ok(())
+    }
+
+    pub(crate) async fn open_order(
+        &self,
+        amount: u64,
+        order_type: OrderType,
+        price: u64,
+    ) -> anyhow::Result<Bits256> {
+        Ok(self
+            .market
+            .open_order(amount, order_type, price)
+            .await?
+            .value)
+    }
+
+    pub(crate) async fn cancel_order(&self, order_id: Bits256) -> anyhow::Result<()> {
+        let _ = self.market.cancel_order(order_id).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+use crate::commands::tui::session::Session;
+use console::{style, Key};
+use fuels::{prelude::ContractId, types::Bits256};
+use spark_market_sdk::OrderType;
+
+/// Number of the price levels shown per side of the book
+const DEPTH: usize = 10;
+/// Number of the recent trades shown
+const TRADES: usize = 10;
+
+/// What the run loop has to do after a key press
+pub(crate) enum Action {
+    None,
+    Quit,
+    Refresh,
+    Open {
+        amount: u64,
+        order_type: OrderType,
+        price: u64,
+    },
+    Cancel(Bits256),
+}
+
+/// Order being typed in, the amount is entered first then the price
+struct OrderInput {
+    order_type: OrderType,
+    amount: String,
+    price: String,
+    editing_price: bool,
+}
+
+impl OrderInput {
+    fn new(order_type: OrderType) -> Self {
+        Self {
+            order_type,
+            amount: String::new(),
+            price: String::new(),
+            editing_price: false,
+        }
+    }
+
+    fn field(&mut self) -> &mut String {
+        if self.editing_price {
+            &mut self.price
+        } else {
+            &mut self.amount
+        }
+    }
+}
+
+/// Selection, input and status line of the terminal UI
+#[derive(Default)]
+pub(crate) struct View {
+    selected: usize,
+    input: Option<OrderInput>,
+    pub(crate) status: String,
+}
+
+impl View {
+    pub(crate) fn handle_key(&mut self, key: Key, session: &Session) -> Action {
+        if let Some(input) = self.input.as_mut() {
+            match key {
+                Key::Escape => self.input = None,
+                Key::Tab => input.editing_price = !input.editing_price,
+                Key::Backspace => {
+                    input.field().pop();
+                }
+                Key::Char(c) if c.is_ascii_digit() => input.field().push(c),
+                Key::Enter if !input.editing_price => input.editing_price = true,
+                Key::Enter => {
+                    let input = self.input.take().unwrap();
+                    match (input.amount.parse(), input.price.parse()) {
+                        (Ok(amount), Ok(price)) => {
+                            return Action::Open {
+                                amount,
+                                order_type: input.order_type,
+                                price,
+                            }
+                        }
+                        _ => self.status = "Invalid amount or price".into(),
+                    }
+                }
+                _ => {}
+            }
+            return Action::None;
+        }
+
+        let orders = session.orders.len();
+        self.selected = self.selected.min(orders.saturating_sub(1));
+        match key {
+            Key::Char('q') | Key::Escape => return Action::Quit,
+            Key::Char('r') => return Action::Refresh,
+            Key::Char('b') => self.input = Some(OrderInput::new(OrderType::Buy)),
+            Key::Char('s') => self.input = Some(OrderInput::new(OrderType::Sell)),
+            Key::ArrowUp | Key::Char('k') => self.selected = self.selected.saturating_sub(1),
+            Key::ArrowDown | Key::Char('j') if self.selected + 1 < orders => self.selected += 1,
+            Key::Char('c') => match session.orders.get(self.selected) {
+                Some((order_id, _)) => return Action::Cancel(*order_id),
+                None => self.status = "No open order selected".into(),
+            },
+            _ => {}
+        }
+        Action::None
+    }
+
+    pub(crate) fn render(&self, session: &Session) -> Vec<String> {
+        let mut lines = vec![format!(
+            "{}  0x{}  block {}",
+            style("Spark market").bold(),
+            ContractId::from(session.market.contract_id()),
+            session.block_height
+        )];
+        match &session.account {
+            Some(account) => {
+                lines.push(format!(
+                    "Liquid  base {:>20}  quote {:>20}",
+                    account.liquid.base, account.liquid.quote
+                ));
+                lines.push(format!(
+                    "Locked  base {:>20}  quote {:>20}",
+                    account.locked.base, account.locked.quote
+                ));
+            }
+            None => lines.push("Loading account...".into()),
+        }
+
+        lines.push(String::new());
+        lines.push(style("Order book").bold().to_string());
+        lines.push(format!("  {:>20} {:>20}", "Price", "Amount"));
+        for (price, amount) in session.book.levels(OrderType::Sell, DEPTH).iter().rev() {
+            lines.push(
+                style(format!("  {:>20} {:>20}", price, amount))
+                    .red()
+                    .to_string(),
+            );
+        }
+        lines.push(format!("  {:-^41}", ""));
+        for (price, amount) in session.book.levels(OrderType::Buy, DEPTH).iter() {
+            lines.push(
+                style(format!("  {:>20} {:>20}", price, amount))
+                    .green()
+                    .to_string(),
+            );
+        }
+
+        lines.push(String::new());
+        lines.push(style("Open orders").bold().to_string());
+        if session.orders.is_empty() {
+            lines.push("  None".into());
+        }
+        for (index, (order_id, order)) in session.orders.iter().enumerate() {
+            let line = format!(
+                "{} {:<4} {} {:>20} {:>20}",
+                if index == self.selected { ">" } else { " " },
+                side(&order.order_type),
+                short_id(order_id),
+                order.price,
+                order.amount
+            );
+            if index == self.selected {
+                lines.push(style(line).reverse().to_string());
+            } else {
+                lines.push(line);
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(style("Recent trades").bold().to_string());
+        lines.push(format!(
+            "  {:<10} {:<4} {:>20} {:>20}",
+            "Block", "Side", "Price", "Size"
+        ));
+        for trade in session.book.trades().take(TRADES) {
+            let line = format!(
+                "  {:<10} {:<4} {:>20} {:>20}",
+                trade.block_height,
+                side(&trade.taker),
+                trade.price,
+                trade.size
+            );
+            match trade.taker {
+                OrderType::Buy => lines.push(style(line).green().to_string()),
+                OrderType::Sell => lines.push(style(line).red().to_string()),
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(self.status.clone());
+        lines.push(match &self.input {
+            Some(input) => format!(
+                "{:?} amount: {}{}  price: {}{}  [tab] switch [enter] submit [esc] discard",
+                input.order_type,
+                input.amount,
+                if input.editing_price { "" } else { "_" },
+                input.price,
+                if input.editing_price { "_" } else { "" },
+            ),
+            None => "[b] buy  [s] sell  [↑/↓] select  [c] cancel  [r] refresh  [q] quit".into(),
+        });
+        lines
+    }
+}
+
+fn side(order_type: &OrderType) -> &'static str {
+    match order_type {
+        OrderType::Buy => "Buy",
+        OrderType::Sell => "Sell",
+    }
+}
+
+/// Shortens a b256 id to its first and last bytes
+fn short_id(id: &Bits256) -> String {
+    let hex = ContractId::from(id.0).to_string();
+    format!("0x{}…{}", &hex[..8], &hex[hex.len() - 4..])
+}
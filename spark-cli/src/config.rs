@@ -0,0 +1,129 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::PathBuf, sync::OnceLock};
+
+/// Profile and keystore selected with `--profile` and `--keystore` for the current invocation
+static SELECTION: OnceLock<Selection> = OnceLock::new();
+
+#[derive(Debug, Default)]
+struct Selection {
+    profile: Option<String>,
+    keystore: Option<PathBuf>,
+}
+
+/// Configuration file with the named CLI profiles.
+///
+/// Read from `SPARK_CONFIG` or `~/.spark/config.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Config {
+    /// Profile used when `--profile` is not passed
+    pub(crate) default_profile: Option<String>,
+    #[serde(default)]
+    pub(crate) profiles: BTreeMap<String, Profile>,
+}
+
+/// Defaults applied to the commands when their arguments are omitted
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Profile {
+    /// The URL of the network node
+    pub(crate) rpc: Option<String>,
+    /// The contract id of the market registry
+    pub(crate) registry: Option<String>,
+    /// Market used when `--contract-id` is omitted, a pair symbol or a contract id
+    pub(crate) market: Option<String>,
+    /// Asset ids by symbol used to resolve pair symbols through the registry
+    #[serde(default)]
+    pub(crate) assets: BTreeMap<String, String>,
+    /// Market contract ids by pair symbol that take precedence over the registry
+    #[serde(default)]
+    pub(crate) markets: BTreeMap<String, String>,
+    /// Source of the signing key
+    #[serde(default)]
+    pub(crate) key: KeySource,
+    /// Format of the command output
+    #[serde(default)]
+    pub(crate) output: OutputFormat,
+}
+
+/// Where the signing key of the state-changing commands is read from
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) enum KeySource {
+    /// `WALLET_SECRET` private key or `MNEMONIC` phrase environment variables
+    #[default]
+    Env,
+    /// Private key in the named environment variable
+    PrivateKey { var: String },
+    /// Mnemonic phrase in the named environment variable, derived with the optional path
+    Mnemonic { var: String, path: Option<String> },
+    /// Encrypted JSON keystore, the passphrase is prompted for
+    Keystore { path: PathBuf },
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// JSON documents for scripting, supported by the dry runs, `info account`, `info config`,
+    /// `info order`, `info orders`, `info portfolio` and the registry queries
+    Json,
+}
+
+impl Config {
+    pub(crate) fn path() -> anyhow::Result<PathBuf> {
+        if let Ok(path) = std::env::var("SPARK_CONFIG") {
+            return Ok(PathBuf::from(path));
+        }
+        let home = home::home_dir().ok_or_else(|| anyhow::anyhow!("No home directory found"))?;
+        Ok(home.join(".spark").join("config.toml"))
+    }
+
+    pub(crate) fn load() -> anyhow::Result<Option<Self>> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let config = toml::from_str(&content)
+            .map_err(|err| anyhow::anyhow!("Invalid config {}: {}", path.display(), err))?;
+        Ok(Some(config))
+    }
+}
+
+/// Selects the profile and the keystore overriding its key source for the current invocation
+pub(crate) fn select(profile: Option<String>, keystore: Option<PathBuf>) {
+    let _ = SELECTION.set(Selection { profile, keystore });
+}
+
+/// Returns the selected or the default profile.
+///
+/// The config file is read on every call so edits apply to running sessions without a
+/// restart. Without a config file and without `--profile` an empty profile is returned.
+pub(crate) fn profile() -> anyhow::Result<Profile> {
+    let selection = SELECTION.get_or_init(Selection::default);
+    let mut profile = load_profile(selection.profile.clone())?;
+    if let Some(path) = selection.keystore.clone() {
+        profile.key = KeySource::Keystore { path };
+    }
+    Ok(profile)
+}
+
+fn load_profile(selected: Option<String>) -> anyhow::Result<Profile> {
+    let config = match Config::load()? {
+        Some(config) => config,
+        None if selected.is_none() => return Ok(Profile::default()),
+        None => anyhow::bail!("Config file {} not found", Config::path()?.display()),
+    };
+
+    match selected.or(config.default_profile) {
+        Some(name) => config
+            .profiles
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Profile {} not found", name)),
+        None => Ok(Profile::default()),
+    }
+}
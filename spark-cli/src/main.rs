@@ -1,4 +1,5 @@
 mod commands;
+mod config;
 mod utils;
 
 use clap::Parser;
@@ -7,6 +8,8 @@ use commands::{
     cli::{Cli, Command},
     core::cli::CoreCommands,
     info::cli::InfoCommands,
+    keystore::cli::KeystoreCommands,
+    multisig::cli::MultisigCommands,
     registry::cli::RegistryCommands,
 };
 use dotenv::dotenv;
@@ -19,6 +22,7 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     let cli = Cli::parse();
+    config::select(cli.profile, cli.keystore);
 
     match cli.command {
         Command::Batch(args) => match args.commands {
@@ -38,28 +42,42 @@ async fn main() -> anyhow::Result<()> {
         },
         Command::Core(args) => match args.commands {
             CoreCommands::Cancel(args) => args.run().await,
+            CoreCommands::CancelProxyTarget(args) => args.run().await,
+            CoreCommands::CheckUpgrade(args) => args.run().await,
             CoreCommands::Deploy(args) => args.run().await,
             CoreCommands::Deposit(args) => args.run().await,
             CoreCommands::DepositFor(args) => args.run().await,
+            CoreCommands::EmergencyExit(args) => args.run().await,
+            CoreCommands::EnableEmergency(args) => args.run().await,
+            CoreCommands::ExecuteProxyTarget(args) => args.run().await,
             CoreCommands::FulfillMany(args) => args.run().await,
+            CoreCommands::GrantRole(args) => args.run().await,
             CoreCommands::Open(args) => args.run().await,
             CoreCommands::MatchMany(args) => args.run().await,
             CoreCommands::MatchPair(args) => args.run().await,
+            CoreCommands::ProposeProxyTarget(args) => args.run().await,
+            CoreCommands::RevokeRole(args) => args.run().await,
             CoreCommands::SetEpoch(args) => args.run().await,
+            CoreCommands::SetMakerRebate(args) => args.run().await,
             CoreCommands::SetProtocolFee(args) => args.run().await,
             CoreCommands::SetMatcherFee(args) => args.run().await,
             CoreCommands::SetMinOrderPrice(args) => args.run().await,
             CoreCommands::SetMinOrderSize(args) => args.run().await,
             CoreCommands::SetPaused(args) => args.run().await,
             CoreCommands::SetProxyTarget(args) => args.run().await,
+            CoreCommands::SetRollingVolume(args) => args.run().await,
             CoreCommands::SetStoreOrderChangeInfo(args) => args.run().await,
+            CoreCommands::SetUpgradeDelay(args) => args.run().await,
             CoreCommands::Withdraw(args) => args.run().await,
             CoreCommands::WithdrawToMarket(args) => args.run().await,
         },
         Command::Info(args) => match args.commands {
             InfoCommands::Account(args) => args.run().await,
+            InfoCommands::Audit(args) => args.run().await,
             InfoCommands::Config(args) => args.run().await,
+            InfoCommands::Emergency(args) => args.run().await,
             InfoCommands::Epoch(args) => args.run().await,
+            InfoCommands::HasRole(args) => args.run().await,
             InfoCommands::ProtocolFee(args) => args.run().await,
             InfoCommands::ProtocolFeeUser(args) => args.run().await,
             InfoCommands::ProtocolFeeUserAmount(args) => args.run().await,
@@ -68,18 +86,48 @@ async fn main() -> anyhow::Result<()> {
             InfoCommands::MinOrderSize(args) => args.run().await,
             InfoCommands::OrderId(args) => args.run().await,
             InfoCommands::Order(args) => args.run().await,
+            InfoCommands::Orders(args) => args.run().await,
             InfoCommands::Paused(args) => args.run().await,
+            InfoCommands::Portfolio(args) => args.run().await,
             InfoCommands::StoreOrderChangeInfo(args) => args.run().await,
             InfoCommands::ProxyOwner(args) => args.run().await,
             InfoCommands::ProxyTarget(args) => args.run().await,
+            InfoCommands::ProxyUpgrade(args) => args.run().await,
+            InfoCommands::RollingVolume(args) => args.run().await,
+            InfoCommands::UserFeeTier(args) => args.run().await,
             InfoCommands::UserOrders(args) => args.run().await,
+            InfoCommands::UserVolume(args) => args.run().await,
+        },
+        Command::Keystore(args) => match args.commands {
+            KeystoreCommands::Address(args) => args.run().await,
+            KeystoreCommands::Import(args) => args.run().await,
+            KeystoreCommands::New(args) => args.run().await,
+        },
+        Command::Multisig(args) => match args.commands {
+            MultisigCommands::AddOwner(args) => args.run().await,
+            MultisigCommands::Approve(args) => args.run().await,
+            MultisigCommands::Cancel(args) => args.run().await,
+            MultisigCommands::Deploy(args) => args.run().await,
+            MultisigCommands::Execute(args) => args.run().await,
+            MultisigCommands::Info(args) => args.run().await,
+            MultisigCommands::Proposal(args) => args.run().await,
+            MultisigCommands::RemoveOwner(args) => args.run().await,
+            MultisigCommands::Revoke(args) => args.run().await,
+            MultisigCommands::SetThreshold(args) => args.run().await,
+            MultisigCommands::Transfer(args) => args.run().await,
         },
         Command::Registry(args) => match args.commands {
             RegistryCommands::Config(args) => args.run().await,
             RegistryCommands::Deploy(args) => args.run().await,
+            RegistryCommands::List(args) => args.run().await,
             RegistryCommands::Markets(args) => args.run().await,
+            RegistryCommands::Migrate(args) => args.run().await,
             RegistryCommands::Register(args) => args.run().await,
+            RegistryCommands::SetPrimary(args) => args.run().await,
+            RegistryCommands::SetStatus(args) => args.run().await,
+            RegistryCommands::SetSymbols(args) => args.run().await,
             RegistryCommands::Unregister(args) => args.run().await,
         },
+        Command::Tui(args) => args.run().await,
     }
 }
@@ -1,9 +1,44 @@
+use crate::config::{profile, KeySource, OutputFormat};
 use clap::ValueEnum;
-use fuels::prelude::{ContractId, Provider, WalletUnlocked};
-use std::str::FromStr;
+use console::Term;
+use fuels::{
+    prelude::{AssetId, ContractId, Provider, WalletUnlocked},
+    programs::calls::{CallHandler, ContractCall},
+    types::{Address, Identity},
+};
+use serde_json::{json, Value};
+use spark_market_sdk::{check_upgrade, Account, CallEstimate, MarketBuild, Order};
+use spark_multisig_sdk::{AdminCall, SparkMultisigContract};
+use spark_registry_sdk::{MarketInfo, SparkRegistryReader};
+use std::{fmt::Debug, path::Path, str::FromStr};
+
+pub(crate) async fn connect(rpc: &str) -> anyhow::Result<Provider> {
+    Ok(Provider::connect(rpc).await?)
+}
 
 pub(crate) async fn setup(rpc: &str) -> anyhow::Result<WalletUnlocked> {
-    let provider = Provider::connect(rpc).await?;
+    let provider = connect(rpc).await?;
+
+    match profile()?.key {
+        KeySource::Env => {}
+        KeySource::PrivateKey { var } => {
+            let secret = std::env::var(&var)
+                .map_err(|_| anyhow::anyhow!("No private key found in environment {}", var))?;
+            let wallet = WalletUnlocked::new_from_private_key(secret.parse()?, Some(provider));
+            return Ok(wallet);
+        }
+        KeySource::Mnemonic { var, path } => {
+            let mnemonic = std::env::var(&var)
+                .map_err(|_| anyhow::anyhow!("No mnemonic found in environment {}", var))?;
+            return wallet_from_mnemonic(&mnemonic, path.as_deref(), Some(provider));
+        }
+        KeySource::Keystore { path } => {
+            let password = read_password(&format!("Password for {}: ", path.display()))?;
+            let wallet = WalletUnlocked::load_keystore(&path, password, Some(provider))
+                .map_err(|err| anyhow::anyhow!("Failed to decrypt keystore: {}", err))?;
+            return Ok(wallet);
+        }
+    }
 
     // First, try to get the private key from environment
     if let Ok(secret) = std::env::var("WALLET_SECRET") {
@@ -13,8 +48,8 @@ pub(crate) async fn setup(rpc: &str) -> anyhow::Result<WalletUnlocked> {
 
     // If no private key is provided, try to get the mnemonic phrase from environment
     if let Ok(mnemonic) = std::env::var("MNEMONIC") {
-        let wallet = WalletUnlocked::new_from_mnemonic_phrase(&mnemonic, Some(provider.clone()))?;
-        return Ok(wallet);
+        let path = std::env::var("DERIVATION_PATH").ok();
+        return wallet_from_mnemonic(&mnemonic, path.as_deref(), Some(provider));
     }
 
     // If neither WALLET_SECRET nor MNEMONIC are provided, return an error
@@ -23,6 +58,293 @@ pub(crate) async fn setup(rpc: &str) -> anyhow::Result<WalletUnlocked> {
     ))
 }
 
+/// Derives the wallet from the mnemonic phrase with the path, or the default
+/// `m/44'/1179993420'/0'/0/0` path
+pub(crate) fn wallet_from_mnemonic(
+    mnemonic: &str,
+    path: Option<&str>,
+    provider: Option<Provider>,
+) -> anyhow::Result<WalletUnlocked> {
+    let wallet = match path {
+        Some(path) => WalletUnlocked::new_from_mnemonic_phrase_with_path(mnemonic, provider, path)?,
+        None => WalletUnlocked::new_from_mnemonic_phrase(mnemonic, provider)?,
+    };
+    Ok(wallet)
+}
+
+/// Reads a passphrase from `KEYSTORE_PASSWORD` or prompts for it on the terminal without echo
+pub(crate) fn read_password(prompt: &str) -> anyhow::Result<String> {
+    if let Ok(password) = std::env::var("KEYSTORE_PASSWORD") {
+        return Ok(password);
+    }
+    read_secret(prompt)
+}
+
+/// Reads a new passphrase from `KEYSTORE_PASSWORD` or prompts for it twice
+pub(crate) fn read_new_password() -> anyhow::Result<String> {
+    if let Ok(password) = std::env::var("KEYSTORE_PASSWORD") {
+        return Ok(password);
+    }
+    let password = read_secret("New keystore password: ")?;
+    if password.is_empty() {
+        anyhow::bail!("Empty keystore password");
+    }
+    if read_secret("Repeat the password: ")? != password {
+        anyhow::bail!("Passwords do not match");
+    }
+    Ok(password)
+}
+
+/// Prompts for a secret on the terminal without echo
+pub(crate) fn read_secret(prompt: &str) -> anyhow::Result<String> {
+    let term = Term::stderr();
+    term.write_str(prompt)?;
+    Ok(term.read_secure_line()?)
+}
+
+/// Returns the `--rpc` argument or the rpc of the profile
+pub(crate) fn rpc_url(rpc: Option<&str>) -> anyhow::Result<String> {
+    match rpc {
+        Some(rpc) => Ok(rpc.into()),
+        None => profile()?
+            .rpc
+            .ok_or_else(|| anyhow::anyhow!("Missing --rpc and no rpc in the profile")),
+    }
+}
+
+/// Returns the registry contract id argument or the registry of the profile
+pub(crate) fn registry_id(registry: Option<&str>) -> anyhow::Result<ContractId> {
+    match registry {
+        Some(registry) => validate_contract_id(registry),
+        None => match profile()?.registry {
+            Some(registry) => validate_contract_id(&registry),
+            None => anyhow::bail!("Missing --contract-id and no registry in the profile"),
+        },
+    }
+}
+
+/// Resolves a market contract id or a pair symbol like `ETH/USDC`.
+///
+/// Pair symbols are looked up in the profile markets first and then in the registry,
+/// with the asset ids of the profile or else by the registered market symbols.
+/// Defaults to the market of the profile.
+pub(crate) async fn market_id(
+    market: Option<&str>,
+    provider: &Provider,
+) -> anyhow::Result<ContractId> {
+    let profile = profile()?;
+    let market = match market.map(String::from).or(profile.market.clone()) {
+        Some(market) => market,
+        None => anyhow::bail!("Missing --contract-id and no market in the profile"),
+    };
+
+    let (base, quote) = match market.split_once('/') {
+        Some(pair) => pair,
+        None => return validate_contract_id(&market),
+    };
+    if let Some(contract_id) = profile.markets.get(&market) {
+        return validate_contract_id(contract_id);
+    }
+
+    let registry = match profile.registry.as_deref() {
+        Some(registry) => validate_contract_id(registry)?,
+        None => anyhow::bail!("No registry in the profile to resolve {}", market),
+    };
+    let registry = SparkRegistryReader::read_only(registry, provider.clone()).await?;
+
+    // Symbols without an asset id in the profile are matched with the primary market of the registry metadata
+    if !profile.assets.contains_key(base) || !profile.assets.contains_key(quote) {
+        let markets = registry.markets_all(50).await?;
+        return match markets
+            .iter()
+            .find(|info| info.primary && info.base_symbol == base && info.quote_symbol == quote)
+        {
+            Some(info) => Ok(info.market),
+            None => anyhow::bail!("Market {} is not registered", market),
+        };
+    }
+
+    let asset = |symbol: &str| {
+        AssetId::from_str(&profile.assets[symbol])
+            .map_err(|_| anyhow::anyhow!("Invalid asset id of {} in the profile", symbol))
+    };
+    let assets = (asset(base)?, asset(quote)?);
+    let markets = registry.markets(vec![assets]).await?.value;
+
+    match markets.first().and_then(|(_, _, contract_id)| *contract_id) {
+        Some(contract_id) => Ok(contract_id),
+        None => anyhow::bail!("Market {} is not registered", market),
+    }
+}
+
+pub(crate) fn output_format() -> anyhow::Result<OutputFormat> {
+    Ok(profile()?.output)
+}
+
+pub(crate) fn print_estimate<T: Debug>(estimate: &CallEstimate<T>) -> anyhow::Result<()> {
+    if output_format()? == OutputFormat::Json {
+        let json = json!({
+            "gas_used": estimate.gas_used,
+            "gas_price": estimate.gas_price,
+            "fee": estimate.fee,
+            "value": format!("{:?}", estimate.value),
+            "logs": estimate.logs,
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+        return Ok(());
+    }
+
+    println!("\nDry run, the transaction has not been submitted");
+    println!("Estimated gas used: {}", estimate.gas_used);
+    println!("Estimated gas price: {}", estimate.gas_price);
+    println!("Estimated contract call cost: {}", estimate.fee);
+    println!("Return value: {:?}", estimate.value);
+    for log in estimate.logs.iter() {
+        println!("Event: {}", log);
+    }
+    Ok(())
+}
+
+/// Compares the build of the deployed market implementation with the new build, the sdk build by default,
+/// and fails on the breaking changes unless forced
+pub(crate) fn check_upgrade_build(
+    previous: &Path,
+    new: Option<&Path>,
+    force: bool,
+) -> anyhow::Result<()> {
+    let previous = MarketBuild::load(previous)?;
+    let new = match new {
+        Some(new) => MarketBuild::load(new)?,
+        None => MarketBuild::bundled()?,
+    };
+    let report = check_upgrade(&previous, &new)?;
+
+    if output_format()? == OutputFormat::Json {
+        let json = json!({
+            "compatible": report.is_compatible(),
+            "issues": report.issues.iter().map(|issue| json!({
+                "breaking": issue.is_breaking(),
+                "issue": issue.to_string(),
+            })).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!(
+            "
+Upgrade check: {} changes",
+            report.issues.len()
+        );
+        for issue in report.issues.iter() {
+            let severity = if issue.is_breaking() {
+                "Breaking"
+            } else {
+                "Warning"
+            };
+            println!("{}: {}", severity, issue);
+        }
+    }
+
+    if !report.is_compatible() && !force {
+        anyhow::bail!(
+            "The new implementation breaks the deployed one, pass --force to upgrade anyway"
+        );
+    }
+    Ok(())
+}
+
+/// Checks the upgrade against the deployed build, the check is skipped only when forced
+pub(crate) fn check_upgrade_required(
+    previous: Option<&Path>,
+    new: Option<&Path>,
+    force: bool,
+) -> anyhow::Result<()> {
+    match previous {
+        Some(previous) => check_upgrade_build(previous, new, force),
+        None if force => {
+            println!("\nUpgrade check skipped");
+            Ok(())
+        }
+        None => anyhow::bail!(
+            "Pass --previous-build with the build output of the deployed implementation to check the upgrade, or --force to skip the check"
+        ),
+    }
+}
+
+/// Proposes the admin call to the multisig owning the contract instead of submitting it
+pub(crate) async fn propose_to_multisig<T>(
+    multisig: &str,
+    wallet: &WalletUnlocked,
+    call_handler: &CallHandler<WalletUnlocked, ContractCall, T>,
+) -> anyhow::Result<()> {
+    let multisig_id = validate_contract_id(multisig)?;
+    let multisig = SparkMultisigContract::new(multisig_id, wallet.clone()).await;
+    let call = AdminCall::from_call_handler(call_handler)?;
+    let id = multisig.propose(call).await?.value;
+
+    println!(
+        "\nProposal {} submitted to the multisig 0x{}, it is executed once approved by {} owners",
+        id,
+        multisig_id,
+        multisig.threshold().await?.value
+    );
+    Ok(())
+}
+
+pub(crate) fn validate_address(address: &str) -> anyhow::Result<Identity> {
+    if address.len() as u64 != 66 {
+        anyhow::bail!("Invalid address length");
+    }
+
+    Ok(Identity::Address(
+        Address::from_str(address).expect("Invalid address"),
+    ))
+}
+
+pub(crate) fn identity_json(identity: &Identity) -> Value {
+    match identity {
+        Identity::Address(address) => json!({ "Address": format!("0x{}", address) }),
+        Identity::ContractId(contract_id) => json!({ "ContractId": format!("0x{}", contract_id) }),
+    }
+}
+
+pub(crate) fn account_json(account: &Account) -> Value {
+    json!({
+        "liquid": { "base": account.liquid.base, "quote": account.liquid.quote },
+        "locked": { "base": account.locked.base, "quote": account.locked.quote },
+    })
+}
+
+pub(crate) fn market_info_json(info: &MarketInfo) -> Value {
+    json!({
+        "market": format!("0x{}", info.market),
+        "base": format!("0x{}", info.base),
+        "base_symbol": info.base_symbol,
+        "base_decimals": info.base_decimals,
+        "quote": format!("0x{}", info.quote),
+        "quote_symbol": info.quote_symbol,
+        "quote_decimals": info.quote_decimals,
+        "price_decimals": info.price_decimals,
+        "status": format!("{:?}", info.status),
+        "version": info.version,
+        "primary": info.primary,
+    })
+}
+
+pub(crate) fn order_json(order: &Order) -> Value {
+    json!({
+        "amount": order.amount,
+        "asset_type": format!("{:?}", order.asset_type),
+        "order_type": format!("{:?}", order.order_type),
+        "owner": identity_json(&order.owner),
+        "price": order.price,
+        "block_height": order.block_height,
+        "order_height": order.order_height,
+        "matcher_fee": order.matcher_fee,
+        "protocol_maker_fee": order.protocol_maker_fee,
+        "protocol_taker_fee": order.protocol_taker_fee,
+    })
+}
+
 pub(crate) fn validate_contract_id(contract_id: &str) -> anyhow::Result<ContractId> {
     if contract_id.len() as u64 != 66 {
         anyhow::bail!("Invalid contract id length");
@@ -62,3 +384,21 @@ pub(crate) enum OrderType {
     /// Sell order
     Sell,
 }
+
+#[derive(Clone, ValueEnum)]
+pub(crate) enum Role {
+    /// Pauses and unpauses the market
+    Pauser,
+    /// Sets the protocol fee and the matcher fee
+    FeeManager,
+    /// Sets the epoch, the order minimums, the order change info storing and the rolling volume
+    ParameterManager,
+}
+
+#[derive(Clone, ValueEnum)]
+pub(crate) enum MarketStatus {
+    /// Listed for trading
+    Active,
+    /// Kept registered for withdrawals only
+    Deprecated,
+}
@@ -0,0 +1,281 @@
+use crate::{Order, OrderType, SparkMarketContract, SparkMarketError};
+use fuels::{
+    accounts::{Account as FuelsAccount, ViewOnlyAccount},
+    prelude::AssetId,
+    types::{Bits256, Bytes32, Identity},
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+};
+
+/// Number of the accounts, user orders or orders requested per call
+const ITEMS_PER_CALL: u64 = 100;
+/// Protocol fee rate of 100%, as `HUNDRED_PERCENT` of the market contract
+const HUNDRED_PERCENT: u128 = 10_000;
+
+/// Amount of the base asset a sell order locks or of the quote asset a buy order locks,
+/// as `lock_order_amount` of the market contract
+pub fn lock_order_amount(
+    order: &Order,
+    base_decimals: u32,
+    quote_decimals: u32,
+    price_decimals: u32,
+) -> u64 {
+    match order.order_type {
+        OrderType::Sell => order.amount,
+        OrderType::Buy => {
+            let scale = 10_u128.pow(base_decimals + price_decimals - quote_decimals);
+            let amount = order.amount as u128 * order.price as u128 / scale;
+            let fee_rate = order.protocol_maker_fee.max(order.protocol_taker_fee) as u128;
+            let protocol_fee = amount * fee_rate / HUNDRED_PERCENT;
+            (amount + protocol_fee + order.matcher_fee as u128) as u64
+        }
+    }
+}
+
+/// Balances of one asset of the market
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetAudit {
+    /// Sum of the liquid balances of the audited accounts
+    pub liquid: u64,
+    /// Sum of the locked balances of the audited accounts
+    pub locked: u64,
+    /// Sum of the amounts the open orders of the audited accounts lock
+    pub orders_locked: u64,
+    /// Balance of the market contract
+    pub contract: u64,
+}
+
+impl AssetAudit {
+    /// Amount the market owes to the audited accounts
+    pub fn accounts(&self) -> u64 {
+        self.liquid + self.locked
+    }
+}
+
+/// Mismatch found by the audit of a market
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditIssue {
+    /// The market holds less of the asset than the accounts are credited with
+    Insolvent {
+        asset: AssetId,
+        accounts: u64,
+        contract: u64,
+    },
+    /// The market holds more of the asset than the audited accounts are credited with,
+    /// an account was left out of the audit or the asset was transferred to the market directly
+    Surplus {
+        asset: AssetId,
+        accounts: u64,
+        contract: u64,
+    },
+    /// The locked balance of a user is lower than the amount its open orders lock
+    LockShortfall {
+        user: Identity,
+        asset: AssetId,
+        locked: u64,
+        orders_locked: u64,
+    },
+    /// The locked balance of a user exceeds the amount its open orders lock, partial fills
+    /// round the locked quote of a buy order up by a unit
+    LockSurplus {
+        user: Identity,
+        asset: AssetId,
+        locked: u64,
+        orders_locked: u64,
+    },
+    /// An order indexed for the user is not stored
+    OrderNotFound { user: Identity, order_id: Bits256 },
+}
+
+impl AuditIssue {
+    /// Whether the mismatch leaves the funds of a user uncovered
+    pub fn is_breaking(&self) -> bool {
+        !matches!(self, Self::Surplus { .. } | Self::LockSurplus { .. })
+    }
+}
+
+impl fmt::Display for AuditIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Insolvent {
+                asset,
+                accounts,
+                contract,
+            } => write!(
+                f,
+                "Asset 0x{} insolvent, the accounts hold {} and the market {}",
+                asset, accounts, contract
+            ),
+            Self::Surplus {
+                asset,
+                accounts,
+                contract,
+            } => write!(
+                f,
+                "Asset 0x{} surplus, the accounts hold {} and the market {}",
+                asset, accounts, contract
+            ),
+            Self::LockShortfall {
+                user,
+                asset,
+                locked,
+                orders_locked,
+            } => write!(
+                f,
+                "User {:?} locks {} of asset 0x{} for orders locking {}",
+                user, locked, asset, orders_locked
+            ),
+            Self::LockSurplus {
+                user,
+                asset,
+                locked,
+                orders_locked,
+            } => write!(
+                f,
+                "User {:?} locks {} of asset 0x{} for orders locking only {}",
+                user, locked, asset, orders_locked
+            ),
+            Self::OrderNotFound { user, order_id } => write!(
+                f,
+                "Order 0x{} of user {:?} not found",
+                Bytes32::from(order_id.0),
+                user
+            ),
+        }
+    }
+}
+
+/// Reconciliation of the accounts and the open orders of a market with its asset balances
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditReport {
+    /// Number of the audited accounts
+    pub users: u64,
+    /// Number of the open orders of the audited accounts
+    pub orders: u64,
+    pub assets: BTreeMap<AssetId, AssetAudit>,
+    pub issues: Vec<AuditIssue>,
+}
+
+impl AuditReport {
+    /// Whether the market covers the balances and the orders of every audited account
+    pub fn is_solvent(&self) -> bool {
+        !self.issues.iter().any(AuditIssue::is_breaking)
+    }
+}
+
+/// Sums the liquid and locked balances of the `users` per asset and compares them with the
+/// asset balances of the market, then checks the locked balances of each user against the
+/// amounts its open orders lock.
+///
+/// The market does not enumerate its accounts, `users` are taken from the deposit and trade
+/// events or an indexer snapshot. The market owner, credited with the protocol fees, is
+/// always audited. A user left out shows as a surplus of the market.
+pub async fn audit<A: FuelsAccount>(
+    market: &SparkMarketContract<A>,
+    users: &[Identity],
+) -> Result<AuditReport, SparkMarketError> {
+    let (base, base_decimals, quote, quote_decimals, owner, price_decimals, _) =
+        market.config().await?.value;
+
+    let mut seen = HashSet::new();
+    let users = users
+        .iter()
+        .copied()
+        .chain(owner)
+        .filter(|user| seen.insert(*user))
+        .collect::<Vec<_>>();
+
+    let mut report = AuditReport {
+        users: users.len() as u64,
+        ..Default::default()
+    };
+    let mut base_audit = AssetAudit::default();
+    let mut quote_audit = AssetAudit::default();
+
+    for chunk in users.chunks(ITEMS_PER_CALL as usize) {
+        let accounts = market.accounts(chunk.to_vec()).await?.value;
+        for (user, account) in chunk.iter().zip(accounts) {
+            base_audit.liquid += account.liquid.base;
+            base_audit.locked += account.locked.base;
+            quote_audit.liquid += account.liquid.quote;
+            quote_audit.locked += account.locked.quote;
+
+            let (mut base_locked, mut quote_locked) = (0, 0);
+            let order_ids = market.user_orders_all(*user, ITEMS_PER_CALL).await?;
+            for ids in order_ids.chunks(ITEMS_PER_CALL as usize) {
+                let orders = market.orders(ids.to_vec()).await?.value;
+                for (order_id, order) in ids.iter().zip(orders) {
+                    let Some(order) = order else {
+                        report.issues.push(AuditIssue::OrderNotFound {
+                            user: *user,
+                            order_id: *order_id,
+                        });
+                        continue;
+                    };
+                    report.orders += 1;
+                    let amount =
+                        lock_order_amount(&order, base_decimals, quote_decimals, price_decimals);
+                    match order.order_type {
+                        OrderType::Sell => base_locked += amount,
+                        OrderType::Buy => quote_locked += amount,
+                    }
+                }
+            }
+            base_audit.orders_locked += base_locked;
+            quote_audit.orders_locked += quote_locked;
+
+            for (asset, locked, orders_locked) in [
+                (base, account.locked.base, base_locked),
+                (quote, account.locked.quote, quote_locked),
+            ] {
+                if locked < orders_locked {
+                    report.issues.push(AuditIssue::LockShortfall {
+                        user: *user,
+                        asset,
+                        locked,
+                        orders_locked,
+                    });
+                } else if locked > orders_locked {
+                    report.issues.push(AuditIssue::LockSurplus {
+                        user: *user,
+                        asset,
+                        locked,
+                        orders_locked,
+                    });
+                }
+            }
+        }
+    }
+
+    let provider = market
+        .instance
+        .account()
+        .try_provider()
+        .map_err(SparkMarketError::Fuels)?
+        .clone();
+    for (asset, mut asset_audit) in [(base, base_audit), (quote, quote_audit)] {
+        asset_audit.contract = provider
+            .get_contract_asset_balance(market.contract_id(), asset)
+            .await
+            .map_err(SparkMarketError::Fuels)?;
+        let accounts = asset_audit.accounts();
+        if asset_audit.contract < accounts {
+            report.issues.push(AuditIssue::Insolvent {
+                asset,
+                accounts,
+                contract: asset_audit.contract,
+            });
+        } else if asset_audit.contract > accounts {
+            report.issues.push(AuditIssue::Surplus {
+                asset,
+                accounts,
+                contract: asset_audit.contract,
+            });
+        }
+        report.assets.insert(asset, asset_audit);
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,54 @@
+use fuels::{
+    accounts::Account as FuelsAccount,
+    core::traits::{Parameterize, Tokenizable},
+    programs::calls::{CallHandler, ContractCall, Execution},
+    tx::Receipt,
+    types::errors::Error as FuelsError,
+};
+use std::fmt::Debug;
+
+/// Outcome of a call that was dry-run against the current chain state and not submitted
+#[derive(Debug)]
+pub struct CallEstimate<T> {
+    /// Value the call would return
+    pub value: T,
+    /// Gas the transaction would use
+    pub gas_used: u64,
+    /// Gas price the fee is estimated with
+    pub gas_price: u64,
+    /// Transaction fee paid in the base asset
+    pub fee: u64,
+    /// Events the call would log, in their debug representation
+    pub logs: Vec<String>,
+    pub receipts: Vec<Receipt>,
+}
+
+/// Dry-runs any contract call handler without broadcasting the transaction.
+///
+/// The call is simulated with the signature and balance checks of a real submission,
+/// so the account must be able to pay for the transaction.
+pub async fn estimate_call<A, T>(
+    mut call_handler: CallHandler<A, ContractCall, T>,
+) -> Result<CallEstimate<T>, FuelsError>
+where
+    A: FuelsAccount,
+    T: Tokenizable + Parameterize + Debug,
+{
+    let cost = call_handler.estimate_transaction_cost(None, None).await?;
+    let response = call_handler.simulate(Execution::Realistic).await?;
+    let logs = response
+        .decode_logs()
+        .results
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(CallEstimate {
+        value: response.value,
+        gas_used: cost.gas_used,
+        gas_price: cost.gas_price,
+        fee: cost.total_fee,
+        logs,
+        receipts: response.receipts,
+    })
+}
@@ -1,17 +1,43 @@
 use fuels::{
+    accounts::{impersonated_account::ImpersonatedAccount, Account as FuelsAccount},
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
     prelude::{
-        abigen, AssetId, CallParameters, Contract, ContractId, LoadConfiguration,
-        StorageConfiguration, TxPolicies, VariableOutputPolicy, WalletUnlocked,
+        abigen, Address, AssetId, CallParameters, Contract, ContractId, LoadConfiguration,
+        Provider, StorageConfiguration, TxPolicies, VariableOutputPolicy, WalletUnlocked,
     },
     programs::{
         calls::{CallHandler, ContractCall, Execution},
         responses::CallResponse,
     },
-    types::{bech32::Bech32ContractId, Bits256, Bytes32, Identity},
+    tx::Receipt,
+    types::{
+        bech32::Bech32ContractId,
+        errors::{transaction::Reason, Error as FuelsError},
+        Bits256, Bytes32, Identity,
+    },
 };
 
 use rand::Rng;
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf};
+
+mod audit;
+mod estimate;
+mod portfolio;
+mod settings;
+mod signer;
+mod tx_builder;
+mod upgrade;
+
+pub use audit::{audit, lock_order_amount, AssetAudit, AuditIssue, AuditReport};
+pub use estimate::{estimate_call, CallEstimate};
+pub use portfolio::{portfolio, AssetPosition, MarketPosition, Portfolio};
+pub use settings::MarketSettings;
+pub use signer::ProcessSigner;
+pub use tx_builder::{MarketCallResult, MarketTxBuilder};
+pub use upgrade::{check_upgrade, MarketBuild, UpgradeIssue, UpgradeReport};
 
 abigen!(
     Contract(
@@ -28,18 +54,680 @@ const MARKET_CONTRACT_BINARY_PATH: &str = "spark-market/out/release/spark-market
 const MARKET_CONTRACT_STORAGE_PATH: &str =
     "spark-market/out/release/spark-market-storage_slots.json";
 
-pub struct SparkMarketContract {
-    instance: SparkMarket<WalletUnlocked>,
+/// Error returned by the market calls.
+/// Reverts are decoded into the error logged by the contract when possible.
+#[derive(Debug)]
+pub enum SparkMarketError {
+    Access(AccessError),
+    Account(AccountError),
+    Asset(AssetError),
+    Auth(AuthError),
+    Emergency(EmergencyError),
+    Initialization(InitializationError),
+    Match(MatchError),
+    Math(MathError),
+    Order(OrderError),
+    Pause(PauseError),
+    Reentrancy(ReentrancyError),
+    Value(ValueError),
+    VersionMismatch { contract: u32, sdk: u32 },
+    ZeroPageSize,
+    Fuels(FuelsError),
+}
+
+impl SparkMarketError {
+    pub(crate) fn decode(decoder: &LogDecoder, error: FuelsError) -> Self {
+        let receipts = match &error {
+            FuelsError::Transaction(Reason::Reverted { receipts, .. }) => receipts,
+            _ => return Self::Fuels(error),
+        };
+
+        fn last_log<T: Tokenizable + Parameterize + 'static>(
+            decoder: &LogDecoder,
+            receipts: &[Receipt],
+        ) -> Option<T> {
+            decoder
+                .decode_logs_with_type::<T>(receipts)
+                .ok()
+                .and_then(|mut logs| logs.pop())
+        }
+
+        if let Some(err) = last_log(decoder, receipts) {
+            Self::Account(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Order(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Match(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Value(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Asset(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Auth(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Emergency(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Math(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Access(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Pause(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Reentrancy(err)
+        } else if let Some(err) = last_log(decoder, receipts) {
+            Self::Initialization(err)
+        } else {
+            Self::Fuels(error)
+        }
+    }
+}
+
+impl fmt::Display for SparkMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Access(err) => write!(f, "AccessError::{:?}", err),
+            Self::Account(err) => write!(f, "AccountError::{:?}", err),
+            Self::Asset(err) => write!(f, "AssetError::{:?}", err),
+            Self::Auth(err) => write!(f, "AuthError::{:?}", err),
+            Self::Emergency(err) => write!(f, "EmergencyError::{:?}", err),
+            Self::Initialization(err) => write!(f, "InitializationError::{:?}", err),
+            Self::Match(err) => write!(f, "MatchError::{:?}", err),
+            Self::Math(err) => write!(f, "MathError::{:?}", err),
+            Self::Order(err) => write!(f, "OrderError::{:?}", err),
+            Self::Pause(err) => write!(f, "PauseError::{:?}", err),
+            Self::Reentrancy(err) => write!(f, "ReentrancyError::{:?}", err),
+            Self::Value(err) => write!(f, "ValueError::{:?}", err),
+            Self::VersionMismatch { contract, sdk } => write!(
+                f,
+                "Market contract version {} mismatch with SDK version {}",
+                str_version(*contract),
+                str_version(*sdk)
+            ),
+            Self::ZeroPageSize => write!(f, "Page size must be greater than zero"),
+            Self::Fuels(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SparkMarketError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Fuels(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<FuelsError> for SparkMarketError {
+    fn from(error: FuelsError) -> Self {
+        Self::Fuels(error)
+    }
+}
+
+/// Contract and SDK version parts required to match on connection
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VersionPolicy {
+    /// Major, minor and patch versions are equal
+    Exact,
+    /// Major versions are equal
+    #[default]
+    Major,
+    /// Major and minor versions are equal
+    Minor,
+}
+
+impl VersionPolicy {
+    pub fn is_compatible(&self, contract: u32, sdk: u32) -> bool {
+        let mask = match self {
+            Self::Exact => 0xFFFFFF,
+            Self::Major => 0xFF0000,
+            Self::Minor => 0xFFFF00,
+        };
+        contract & mask == sdk & mask
+    }
+}
+
+/// Formats a contract version as "major.minor.patch"
+pub fn str_version(version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        (version & 0xFF0000) >> 16,
+        (version & 0xFF00) >> 8,
+        version & 0xFF
+    )
+}
+
+pub struct SparkMarketContract<A = WalletUnlocked> {
+    instance: SparkMarket<A>,
     implementation: ContractId,
 }
 
-impl SparkMarketContract {
+/// Market client for the info methods that does not hold any private key
+pub type SparkMarketReader = SparkMarketContract<ImpersonatedAccount>;
+
+impl<A: FuelsAccount> SparkMarketContract<A> {
+    pub async fn new(contract_id: ContractId, wallet: A) -> Result<Self, SparkMarketError> {
+        Self::new_with_policy(contract_id, wallet, VersionPolicy::default()).await
+    }
+
+    pub async fn new_with_policy(
+        contract_id: ContractId,
+        wallet: A,
+        policy: VersionPolicy,
+    ) -> Result<Self, SparkMarketError> {
+        let market = Self::connect_unchecked(contract_id, wallet).await?;
+        let contract = market.contract_version().await?;
+        let sdk = SparkMarketContract::sdk_version();
+        if !policy.is_compatible(contract, sdk) {
+            return Err(SparkMarketError::VersionMismatch { contract, sdk });
+        }
+        Ok(market)
+    }
+
+    /// Connects to the market without the contract version check
+    pub async fn connect_unchecked(
+        contract_id: ContractId,
+        wallet: A,
+    ) -> Result<Self, SparkMarketError> {
+        let proxy = SparkProxy::new(contract_id, wallet.clone());
+        let result = proxy
+            .methods()
+            .proxy_target()
+            .simulate(Execution::StateReadOnly)
+            .await;
+        let implementation = match result {
+            Ok(response) => response.value.unwrap_or(contract_id),
+            // Not a proxy, the market is called directly
+            Err(FuelsError::Transaction(Reason::Reverted { .. })) => contract_id,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            instance: SparkMarket::new(contract_id, wallet),
+            implementation,
+        })
+    }
+
+    pub fn get_instance(&self) -> &SparkMarket<A> {
+        &self.instance
+    }
+
+    /// Starts a multicall transaction signed by the market account
+    pub fn tx_builder(&self) -> MarketTxBuilder<A> {
+        MarketTxBuilder::new(self.instance.account().clone())
+    }
+
+    /// Dry-runs a market call handler and returns the estimated gas, fee and the would-be
+    /// return value and events without submitting the transaction
+    pub async fn estimate<T: Tokenizable + Parameterize + fmt::Debug>(
+        &self,
+        call_handler: CallHandler<A, ContractCall, T>,
+    ) -> Result<CallEstimate<T>, SparkMarketError> {
+        estimate_call(call_handler)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    /// Decodes the error logged by the reverted market call
+    pub fn decode_error(&self, error: FuelsError) -> SparkMarketError {
+        SparkMarketError::decode(&self.instance.log_decoder(), error)
+    }
+
+    pub fn id(&self) -> Bytes32 {
+        self.instance.contract_id().hash
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        self.instance.contract_id()
+    }
+
+    pub fn implementation_contract_id(&self) -> Bech32ContractId {
+        self.implementation.into()
+    }
+
+    pub async fn contract_version(&self) -> Result<u32, SparkMarketError> {
+        let (_, _, _, _, _, _, version) = self.config().await?.value;
+        Ok(version)
+    }
+
+    pub async fn contract_str_version(&self) -> Result<String, SparkMarketError> {
+        Ok(str_version(self.contract_version().await?))
+    }
+
+    pub async fn account(&self, user: Identity) -> Result<CallResponse<Account>, SparkMarketError> {
+        self.instance
+            .methods()
+            .account(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn accounts(
+        &self,
+        users: Vec<Identity>,
+    ) -> Result<CallResponse<Vec<Account>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .accounts(users)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn protocol_fee(&self) -> Result<CallResponse<Vec<ProtocolFee>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .protocol_fee()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn maker_rebate(&self) -> Result<CallResponse<Vec<u64>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .maker_rebate()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn order_maker_rebate(
+        &self,
+        order: Bits256,
+    ) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .order_maker_rebate(order)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn protocol_fee_user(
+        &self,
+        user: Identity,
+    ) -> Result<CallResponse<(u64, u64)>, SparkMarketError> {
+        self.instance
+            .methods()
+            .protocol_fee_user(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn protocol_fee_user_amount(
+        &self,
+        amount: u64,
+        user: Identity,
+    ) -> Result<CallResponse<(u64, u64)>, SparkMarketError> {
+        self.instance
+            .methods()
+            .protocol_fee_user_amount(amount, user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn matcher_fee(&self) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .matcher_fee()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn is_paused(&self) -> Result<CallResponse<bool>, SparkMarketError> {
+        self.instance
+            .methods()
+            .is_paused()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn store_order_change_info(&self) -> Result<CallResponse<bool>, SparkMarketError> {
+        self.instance
+            .methods()
+            .store_order_change_info()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn rolling_volume(&self) -> Result<CallResponse<bool>, SparkMarketError> {
+        self.instance
+            .methods()
+            .rolling_volume()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn user_volume(
+        &self,
+        user: Identity,
+    ) -> Result<CallResponse<(u64, u64)>, SparkMarketError> {
+        self.instance
+            .methods()
+            .user_volume(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn user_fee_tier(
+        &self,
+        user: Identity,
+    ) -> Result<CallResponse<(u64, ProtocolFee, Option<u64>)>, SparkMarketError> {
+        self.instance
+            .methods()
+            .user_fee_tier(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn has_role(
+        &self,
+        role: Role,
+        account: Identity,
+    ) -> Result<CallResponse<bool>, SparkMarketError> {
+        self.instance
+            .methods()
+            .has_role(role, account)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn emergency(&self) -> Result<CallResponse<bool>, SparkMarketError> {
+        self.instance
+            .methods()
+            .emergency()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn get_epoch(&self) -> Result<CallResponse<(u64, u64)>, SparkMarketError> {
+        self.instance
+            .methods()
+            .get_epoch()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn order(
+        &self,
+        order: Bits256,
+    ) -> Result<CallResponse<Option<Order>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .order(order)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn orders(
+        &self,
+        orders: Vec<Bits256>,
+    ) -> Result<CallResponse<Vec<Option<Order>>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .orders(orders)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn user_orders(
+        &self,
+        user: Identity,
+    ) -> Result<CallResponse<Vec<Bits256>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .user_orders(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn user_orders_paged(
+        &self,
+        user: Identity,
+        offset: u64,
+        limit: u64,
+    ) -> Result<CallResponse<Vec<Bits256>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .user_orders_paged(user, offset, limit)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn user_orders_count(
+        &self,
+        user: Identity,
+    ) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .user_orders_count(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub fn user_orders_pages(
+        &self,
+        user: Identity,
+        page_size: u64,
+    ) -> Result<UserOrdersPages<'_, A>, SparkMarketError> {
+        if page_size == 0 {
+            return Err(SparkMarketError::ZeroPageSize);
+        }
+        Ok(UserOrdersPages {
+            market: self,
+            user,
+            offset: 0,
+            page_size,
+            done: false,
+        })
+    }
+
+    pub async fn user_orders_all(
+        &self,
+        user: Identity,
+        page_size: u64,
+    ) -> Result<Vec<Bits256>, SparkMarketError> {
+        let mut pages = self.user_orders_pages(user, page_size)?;
+        let mut orders = Vec::new();
+        while let Some(page) = pages.next().await? {
+            orders.extend(page);
+        }
+        Ok(orders)
+    }
+
+    pub async fn user_order_height(
+        &self,
+        user: Identity,
+    ) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .user_order_height(user)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn order_change_info(
+        &self,
+        order_id: Bits256,
+    ) -> Result<CallResponse<Vec<OrderChangeInfo>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .order_change_info(order_id)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn order_change_info_paged(
+        &self,
+        order_id: Bits256,
+        offset: u64,
+        limit: u64,
+    ) -> Result<CallResponse<Vec<OrderChangeInfo>>, SparkMarketError> {
+        self.instance
+            .methods()
+            .order_change_info_paged(order_id, offset, limit)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn order_change_info_count(
+        &self,
+        order_id: Bits256,
+    ) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .order_change_info_count(order_id)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub fn order_change_info_pages(
+        &self,
+        order_id: Bits256,
+        page_size: u64,
+    ) -> Result<OrderChangeInfoPages<'_, A>, SparkMarketError> {
+        if page_size == 0 {
+            return Err(SparkMarketError::ZeroPageSize);
+        }
+        Ok(OrderChangeInfoPages {
+            market: self,
+            order_id,
+            offset: 0,
+            page_size,
+            done: false,
+        })
+    }
+
+    pub async fn order_change_info_all(
+        &self,
+        order_id: Bits256,
+        page_size: u64,
+    ) -> Result<Vec<OrderChangeInfo>, SparkMarketError> {
+        let mut pages = self.order_change_info_pages(order_id, page_size)?;
+        let mut changes = Vec::new();
+        while let Some(page) = pages.next().await? {
+            changes.extend(page);
+        }
+        Ok(changes)
+    }
+
+    pub async fn min_order_size(&self) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .min_order_size()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn min_order_price(&self) -> Result<CallResponse<u64>, SparkMarketError> {
+        self.instance
+            .methods()
+            .min_order_price()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn config(
+        &self,
+    ) -> Result<
+        CallResponse<(AssetId, u32, AssetId, u32, Option<Identity>, u32, u32)>,
+        SparkMarketError,
+    > {
+        self.instance
+            .methods()
+            .config()
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub async fn order_id(
+        &self,
+        order_type: OrderType,
+        owner: Identity,
+        price: u64,
+        block_height: u32,
+        order_height: u64,
+    ) -> Result<CallResponse<Bits256>, SparkMarketError> {
+        self.instance
+            .methods()
+            .order_id(order_type, owner, price, block_height, order_height)
+            .with_contract_ids(&[self.implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+}
+
+impl SparkMarketReader {
+    /// Connects to the market for the info methods with the provider only
+    pub async fn read_only(
+        contract_id: ContractId,
+        provider: Provider,
+    ) -> Result<Self, SparkMarketError> {
+        let account = ImpersonatedAccount::new(Address::zeroed().into(), Some(provider));
+        Self::new(contract_id, account).await
+    }
+}
+
+impl<A: FuelsAccount> SparkMarketContract<A> {
     pub async fn deploy(
         base_asset: AssetId,
         base_decimals: u32,
         quote_asset: AssetId,
         quote_decimals: u32,
-        owner: WalletUnlocked,
+        owner: A,
         price_decimals: u32,
         version: u32,
     ) -> anyhow::Result<Self> {
@@ -75,118 +763,54 @@ impl SparkMarketContract {
         .with_salt(salt);
 
         /*let max_allowed = owner
-            .provider()
-            .unwrap()
-            .consensus_parameters()
-            .contract_params()
-            .contract_max_size();
-
-        let code_size = contract.code().len() as u64;
-        assert!(code_size <= max_allowed, "{} - {}", code_size, max_allowed);*/
-
-        let contract_id = contract.deploy(&owner, TxPolicies::default()).await?;
-
-        let market = SparkMarket::new(contract_id.clone(), owner.clone());
-
-        market
-            .methods()
-            .initialize_ownership(owner.address().into())
-            .call()
-            .await?;
-
-        Ok(Self {
-            instance: market,
-            implementation: contract_id.into(),
-        })
-    }
-
-    pub async fn new(contract_id: ContractId, wallet: WalletUnlocked) -> Self {
-        let proxy = SparkProxy::new(contract_id, wallet.clone());
-        let result = proxy
-            .methods()
-            .proxy_target()
-            .simulate(Execution::StateReadOnly)
-            .await;
-        let implementation = match result {
-            Ok(response) => response.value.unwrap(),
-            Err(_) => contract_id,
-        };
-        let _self = Self {
-            instance: SparkMarket::new(contract_id, wallet),
-            implementation: implementation,
-        };
-        assert!(
-            _self.contract_version().await.unwrap() & 0xFF0000 == Self::sdk_version() & 0xFF0000,
-            "Market contract version mismatch with SDK version"
-        );
-        _self
-    }
-
-    pub fn get_instance(&self) -> &SparkMarket<WalletUnlocked> {
-        &self.instance
-    }
-
-    pub fn with_account(&self, account: &WalletUnlocked) -> Self {
-        Self {
-            instance: self.instance.clone().with_account(account.clone()),
-            implementation: self.implementation,
-        }
-    }
-
-    pub fn id(&self) -> Bytes32 {
-        self.instance.contract_id().hash
-    }
+            .provider()
+            .unwrap()
+            .consensus_parameters()
+            .contract_params()
+            .contract_max_size();
 
-    pub fn contract_id(&self) -> &Bech32ContractId {
-        self.instance.contract_id()
-    }
+        let code_size = contract.code().len() as u64;
+        assert!(code_size <= max_allowed, "{} - {}", code_size, max_allowed);*/
 
-    pub fn implementation_contract_id(&self) -> Bech32ContractId {
-        self.implementation.into()
-    }
+        let contract_id = contract.deploy(&owner, TxPolicies::default()).await?;
 
-    pub async fn contract_version(&self) -> anyhow::Result<u32> {
-        let (_, _, _, _, _, _, version) = self.config().await?.value;
-        Ok(version)
-    }
+        let market = SparkMarket::new(contract_id.clone(), owner.clone());
 
-    pub async fn contract_str_version(&self) -> anyhow::Result<String> {
-        let version = self.contract_version().await?;
-        Ok(format!(
-            "{}.{}.{}",
-            (version & 0xFF0000) >> 16,
-            (version & 0xFF00) >> 8,
-            version & 0xFF
-        ))
-    }
+        market
+            .methods()
+            .initialize_ownership(owner.address().into())
+            .call()
+            .await?;
 
-    pub fn sdk_version() -> u32 {
-        let s_version = Self::sdk_str_version();
-        // Converts "0.1.1" string version to 257u32 (0x000101)
-        let version = s_version.split('.').collect::<Vec<&str>>();
-        let len = version.len();
-        version
-            .iter()
-            .enumerate()
-            .map(|(i, &x)| x.parse::<u32>().unwrap() << (8 * (len - i - 1)))
-            .collect::<Vec<u32>>()
-            .iter()
-            .sum()
+        Ok(Self {
+            instance: market,
+            implementation: contract_id.into(),
+        })
     }
 
-    pub fn sdk_str_version() -> String {
-        env!("CARGO_PKG_VERSION").into()
+    pub fn with_account<B: FuelsAccount>(&self, account: &B) -> SparkMarketContract<B> {
+        SparkMarketContract {
+            instance: self.instance.clone().with_account(account.clone()),
+            implementation: self.implementation,
+        }
     }
 
-    pub async fn deposit(&self, amount: u64, asset: AssetId) -> anyhow::Result<CallResponse<()>> {
-        Ok(self.deposit_call_handler(amount, asset).call().await?)
+    pub async fn deposit(
+        &self,
+        amount: u64,
+        asset: AssetId,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.deposit_call_handler(amount, asset)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn deposit_call_handler(
         &self,
         amount: u64,
         asset: AssetId,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    ) -> CallHandler<A, ContractCall, ()> {
         let call_params = CallParameters::new(amount, asset, 1_000_000);
 
         self.instance
@@ -202,11 +826,11 @@ impl SparkMarketContract {
         amount: u64,
         asset: AssetId,
         user: Identity,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .deposit_for_call_handler(amount, asset, user)
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.deposit_for_call_handler(amount, asset, user)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn deposit_for_call_handler(
@@ -214,7 +838,7 @@ impl SparkMarketContract {
         amount: u64,
         asset: AssetId,
         user: Identity,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    ) -> CallHandler<A, ContractCall, ()> {
         let call_params = CallParameters::new(amount, asset, 1_000_000);
 
         self.instance
@@ -229,23 +853,23 @@ impl SparkMarketContract {
         &self,
         amount: u64,
         asset_type: AssetType,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .withdraw_call_handler(amount, asset_type)
-            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.withdraw_call_handler(amount, asset_type)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn withdraw_call_handler(
         &self,
         amount: u64,
         asset_type: AssetType,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    ) -> CallHandler<A, ContractCall, ()> {
         self.instance
             .methods()
             .withdraw(amount, asset_type)
             .with_contract_ids(&[self.implementation.into()])
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
     }
 
     pub async fn withdraw_to_market(
@@ -253,12 +877,11 @@ impl SparkMarketContract {
         amount: u64,
         asset_type: AssetType,
         market: &Bech32ContractId,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .withdraw_to_market_call_handler(amount, asset_type, market)
-            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.withdraw_to_market_call_handler(amount, asset_type, market)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn withdraw_to_market_call_handler(
@@ -266,11 +889,12 @@ impl SparkMarketContract {
         amount: u64,
         asset_type: AssetType,
         market: &Bech32ContractId,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    ) -> CallHandler<A, ContractCall, ()> {
         self.instance
             .methods()
             .withdraw_to_market(amount, asset_type, market)
             .with_contract_ids(&[self.implementation.into(), market.clone()])
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
     }
 
     pub async fn open_order(
@@ -278,11 +902,11 @@ impl SparkMarketContract {
         amount: u64,
         order_type: OrderType,
         price: u64,
-    ) -> anyhow::Result<CallResponse<Bits256>> {
-        Ok(self
-            .open_order_call_handler(amount, order_type, price)
+    ) -> Result<CallResponse<Bits256>, SparkMarketError> {
+        self.open_order_call_handler(amount, order_type, price)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn open_order_call_handler(
@@ -290,70 +914,73 @@ impl SparkMarketContract {
         amount: u64,
         order_type: OrderType,
         price: u64,
-    ) -> CallHandler<WalletUnlocked, ContractCall, Bits256> {
+    ) -> CallHandler<A, ContractCall, Bits256> {
         self.instance
             .methods()
             .open_order(amount, order_type, price)
             .with_contract_ids(&[self.implementation.into()])
     }
 
-    pub async fn cancel_order(&self, order_id: Bits256) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .cancel_order_call_handler(order_id)
-            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    pub async fn cancel_order(
+        &self,
+        order_id: Bits256,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.cancel_order_call_handler(order_id)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub fn cancel_order_call_handler(
-        &self,
-        order_id: Bits256,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    pub fn cancel_order_call_handler(&self, order_id: Bits256) -> CallHandler<A, ContractCall, ()> {
         self.instance
             .methods()
             .cancel_order(order_id)
             .with_contract_ids(&[self.implementation.into()])
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
     }
 
     pub async fn match_order_pair(
         &self,
         order_id0: Bits256,
         order_id1: Bits256,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .match_order_pair_call_handler(order_id0, order_id1)
-            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.match_order_pair_call_handler(order_id0, order_id1)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn match_order_pair_call_handler(
         &self,
         order_id0: Bits256,
         order_id1: Bits256,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    ) -> CallHandler<A, ContractCall, ()> {
         self.instance
             .methods()
             .match_order_pair(order_id0, order_id1)
             .with_contract_ids(&[self.implementation.into()])
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
     }
 
-    pub async fn match_order_many(&self, orders: Vec<Bits256>) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .match_order_many_call_handler(orders)
-            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    pub async fn match_order_many(
+        &self,
+        orders: Vec<Bits256>,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.match_order_many_call_handler(orders)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn match_order_many_call_handler(
         &self,
         orders: Vec<Bits256>,
-    ) -> CallHandler<WalletUnlocked, ContractCall, ()> {
+    ) -> CallHandler<A, ContractCall, ()> {
         self.instance
             .methods()
             .match_order_many(orders)
             .with_contract_ids(&[self.implementation.into()])
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
     }
 
     pub async fn fulfill_many(
@@ -364,12 +991,11 @@ impl SparkMarketContract {
         price: u64,
         slippage: u64,
         orders: Vec<Bits256>,
-    ) -> anyhow::Result<CallResponse<Bits256>> {
-        Ok(self
-            .fulfill_many_call_handler(amount, order_type, limit_type, price, slippage, orders)
-            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+    ) -> Result<CallResponse<Bits256>, SparkMarketError> {
+        self.fulfill_many_call_handler(amount, order_type, limit_type, price, slippage, orders)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
     pub fn fulfill_many_call_handler(
@@ -380,306 +1006,375 @@ impl SparkMarketContract {
         price: u64,
         slippage: u64,
         orders: Vec<Bits256>,
-    ) -> CallHandler<WalletUnlocked, ContractCall, Bits256> {
+    ) -> CallHandler<A, ContractCall, Bits256> {
         self.instance
             .methods()
             .fulfill_order_many(amount, order_type, limit_type, price, slippage, orders)
             .with_contract_ids(&[self.implementation.into()])
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
     }
 
     pub async fn set_protocol_fee(
         &self,
         fee: Vec<ProtocolFee>,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_protocol_fee_call_handler(fee)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub fn set_protocol_fee_call_handler(
+        &self,
+        fee: Vec<ProtocolFee>,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
             .set_protocol_fee(fee)
             .with_contract_ids(&[self.implementation.into()])
+    }
+
+    pub async fn set_maker_rebate(
+        &self,
+        maker_rebate: Vec<u64>,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_maker_rebate_call_handler(maker_rebate)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn set_matcher_fee(&self, amount: u64) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    pub fn set_maker_rebate_call_handler(
+        &self,
+        maker_rebate: Vec<u64>,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .set_matcher_fee(amount)
+            .set_maker_rebate(maker_rebate)
             .with_contract_ids(&[self.implementation.into()])
+    }
+
+    pub async fn set_matcher_fee(&self, amount: u64) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_matcher_fee_call_handler(amount)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub fn set_matcher_fee_call_handler(&self, amount: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance
+            .methods()
+            .set_matcher_fee(amount)
+            .with_contract_ids(&[self.implementation.into()])
     }
 
     pub async fn set_store_order_change_info(
         &self,
         store: bool,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_store_order_change_info_call_handler(store)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
+    }
+
+    pub fn set_store_order_change_info_call_handler(
+        &self,
+        store: bool,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
             .set_store_order_change_info(store)
             .with_contract_ids(&[self.implementation.into()])
+    }
+
+    pub async fn set_min_order_size(
+        &self,
+        size: u64,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_min_order_size_call_handler(size)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn set_min_order_size(&self, size: u64) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    pub fn set_min_order_size_call_handler(&self, size: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
             .set_min_order_size(size)
             .with_contract_ids(&[self.implementation.into()])
+    }
+
+    pub async fn set_min_order_price(
+        &self,
+        price: u64,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_min_order_price_call_handler(price)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn set_min_order_price(&self, price: u64) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    pub fn set_min_order_price_call_handler(&self, price: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
             .set_min_order_price(price)
             .with_contract_ids(&[self.implementation.into()])
-            .call()
-            .await?)
     }
 
-    pub async fn initialize_ownership(
+    pub async fn set_rolling_volume(
         &self,
-        new_owner: Identity,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
-            .methods()
-            .initialize_ownership(new_owner)
-            .with_contract_ids(&[self.implementation.into()])
+        rolling: bool,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_rolling_volume_call_handler(rolling)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn transfer_ownership(
+    pub fn set_rolling_volume_call_handler(
         &self,
-        new_owner: Identity,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+        rolling: bool,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .transfer_ownership(new_owner)
+            .set_rolling_volume(rolling)
             .with_contract_ids(&[self.implementation.into()])
-            .call()
-            .await?)
     }
 
-    pub async fn account(&self, user: Identity) -> anyhow::Result<CallResponse<Account>> {
-        Ok(self
-            .instance
-            .methods()
-            .account(user)
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub async fn grant_role(
+        &self,
+        role: Role,
+        account: Identity,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.grant_role_call_handler(role, account)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn protocol_fee(&self) -> anyhow::Result<CallResponse<Vec<ProtocolFee>>> {
-        Ok(self
-            .instance
+    pub fn grant_role_call_handler(
+        &self,
+        role: Role,
+        account: Identity,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .protocol_fee()
+            .grant_role(role, account)
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
 
-    pub async fn protocol_fee_user(
+    pub async fn revoke_role(
         &self,
-        user: Identity,
-    ) -> anyhow::Result<CallResponse<(u64, u64)>> {
-        Ok(self
-            .instance
-            .methods()
-            .protocol_fee_user(user)
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+        role: Role,
+        account: Identity,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.revoke_role_call_handler(role, account)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn protocol_fee_user_amount(
+    pub fn revoke_role_call_handler(
         &self,
-        amount: u64,
-        user: Identity,
-    ) -> anyhow::Result<CallResponse<(u64, u64)>> {
-        Ok(self
-            .instance
+        role: Role,
+        account: Identity,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .protocol_fee_user_amount(amount, user)
+            .revoke_role(role, account)
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
 
-    pub async fn matcher_fee(&self) -> anyhow::Result<CallResponse<u64>> {
-        Ok(self
-            .instance
-            .methods()
-            .matcher_fee()
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub async fn enable_emergency(&self) -> Result<CallResponse<()>, SparkMarketError> {
+        self.enable_emergency_call_handler()
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn pause(&self) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    pub fn enable_emergency_call_handler(&self) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .pause()
+            .enable_emergency()
             .with_contract_ids(&[self.implementation.into()])
-            .call()
-            .await?)
     }
 
-    pub async fn unpause(&self) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
-            .methods()
-            .unpause()
-            .with_contract_ids(&[self.implementation.into()])
+    pub async fn emergency_exit(&self) -> Result<CallResponse<()>, SparkMarketError> {
+        self.emergency_exit_call_handler()
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn is_paused(&self) -> anyhow::Result<CallResponse<bool>> {
-        Ok(self
-            .instance
+    /// Withdraws both the base and the quote asset of the caller
+    pub fn emergency_exit_call_handler(&self) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .is_paused()
+            .emergency_exit()
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
     }
 
-    pub async fn store_order_change_info(&self) -> anyhow::Result<CallResponse<bool>> {
-        Ok(self
-            .instance
-            .methods()
-            .store_order_change_info()
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub async fn initialize_ownership(
+        &self,
+        new_owner: Identity,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.initialize_ownership_call_handler(new_owner)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn get_epoch(&self) -> anyhow::Result<CallResponse<(u64, u64)>> {
-        Ok(self
-            .instance
+    pub fn initialize_ownership_call_handler(
+        &self,
+        new_owner: Identity,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .get_epoch()
+            .initialize_ownership(new_owner)
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
 
-    pub async fn set_epoch(
+    pub async fn transfer_ownership(
         &self,
-        epoch: u64,
-        epoch_duration: u64,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
-            .methods()
-            .set_epoch(epoch, epoch_duration)
-            .with_contract_ids(&[self.implementation.into()])
+        new_owner: Identity,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.transfer_ownership_call_handler(new_owner)
             .call()
-            .await?)
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn order(&self, order: Bits256) -> anyhow::Result<CallResponse<Option<Order>>> {
-        Ok(self
-            .instance
+    pub fn transfer_ownership_call_handler(
+        &self,
+        new_owner: Identity,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .order(order)
+            .transfer_ownership(new_owner)
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
 
-    pub async fn user_orders(&self, user: Identity) -> anyhow::Result<CallResponse<Vec<Bits256>>> {
-        Ok(self
-            .instance
-            .methods()
-            .user_orders(user)
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub async fn pause(&self) -> Result<CallResponse<()>, SparkMarketError> {
+        self.pause_call_handler()
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn user_order_height(&self, user: Identity) -> anyhow::Result<CallResponse<u64>> {
-        Ok(self
-            .instance
+    pub fn pause_call_handler(&self) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .user_order_height(user)
+            .pause()
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
 
-    pub async fn order_change_info(
-        &self,
-        order_id: Bits256,
-    ) -> anyhow::Result<CallResponse<Vec<OrderChangeInfo>>> {
-        Ok(self
-            .instance
-            .methods()
-            .order_change_info(order_id)
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub async fn unpause(&self) -> Result<CallResponse<()>, SparkMarketError> {
+        self.unpause_call_handler()
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn min_order_size(&self) -> anyhow::Result<CallResponse<u64>> {
-        Ok(self
-            .instance
+    pub fn unpause_call_handler(&self) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .min_order_size()
+            .unpause()
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
 
-    pub async fn min_order_price(&self) -> anyhow::Result<CallResponse<u64>> {
-        Ok(self
-            .instance
-            .methods()
-            .min_order_price()
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub async fn set_epoch(
+        &self,
+        epoch: u64,
+        epoch_duration: u64,
+    ) -> Result<CallResponse<()>, SparkMarketError> {
+        self.set_epoch_call_handler(epoch, epoch_duration)
+            .call()
+            .await
+            .map_err(|err| self.decode_error(err))
     }
 
-    pub async fn config(
+    pub fn set_epoch_call_handler(
         &self,
-    ) -> anyhow::Result<CallResponse<(AssetId, u32, AssetId, u32, Option<Identity>, u32, u32)>>
-    {
-        Ok(self
-            .instance
+        epoch: u64,
+        epoch_duration: u64,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance
             .methods()
-            .config()
+            .set_epoch(epoch, epoch_duration)
             .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
     }
+}
 
-    pub async fn order_id(
-        &self,
-        order_type: OrderType,
-        owner: Identity,
-        price: u64,
-        block_height: u32,
-        order_height: u64,
-    ) -> anyhow::Result<CallResponse<Bits256>> {
-        Ok(self
-            .instance
-            .methods()
-            .order_id(order_type, owner, price, block_height, order_height)
-            .with_contract_ids(&[self.implementation.into()])
-            .simulate(Execution::StateReadOnly)
-            .await?)
+impl SparkMarketContract {
+    pub fn sdk_version() -> u32 {
+        let s_version = Self::sdk_str_version();
+        // Converts "0.1.1" string version to 257u32 (0x000101)
+        let version = s_version.split('.').collect::<Vec<&str>>();
+        let len = version.len();
+        version
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x.parse::<u32>().unwrap() << (8 * (len - i - 1)))
+            .collect::<Vec<u32>>()
+            .iter()
+            .sum()
+    }
+
+    pub fn sdk_str_version() -> String {
+        env!("CARGO_PKG_VERSION").into()
+    }
+}
+
+/// Pages through the user order ids, requesting `page_size` ids per call.
+pub struct UserOrdersPages<'a, A = WalletUnlocked> {
+    market: &'a SparkMarketContract<A>,
+    user: Identity,
+    offset: u64,
+    page_size: u64,
+    done: bool,
+}
+
+impl<A: FuelsAccount> UserOrdersPages<'_, A> {
+    /// Returns the next non-empty page or `None` once all order ids were read.
+    pub async fn next(&mut self) -> Result<Option<Vec<Bits256>>, SparkMarketError> {
+        if self.done {
+            return Ok(None);
+        }
+        let page = self
+            .market
+            .user_orders_paged(self.user, self.offset, self.page_size)
+            .await?
+            .value;
+        self.offset += page.len() as u64;
+        self.done = (page.len() as u64) < self.page_size;
+        Ok((!page.is_empty()).then_some(page))
+    }
+}
+
+/// Pages through the order change history, requesting `page_size` entries per call.
+pub struct OrderChangeInfoPages<'a, A = WalletUnlocked> {
+    market: &'a SparkMarketContract<A>,
+    order_id: Bits256,
+    offset: u64,
+    page_size: u64,
+    done: bool,
+}
+
+impl<A: FuelsAccount> OrderChangeInfoPages<'_, A> {
+    /// Returns the next non-empty page or `None` once all entries were read.
+    pub async fn next(&mut self) -> Result<Option<Vec<OrderChangeInfo>>, SparkMarketError> {
+        if self.done {
+            return Ok(None);
+        }
+        let page = self
+            .market
+            .order_change_info_paged(self.order_id, self.offset, self.page_size)
+            .await?
+            .value;
+        self.offset += page.len() as u64;
+        self.done = (page.len() as u64) < self.page_size;
+        Ok((!page.is_empty()).then_some(page))
     }
 }
@@ -0,0 +1,88 @@
+use crate::{Account, OrderType, SparkMarketContract, SparkMarketError};
+use fuels::{
+    accounts::Account as FuelsAccount,
+    prelude::{AssetId, ContractId},
+    types::Identity,
+};
+use std::collections::BTreeMap;
+
+/// Number of the user orders requested per call
+const ORDERS_PER_CALL: u64 = 100;
+
+/// Funds and open orders of a user in one market
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarketPosition {
+    pub market: ContractId,
+    pub base: AssetId,
+    pub quote: AssetId,
+    pub account: Account,
+    pub open_orders: u64,
+}
+
+/// Funds of a user in one asset summed over the markets
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetPosition {
+    pub liquid: u64,
+    pub locked: u64,
+    /// Open orders locking the asset, sell orders lock the base and buy orders the quote
+    pub open_orders: u64,
+}
+
+/// Cross-market view of the funds of a user
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Portfolio {
+    pub user: Identity,
+    pub markets: Vec<MarketPosition>,
+    pub assets: BTreeMap<AssetId, AssetPosition>,
+}
+
+/// Queries the account and the open orders of the user on each market and sums the
+/// balances per asset
+pub async fn portfolio<A: FuelsAccount>(
+    markets: &[SparkMarketContract<A>],
+    user: Identity,
+) -> Result<Portfolio, SparkMarketError> {
+    let mut positions = Vec::with_capacity(markets.len());
+    let mut assets: BTreeMap<AssetId, AssetPosition> = BTreeMap::new();
+
+    for market in markets.iter() {
+        let (base, _, quote, _, _, _, _) = market.config().await?.value;
+        let account = market.account(user).await?.value;
+        let order_ids = market.user_orders_all(user, ORDERS_PER_CALL).await?;
+
+        let (mut sell_orders, mut buy_orders) = (0, 0);
+        for chunk in order_ids.chunks(ORDERS_PER_CALL as usize) {
+            let orders = market.orders(chunk.to_vec()).await?.value;
+            for order in orders.into_iter().flatten() {
+                match order.order_type {
+                    OrderType::Sell => sell_orders += 1,
+                    OrderType::Buy => buy_orders += 1,
+                }
+            }
+        }
+
+        let base_position = assets.entry(base).or_default();
+        base_position.liquid += account.liquid.base;
+        base_position.locked += account.locked.base;
+        base_position.open_orders += sell_orders;
+
+        let quote_position = assets.entry(quote).or_default();
+        quote_position.liquid += account.liquid.quote;
+        quote_position.locked += account.locked.quote;
+        quote_position.open_orders += buy_orders;
+
+        positions.push(MarketPosition {
+            market: market.contract_id().into(),
+            base,
+            quote,
+            account,
+            open_orders: sell_orders + buy_orders,
+        });
+    }
+
+    Ok(Portfolio {
+        user,
+        markets: positions,
+        assets,
+    })
+}
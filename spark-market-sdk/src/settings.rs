@@ -0,0 +1,115 @@
+use crate::{ProtocolFee, SparkMarketContract, SparkMarketError};
+use fuels::{
+    accounts::Account as FuelsAccount,
+    programs::responses::CallResponse,
+    types::errors::{transaction::Reason, Error as FuelsError},
+};
+
+/// Owner settings of a market, kept across an upgrade of its implementation
+///
+/// The settings an older implementation has no getter for are `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketSettings {
+    pub epoch: (u64, u64),
+    pub protocol_fee: Vec<ProtocolFee>,
+    pub matcher_fee: u64,
+    pub min_order_size: u64,
+    pub min_order_price: u64,
+    pub store_order_change_info: bool,
+    pub rolling_volume: Option<bool>,
+    pub maker_rebate: Option<Vec<u64>>,
+}
+
+impl MarketSettings {
+    /// Reads the settings of the market, skipping the getters its implementation does not have
+    pub async fn read<A: FuelsAccount>(
+        market: &SparkMarketContract<A>,
+    ) -> Result<Self, SparkMarketError> {
+        Ok(Self {
+            epoch: market.get_epoch().await?.value,
+            protocol_fee: market.protocol_fee().await?.value,
+            matcher_fee: market.matcher_fee().await?.value,
+            min_order_size: market.min_order_size().await?.value,
+            min_order_price: market.min_order_price().await?.value,
+            store_order_change_info: market.store_order_change_info().await?.value,
+            rolling_volume: optional(market.rolling_volume().await)?,
+            maker_rebate: optional(market.maker_rebate().await)?,
+        })
+    }
+
+    /// Sets the settings the market reads differently, returns the names of the settings set
+    ///
+    /// The maker rebates are set after the protocol fee as setting the fee resets them.
+    pub async fn replay<A: FuelsAccount>(
+        &self,
+        market: &SparkMarketContract<A>,
+    ) -> Result<Vec<&'static str>, SparkMarketError> {
+        let current = Self::read(market).await?;
+        let mut replayed = Vec::new();
+        if current.epoch != self.epoch {
+            let _ = market.set_epoch(self.epoch.0, self.epoch.1).await?;
+            replayed.push("epoch");
+        }
+        if current.protocol_fee != self.protocol_fee {
+            let _ = market.set_protocol_fee(self.protocol_fee.clone()).await?;
+            replayed.push("protocol fee");
+        }
+        if current.matcher_fee != self.matcher_fee {
+            let _ = market.set_matcher_fee(self.matcher_fee).await?;
+            replayed.push("matcher fee");
+        }
+        if current.min_order_size != self.min_order_size {
+            let _ = market.set_min_order_size(self.min_order_size).await?;
+            replayed.push("minimum order size");
+        }
+        if current.min_order_price != self.min_order_price {
+            let _ = market.set_min_order_price(self.min_order_price).await?;
+            replayed.push("minimum order price");
+        }
+        if current.store_order_change_info != self.store_order_change_info {
+            let _ = market
+                .set_store_order_change_info(self.store_order_change_info)
+                .await?;
+            replayed.push("store order change info");
+        }
+        if let Some(rolling_volume) = self.rolling_volume {
+            if current.rolling_volume != Some(rolling_volume) {
+                let _ = market.set_rolling_volume(rolling_volume).await?;
+                replayed.push("rolling volume");
+            }
+        }
+        if let Some(maker_rebate) = &self.maker_rebate {
+            let current = optional(market.maker_rebate().await)?;
+            if current.as_ref() != Some(maker_rebate) {
+                let _ = market.set_maker_rebate(maker_rebate.clone()).await?;
+                replayed.push("maker rebate");
+            }
+        }
+        Ok(replayed)
+    }
+
+    /// Whether the settings read from the upgraded market keep these settings, the settings
+    /// these do not have are left out
+    pub fn is_kept_by(&self, upgraded: &Self) -> bool {
+        self.epoch == upgraded.epoch
+            && self.protocol_fee == upgraded.protocol_fee
+            && self.matcher_fee == upgraded.matcher_fee
+            && self.min_order_size == upgraded.min_order_size
+            && self.min_order_price == upgraded.min_order_price
+            && self.store_order_change_info == upgraded.store_order_change_info
+            && (self.rolling_volume.is_none() || self.rolling_volume == upgraded.rolling_volume)
+            && (self.maker_rebate.is_none() || self.maker_rebate == upgraded.maker_rebate)
+    }
+}
+
+/// Value of a getter the implementation may not have, a revert without a logged error is
+/// taken as the missing function
+fn optional<T>(
+    result: Result<CallResponse<T>, SparkMarketError>,
+) -> Result<Option<T>, SparkMarketError> {
+    match result {
+        Ok(response) => Ok(Some(response.value)),
+        Err(SparkMarketError::Fuels(FuelsError::Transaction(Reason::Reverted { .. }))) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use fuels::{
+    accounts::{wallet::Wallet, Account, ViewOnlyAccount},
+    core::traits::Signer,
+    crypto::{Message, Signature},
+    prelude::{AssetId, Provider},
+    types::{
+        bech32::Bech32Address,
+        coin_type_id::CoinTypeId,
+        errors::{error, Result},
+        input::Input,
+        transaction_builders::TransactionBuilder,
+    },
+};
+use std::{process::Stdio, str::FromStr};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    process::Command,
+};
+
+/// Account that signs transactions through an external process.
+///
+/// For every signature the `program` is spawned with `args`, the hex encoded 32 byte
+/// message is written to its stdin followed by a new line, and the hex encoded 64 byte
+/// signature is read from the first line of its stdout.
+/// Intended for tests and for wrapping HSM or remote signing tools.
+#[derive(Clone, Debug)]
+pub struct ProcessSigner {
+    wallet: Wallet,
+    program: String,
+    args: Vec<String>,
+}
+
+impl ProcessSigner {
+    pub fn new(
+        address: Bech32Address,
+        program: impl Into<String>,
+        args: Vec<String>,
+        provider: Option<Provider>,
+    ) -> Self {
+        Self {
+            wallet: Wallet::from_address(address, provider),
+            program: program.into(),
+            args,
+        }
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.wallet.set_provider(provider);
+    }
+}
+
+#[async_trait]
+impl Signer for ProcessSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|err| error!(Other, "failed to spawn signer: {err}"))?;
+
+        let request = message
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        let mut stdin = child.stdin.take().expect("signer stdin is piped");
+        stdin
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .map_err(|err| error!(Other, "failed to write to signer: {err}"))?;
+        drop(stdin);
+
+        let stdout = child.stdout.take().expect("signer stdout is piped");
+        let mut response = String::new();
+        BufReader::new(stdout)
+            .read_line(&mut response)
+            .await
+            .map_err(|err| error!(Other, "failed to read from signer: {err}"))?;
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|err| error!(Other, "failed to wait for signer: {err}"))?;
+        if !status.success() {
+            return Err(error!(Other, "signer exited with {status}"));
+        }
+
+        let response = response.trim();
+        Signature::from_str(response.strip_prefix("0x").unwrap_or(response))
+            .map_err(|err| error!(Other, "invalid signer response: {err:?}"))
+    }
+
+    fn address(&self) -> &Bech32Address {
+        self.wallet.address()
+    }
+}
+
+#[async_trait]
+impl ViewOnlyAccount for ProcessSigner {
+    fn address(&self) -> &Bech32Address {
+        self.wallet.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.wallet.try_provider()
+    }
+
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        excluded_coins: Option<Vec<CoinTypeId>>,
+    ) -> Result<Vec<Input>> {
+        self.wallet
+            .get_asset_inputs_for_amount(asset_id, amount, excluded_coins)
+            .await
+    }
+}
+
+#[async_trait]
+impl Account for ProcessSigner {
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(self.clone())?;
+
+        Ok(())
+    }
+}
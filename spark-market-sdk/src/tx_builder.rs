@@ -0,0 +1,273 @@
+use crate::{AssetType, LimitType, OrderType, SparkMarketContract, SparkMarketError};
+use fuels::{
+    accounts::Account as FuelsAccount,
+    core::{codec::LogDecoder, traits::Tokenizable},
+    prelude::{AssetId, TxPolicies, VariableOutputPolicy},
+    programs::{
+        calls::{CallHandler, ContractCall, Execution},
+        responses::CallResponse,
+    },
+    types::{bech32::Bech32ContractId, errors::Error as FuelsError, Bits256, Identity, Token},
+};
+
+/// Result of a single market call batched by `MarketTxBuilder`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarketCallResult {
+    Deposit,
+    DepositFor,
+    Withdraw,
+    WithdrawToMarket,
+    OpenOrder(Bits256),
+    CancelOrder,
+    MatchOrderPair,
+    MatchOrderMany,
+    FulfillMany(Bits256),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum MarketCallKind {
+    Deposit,
+    DepositFor,
+    Withdraw,
+    WithdrawToMarket,
+    OpenOrder,
+    CancelOrder,
+    MatchOrderPair,
+    MatchOrderMany,
+    FulfillMany,
+}
+
+impl MarketCallKind {
+    /// Number of the variable outputs the call may need for asset transfers
+    fn variable_outputs(&self) -> usize {
+        match self {
+            Self::Deposit | Self::DepositFor | Self::OpenOrder => 0,
+            Self::Withdraw
+            | Self::WithdrawToMarket
+            | Self::CancelOrder
+            | Self::MatchOrderPair
+            | Self::MatchOrderMany
+            | Self::FulfillMany => 1,
+        }
+    }
+
+    fn result(&self, token: Token) -> Result<MarketCallResult, FuelsError> {
+        Ok(match self {
+            Self::Deposit => MarketCallResult::Deposit,
+            Self::DepositFor => MarketCallResult::DepositFor,
+            Self::Withdraw => MarketCallResult::Withdraw,
+            Self::WithdrawToMarket => MarketCallResult::WithdrawToMarket,
+            Self::OpenOrder => MarketCallResult::OpenOrder(Bits256::from_token(token)?),
+            Self::CancelOrder => MarketCallResult::CancelOrder,
+            Self::MatchOrderPair => MarketCallResult::MatchOrderPair,
+            Self::MatchOrderMany => MarketCallResult::MatchOrderMany,
+            Self::FulfillMany => MarketCallResult::FulfillMany(Bits256::from_token(token)?),
+        })
+    }
+}
+
+/// Batches market calls of one or more markets into a single multicall transaction.
+///
+/// The implementation contract ids of the proxied markets and the variable outputs
+/// are collected from the added calls, the results are returned in the call order.
+pub struct MarketTxBuilder<A> {
+    multi_call: CallHandler<A, Vec<ContractCall>, ()>,
+    kinds: Vec<MarketCallKind>,
+    log_decoder: Option<LogDecoder>,
+    tx_policies: TxPolicies,
+}
+
+impl<A: FuelsAccount> MarketTxBuilder<A> {
+    pub fn new(account: A) -> Self {
+        Self {
+            multi_call: CallHandler::new_multi_call(account),
+            kinds: Vec::new(),
+            log_decoder: None,
+            tx_policies: TxPolicies::default(),
+        }
+    }
+
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    fn add_call<T: Tokenizable>(
+        mut self,
+        market: &SparkMarketContract<A>,
+        kind: MarketCallKind,
+        call_handler: CallHandler<A, ContractCall, T>,
+    ) -> Self {
+        let market_decoder = market.get_instance().log_decoder();
+        match self.log_decoder.as_mut() {
+            Some(log_decoder) => log_decoder.merge(market_decoder),
+            None => self.log_decoder = Some(market_decoder),
+        }
+        self.multi_call = self.multi_call.add_call(call_handler);
+        self.kinds.push(kind);
+        self
+    }
+
+    pub fn deposit(self, market: &SparkMarketContract<A>, amount: u64, asset: AssetId) -> Self {
+        let call_handler = market.deposit_call_handler(amount, asset);
+        self.add_call(market, MarketCallKind::Deposit, call_handler)
+    }
+
+    pub fn deposit_for(
+        self,
+        market: &SparkMarketContract<A>,
+        amount: u64,
+        asset: AssetId,
+        user: Identity,
+    ) -> Self {
+        let call_handler = market.deposit_for_call_handler(amount, asset, user);
+        self.add_call(market, MarketCallKind::DepositFor, call_handler)
+    }
+
+    pub fn withdraw(
+        self,
+        market: &SparkMarketContract<A>,
+        amount: u64,
+        asset_type: AssetType,
+    ) -> Self {
+        let call_handler = market.withdraw_call_handler(amount, asset_type);
+        self.add_call(market, MarketCallKind::Withdraw, call_handler)
+    }
+
+    pub fn withdraw_to_market(
+        self,
+        market: &SparkMarketContract<A>,
+        amount: u64,
+        asset_type: AssetType,
+        to_market: &Bech32ContractId,
+    ) -> Self {
+        let call_handler = market.withdraw_to_market_call_handler(amount, asset_type, to_market);
+        self.add_call(market, MarketCallKind::WithdrawToMarket, call_handler)
+    }
+
+    pub fn open_order(
+        self,
+        market: &SparkMarketContract<A>,
+        amount: u64,
+        order_type: OrderType,
+        price: u64,
+    ) -> Self {
+        let call_handler = market.open_order_call_handler(amount, order_type, price);
+        self.add_call(market, MarketCallKind::OpenOrder, call_handler)
+    }
+
+    pub fn cancel_order(self, market: &SparkMarketContract<A>, order_id: Bits256) -> Self {
+        let call_handler = market.cancel_order_call_handler(order_id);
+        self.add_call(market, MarketCallKind::CancelOrder, call_handler)
+    }
+
+    pub fn match_order_pair(
+        self,
+        market: &SparkMarketContract<A>,
+        order_id0: Bits256,
+        order_id1: Bits256,
+    ) -> Self {
+        let call_handler = market.match_order_pair_call_handler(order_id0, order_id1);
+        self.add_call(market, MarketCallKind::MatchOrderPair, call_handler)
+    }
+
+    pub fn match_order_many(self, market: &SparkMarketContract<A>, orders: Vec<Bits256>) -> Self {
+        let call_handler = market.match_order_many_call_handler(orders);
+        self.add_call(market, MarketCallKind::MatchOrderMany, call_handler)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn fulfill_many(
+        self,
+        market: &SparkMarketContract<A>,
+        amount: u64,
+        order_type: OrderType,
+        limit_type: LimitType,
+        price: u64,
+        slippage: u64,
+        orders: Vec<Bits256>,
+    ) -> Self {
+        let call_handler = market
+            .fulfill_many_call_handler(amount, order_type, limit_type, price, slippage, orders);
+        self.add_call(market, MarketCallKind::FulfillMany, call_handler)
+    }
+
+    fn into_parts(self) -> (CallHandler<A, Vec<ContractCall>, ()>, BatchResults) {
+        let variable_outputs = self.kinds.iter().map(|kind| kind.variable_outputs()).sum();
+        let multi_call = self
+            .multi_call
+            .with_tx_policies(self.tx_policies)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(variable_outputs));
+        let results = BatchResults {
+            kinds: self.kinds,
+            log_decoder: self.log_decoder,
+        };
+        (multi_call, results)
+    }
+
+    /// Submits the batched calls in one transaction
+    pub async fn call(self) -> Result<CallResponse<Vec<MarketCallResult>>, SparkMarketError> {
+        let (multi_call, results) = self.into_parts();
+        let response = multi_call
+            .call::<Token>()
+            .await
+            .map_err(|err| results.decode_error(err))?;
+        results.decode(response)
+    }
+
+    /// Dry runs the batched calls without submitting the transaction
+    pub async fn simulate(self) -> Result<CallResponse<Vec<MarketCallResult>>, SparkMarketError> {
+        let (multi_call, results) = self.into_parts();
+        let response = multi_call
+            .simulate::<Token>(Execution::Realistic)
+            .await
+            .map_err(|err| results.decode_error(err))?;
+        results.decode(response)
+    }
+}
+
+struct BatchResults {
+    kinds: Vec<MarketCallKind>,
+    log_decoder: Option<LogDecoder>,
+}
+
+impl BatchResults {
+    fn decode_error(&self, error: FuelsError) -> SparkMarketError {
+        match self.log_decoder.as_ref() {
+            Some(log_decoder) => SparkMarketError::decode(log_decoder, error),
+            None => SparkMarketError::Fuels(error),
+        }
+    }
+
+    fn decode(
+        &self,
+        response: CallResponse<Token>,
+    ) -> Result<CallResponse<Vec<MarketCallResult>>, SparkMarketError> {
+        let tokens = match response.value {
+            Token::Tuple(tokens) => tokens,
+            token => vec![token],
+        };
+        let value = self
+            .kinds
+            .iter()
+            .zip(tokens)
+            .map(|(kind, token)| kind.result(token))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(CallResponse {
+            value,
+            receipts: response.receipts,
+            gas_used: response.gas_used,
+            log_decoder: response.log_decoder,
+            tx_id: response.tx_id,
+        })
+    }
+}
@@ -0,0 +1,551 @@
+use anyhow::Context;
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// Build output directory of the market bundled with the sdk
+const MARKET_CONTRACT_OUT_PATH: &str = "spark-market/out/release";
+const MARKET_ABI_FILE: &str = "spark-market-abi.json";
+const MARKET_STORAGE_SLOTS_FILE: &str = "spark-market-storage_slots.json";
+/// Contract source of a forc project relative to its build output directory, forc does not
+/// output the storage field types so they are read from its storage block
+const MARKET_SOURCE_PATH: &str = "../../src/main.sw";
+
+/// ABI, storage slots and storage field types of a market implementation build
+#[derive(Clone, Debug)]
+pub struct MarketBuild {
+    abi: Value,
+    storage_slots: BTreeMap<String, String>,
+    storage_types: Option<BTreeMap<String, String>>,
+}
+
+impl MarketBuild {
+    /// Loads the ABI and the storage slots JSON from a `forc build` output directory, and the
+    /// storage field types from the contract source of the project if found
+    pub fn load(dir: &Path) -> anyhow::Result<Self> {
+        let read = |file: &str| -> anyhow::Result<Value> {
+            let path = dir.join(file);
+            let json = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str(&json).with_context(|| format!("Invalid {}", path.display()))
+        };
+
+        let build = Self::new(read(MARKET_ABI_FILE)?, read(MARKET_STORAGE_SLOTS_FILE)?)?;
+        let source = dir.join(MARKET_SOURCE_PATH);
+        match source.exists() {
+            true => {
+                let source = fs::read_to_string(&source)
+                    .with_context(|| format!("Failed to read {}", source.display()))?;
+                build.with_storage_source(&source)
+            }
+            false => Ok(build),
+        }
+    }
+
+    /// Builds from the parsed ABI and storage slots JSON
+    pub fn new(abi: Value, storage_slots: Value) -> anyhow::Result<Self> {
+        let storage_slots = storage_slots
+            .as_array()
+            .context("Storage slots are not an array")?
+            .iter()
+            .map(|slot| Ok((str_field(slot, "key")?, str_field(slot, "value")?)))
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            abi,
+            storage_slots,
+            storage_types: None,
+        })
+    }
+
+    /// Sets the type of each storage field from the storage block of the contract source
+    /// Ex. `orders: StorageMap<b256, Order> = StorageMap {},` to `StorageMap<b256, Order>`
+    pub fn with_storage_source(mut self, source: &str) -> anyhow::Result<Self> {
+        self.storage_types = Some(storage_block_types(source)?);
+        Ok(self)
+    }
+
+    /// The market build the sdk deploys, with the contract source bundled next to it
+    pub fn bundled() -> anyhow::Result<Self> {
+        Self::load(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(MARKET_CONTRACT_OUT_PATH))
+    }
+
+    /// Function signatures by name, argument names are left out as they are not encoded
+    fn functions(&self) -> anyhow::Result<BTreeMap<String, String>> {
+        let types = TypeResolver::new(&self.abi)?;
+        array_field(&self.abi, "functions")?
+            .iter()
+            .map(|function| {
+                let inputs = array_field(function, "inputs")?
+                    .iter()
+                    .map(|input| types.concrete(&str_field(input, "concreteTypeId")?))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let output = types.concrete(&str_field(function, "output")?)?;
+                Ok((
+                    str_field(function, "name")?,
+                    format!("({}) -> {}", inputs.join(", "), output),
+                ))
+            })
+            .collect()
+    }
+
+    /// Storage field types by name, the structs and enums of the ABI are replaced by their
+    /// layout so that a changed value type of a storage map or vector is told apart. Types the
+    /// ABI does not use are compared by name.
+    fn storage_fields(&self) -> anyhow::Result<Option<BTreeMap<String, String>>> {
+        let Some(storage_types) = &self.storage_types else {
+            return Ok(None);
+        };
+        let types = TypeResolver::new(&self.abi)?;
+        storage_types
+            .iter()
+            .map(|(field, ty)| Ok((field.clone(), types.expand(ty)?)))
+            .collect::<anyhow::Result<_>>()
+            .map(Some)
+    }
+
+    /// Logged types by name with their log id and layout
+    fn events(&self) -> anyhow::Result<BTreeMap<String, String>> {
+        let types = TypeResolver::new(&self.abi)?;
+        array_field(&self.abi, "loggedTypes")?
+            .iter()
+            .map(|logged| {
+                let id = str_field(logged, "concreteTypeId")?;
+                Ok((
+                    types.name(&id)?,
+                    format!(
+                        "{} log id {}",
+                        types.concrete(&id)?,
+                        str_field(logged, "logId")?
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Change between the deployed and the new implementation of a market
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpgradeIssue {
+    /// The slot of a removed or renamed storage field, its value is left behind in the proxy storage
+    StorageSlotRemoved {
+        key: String,
+    },
+    /// The initial value of a storage field changed, the type of the field may have changed
+    StorageSlotChanged {
+        key: String,
+        previous: String,
+        new: String,
+    },
+    /// A new storage field, its initial value is not written to the proxy storage
+    StorageSlotAdded {
+        key: String,
+    },
+    /// A removed or renamed storage field, its map or vector entries are left behind
+    StorageFieldRemoved {
+        name: String,
+    },
+    /// The type of a storage field changed, its stored values are read with the new layout
+    StorageFieldChanged {
+        name: String,
+        previous: String,
+        new: String,
+    },
+    /// The contract source of a build is not found, the stored values of the storage maps and
+    /// vectors are not checked
+    StorageTypesUndeclared,
+    FunctionRemoved {
+        name: String,
+    },
+    /// The argument or return types of a function changed
+    FunctionChanged {
+        name: String,
+        previous: String,
+        new: String,
+    },
+    EventRemoved {
+        name: String,
+    },
+    /// The layout or the log id of an event changed, indexers decoding it break
+    EventChanged {
+        name: String,
+        previous: String,
+        new: String,
+    },
+}
+
+impl UpgradeIssue {
+    /// Whether the change breaks the proxy storage or the integrations of the deployed implementation
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            self,
+            Self::StorageSlotAdded { .. } | Self::StorageTypesUndeclared
+        )
+    }
+}
+
+impl fmt::Display for UpgradeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StorageSlotRemoved { key } => write!(f, "Storage slot {} removed", key),
+            Self::StorageSlotChanged { key, previous, new } => write!(
+                f,
+                "Storage slot {} initial value changed from {} to {}",
+                key, previous, new
+            ),
+            Self::StorageSlotAdded { key } => write!(
+                f,
+                "Storage slot {} added, its initial value is not in the proxy storage",
+                key
+            ),
+            Self::StorageFieldRemoved { name } => write!(
+                f,
+                "Storage field {} removed, its stored values are left behind",
+                name
+            ),
+            Self::StorageFieldChanged {
+                name,
+                previous,
+                new,
+            } => write!(
+                f,
+                "Storage field {} type changed from {} to {}",
+                name, previous, new
+            ),
+            Self::StorageTypesUndeclared => write!(
+                f,
+                "Contract source not found for both builds, the stored values of the storage maps and vectors are not checked"
+            ),
+            Self::FunctionRemoved { name } => write!(f, "Function {} removed", name),
+            Self::FunctionChanged {
+                name,
+                previous,
+                new,
+            } => write!(f, "Function {} changed from {} to {}", name, previous, new),
+            Self::EventRemoved { name } => write!(f, "Event {} removed", name),
+            Self::EventChanged {
+                name,
+                previous,
+                new,
+            } => write!(f, "Event {} changed from {} to {}", name, previous, new),
+        }
+    }
+}
+
+/// Changes found between the deployed and the new implementation
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UpgradeReport {
+    pub issues: Vec<UpgradeIssue>,
+}
+
+impl UpgradeReport {
+    /// Whether the new implementation can be set as the proxy target
+    pub fn is_compatible(&self) -> bool {
+        !self.issues.iter().any(UpgradeIssue::is_breaking)
+    }
+}
+
+/// Compares the storage slots, the storage field types, the functions and the events of the
+/// deployed implementation with the new one. Added storage fields, functions and events are
+/// compatible and not reported.
+pub fn check_upgrade(previous: &MarketBuild, new: &MarketBuild) -> anyhow::Result<UpgradeReport> {
+    let mut issues = Vec::new();
+
+    for (key, value) in previous.storage_slots.iter() {
+        match new.storage_slots.get(key) {
+            None => issues.push(UpgradeIssue::StorageSlotRemoved { key: key.clone() }),
+            Some(new_value) if new_value != value => {
+                issues.push(UpgradeIssue::StorageSlotChanged {
+                    key: key.clone(),
+                    previous: value.clone(),
+                    new: new_value.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for key in new.storage_slots.keys() {
+        if !previous.storage_slots.contains_key(key) {
+            issues.push(UpgradeIssue::StorageSlotAdded { key: key.clone() });
+        }
+    }
+
+    match (previous.storage_fields()?, new.storage_fields()?) {
+        (Some(previous_fields), Some(new_fields)) => {
+            for (name, ty) in previous_fields {
+                match new_fields.get(&name) {
+                    None => issues.push(UpgradeIssue::StorageFieldRemoved { name }),
+                    Some(new_ty) if *new_ty != ty => {
+                        issues.push(UpgradeIssue::StorageFieldChanged {
+                            name,
+                            previous: ty,
+                            new: new_ty.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        _ => issues.push(UpgradeIssue::StorageTypesUndeclared),
+    }
+
+    let functions = new.functions()?;
+    for (name, signature) in previous.functions()? {
+        match functions.get(&name) {
+            None => issues.push(UpgradeIssue::FunctionRemoved { name }),
+            Some(new_signature) if *new_signature != signature => {
+                issues.push(UpgradeIssue::FunctionChanged {
+                    name,
+                    previous: signature,
+                    new: new_signature.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    let events = new.events()?;
+    for (name, layout) in previous.events()? {
+        match events.get(&name) {
+            None => issues.push(UpgradeIssue::EventRemoved { name }),
+            Some(new_layout) if *new_layout != layout => issues.push(UpgradeIssue::EventChanged {
+                name,
+                previous: layout,
+                new: new_layout.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    Ok(UpgradeReport { issues })
+}
+
+/// Resolves the ABI type ids to their full layout with the field names, so that a changed
+/// struct is told apart from the previous one of the same name
+struct TypeResolver<'a> {
+    concrete: BTreeMap<String, &'a Value>,
+    metadata: BTreeMap<u64, &'a Value>,
+}
+
+impl<'a> TypeResolver<'a> {
+    fn new(abi: &'a Value) -> anyhow::Result<Self> {
+        let concrete = array_field(abi, "concreteTypes")?
+            .iter()
+            .map(|ty| Ok((str_field(ty, "concreteTypeId")?, ty)))
+            .collect::<anyhow::Result<_>>()?;
+        let metadata = array_field(abi, "metadataTypes")?
+            .iter()
+            .map(|ty| Ok((u64_field(ty, "metadataTypeId")?, ty)))
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { concrete, metadata })
+    }
+
+    fn concrete_type(&self, id: &str) -> anyhow::Result<&'a Value> {
+        self.concrete
+            .get(id)
+            .copied()
+            .with_context(|| format!("Unknown concrete type {}", id))
+    }
+
+    fn name(&self, id: &str) -> anyhow::Result<String> {
+        str_field(self.concrete_type(id)?, "type")
+    }
+
+    fn concrete(&self, id: &str) -> anyhow::Result<String> {
+        let ty = self.concrete_type(id)?;
+        let Some(metadata_id) = ty.get("metadataTypeId").and_then(Value::as_u64) else {
+            return str_field(ty, "type");
+        };
+        let arguments = match ty.get("typeArguments").and_then(Value::as_array) {
+            Some(arguments) => arguments
+                .iter()
+                .map(|argument| {
+                    let id = argument.as_str().context("Invalid type argument")?;
+                    self.concrete(id)
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        self.metadata_type(metadata_id, &arguments)
+    }
+
+    /// Replaces the struct and enum names of a declared type by their layout
+    /// Ex. `StorageMap<b256, Order>` to `StorageMap<b256, struct Order { .. }>`
+    fn expand(&self, declared: &str) -> anyhow::Result<String> {
+        let mut expanded = String::new();
+        let mut name = String::new();
+        for c in declared.chars().chain(std::iter::once(' ')) {
+            if c.is_alphanumeric() || c == '_' || c == ':' {
+                name.push(c);
+                continue;
+            }
+            if !name.is_empty() {
+                expanded.push_str(&self.named(&name)?);
+                name.clear();
+            }
+            if !c.is_whitespace() {
+                expanded.push(c);
+            } else if expanded.ends_with(',') {
+                expanded.push(' ');
+            }
+        }
+        Ok(expanded)
+    }
+
+    /// Layout of the struct or enum of the ABI with the name, the name itself for the
+    /// primitives and the types the ABI does not use
+    fn named(&self, name: &str) -> anyhow::Result<String> {
+        if let Some((id, _)) = self.concrete.iter().find(|(_, ty)| is_named(ty, name)) {
+            return self.concrete(id);
+        }
+        // Types only nested in other types of the ABI
+        match self.metadata.iter().find(|(_, ty)| is_named(ty, name)) {
+            Some((id, _)) => self.metadata_type(*id, &[]),
+            None => Ok(name.to_string()),
+        }
+    }
+
+    /// Layout of a metadata type, `arguments` replace its type parameters in order
+    fn metadata_type(&self, id: u64, arguments: &[String]) -> anyhow::Result<String> {
+        let ty = self
+            .metadata
+            .get(&id)
+            .copied()
+            .with_context(|| format!("Unknown metadata type {}", id))?;
+        let name = str_field(ty, "type")?;
+
+        let parameters = match ty.get("typeParameters").and_then(Value::as_array) {
+            Some(parameters) => parameters
+                .iter()
+                .map(|parameter| parameter.as_u64().context("Invalid type parameter"))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let generics: BTreeMap<u64, String> = parameters
+            .into_iter()
+            .zip(arguments.iter().cloned())
+            .collect();
+
+        let Some(components) = ty.get("components").and_then(Value::as_array) else {
+            return Ok(name);
+        };
+        let components = components
+            .iter()
+            .map(|component| {
+                let layout = self.component(component, &generics)?;
+                Ok(format!("{}: {}", str_field(component, "name")?, layout))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(format!("{} {{ {} }}", name, components.join(", ")))
+    }
+
+    fn component(
+        &self,
+        component: &Value,
+        generics: &BTreeMap<u64, String>,
+    ) -> anyhow::Result<String> {
+        let type_id = component.get("typeId").context("Missing component type")?;
+        if let Some(id) = type_id.as_str() {
+            return self.concrete(id);
+        }
+        let id = type_id.as_u64().context("Invalid component type")?;
+        if let Some(argument) = generics.get(&id) {
+            return Ok(argument.clone());
+        }
+        let arguments = match component.get("typeArguments").and_then(Value::as_array) {
+            Some(arguments) => arguments
+                .iter()
+                .map(|argument| self.component(argument, generics))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        self.metadata_type(id, &arguments)
+    }
+}
+
+/// Types of the fields of the storage block of a contract source by name
+fn storage_block_types(source: &str) -> anyhow::Result<BTreeMap<String, String>> {
+    let code = source
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let start = code
+        .lines()
+        .position(|line| line.trim_start().starts_with("storage {"))
+        .context("Missing storage block")?;
+    let block = code.lines().skip(start).collect::<Vec<_>>().join("\n");
+    let block = &block[block.find('{').unwrap_or_default() + 1..];
+
+    // Splits the fields at the commas outside of the type arguments and the initial values
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut depth = 0;
+    for c in block.chars() {
+        match c {
+            '{' | '(' | '[' | '<' => depth += 1,
+            '}' | ')' | ']' | '>' if depth > 0 => depth -= 1,
+            '}' => break,
+            ',' if depth == 0 => {
+                fields.push(std::mem::take(&mut field));
+                continue;
+            }
+            _ => {}
+        }
+        field.push(c);
+    }
+    fields.push(field);
+
+    fields
+        .iter()
+        .filter(|field| !field.trim().is_empty())
+        .map(|field| {
+            let (name, declaration) = field
+                .split_once(':')
+                .with_context(|| format!("Unsupported storage field {}", field.trim()))?;
+            let (ty, _) = declaration
+                .split_once('=')
+                .with_context(|| format!("Missing initial value of {}", name.trim()))?;
+            let ty = ty.split_whitespace().collect::<Vec<_>>().join(" ");
+            Ok((name.trim().to_string(), ty))
+        })
+        .collect()
+}
+
+/// Whether the ABI type is the struct or enum with the name, with or without its module path
+fn is_named(ty: &Value, name: &str) -> bool {
+    let Some(ty) = ty.get("type").and_then(Value::as_str) else {
+        return false;
+    };
+    match ty
+        .strip_prefix("struct ")
+        .or_else(|| ty.strip_prefix("enum "))
+    {
+        Some(path) => path == name || path.ends_with(&format!("::{}", name)),
+        None => false,
+    }
+}
+
+fn array_field<'a>(value: &'a Value, field: &str) -> anyhow::Result<&'a Vec<Value>> {
+    value
+        .get(field)
+        .and_then(Value::as_array)
+        .with_context(|| format!("Missing {} array", field))
+}
+
+fn str_field(value: &Value, field: &str) -> anyhow::Result<String> {
+    value
+        .get(field)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .with_context(|| format!("Missing {} string", field))
+}
+
+fn u64_field(value: &Value, field: &str) -> anyhow::Result<u64> {
+    value
+        .get(field)
+        .and_then(Value::as_u64)
+        .with_context(|| format!("Missing {} number", field))
+}
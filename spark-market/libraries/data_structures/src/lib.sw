@@ -9,5 +9,7 @@ pub mod order_type;
 pub mod order;
 pub mod order_change;
 pub mod protocol_fee;
+pub mod role;
+pub mod rolling_volume;
 pub mod user_volume;
 
@@ -40,15 +40,54 @@ impl Vec<ProtocolFee> {
         }
         valid
     }
+
+    pub fn is_maker_rebate_valid(self, maker_rebate: Vec<u64>) -> bool {
+        let mut index = 0;
+        let mut valid = true;
+
+        while index < maker_rebate.len() {
+            let fee = self.get(index).unwrap();
+            let rebate = maker_rebate.get(index).unwrap();
+            // A rebate tier charges no maker fee and is funded from the taker fee
+            if rebate > 0
+                && (fee.maker_fee > 0
+                || rebate > fee.taker_fee)
+            {
+                valid = false;
+                break;
+            }
+            index += 1;
+        }
+        valid
+    }
 }
 
 impl StorageKey<StorageVec<ProtocolFee>> {
     #[storage(read)]
     pub fn get_volume_protocol_fee(self, volume: u64) -> ProtocolFee {
+        if self.len() == 0 {
+            return ProtocolFee::new();
+        }
+        self.get(self.get_volume_protocol_fee_index(volume)).unwrap().read()
+    }
+
+    #[storage(read)]
+    pub fn get_volume_protocol_fee_tier(self, volume: u64) -> (u64, ProtocolFee, Option<u64>) {
         let len = self.len();
         if len == 0 {
-            return ProtocolFee::new();
+            return (0, ProtocolFee::new(), None);
         }
+        let index = self.get_volume_protocol_fee_index(volume);
+        let next_threshold = if index + 1 < len {
+            Some(self.get(index + 1).unwrap().read().volume_threshold)
+        } else {
+            None
+        };
+        (index, self.get(index).unwrap().read(), next_threshold)
+    }
+
+    #[storage(read)]
+    fn get_volume_protocol_fee_index(self, volume: u64) -> u64 {
         let mut index = 0;
         if volume > 0 {
             let mut left = 0;
@@ -64,6 +103,6 @@ impl StorageKey<StorageVec<ProtocolFee>> {
                 }
             }
         }
-        self.get(index).unwrap().read()
+        index
     }
 }
@@ -0,0 +1,40 @@
+library;
+
+use std::hash::{Hash, Hasher};
+
+/// Administrative role granted by the owner, the owner holds every role.
+pub enum Role {
+    /// Pauses and unpauses the market.
+    Pauser: (),
+    /// Sets the protocol fee and the matcher fee.
+    FeeManager: (),
+    /// Sets the epoch, the order minimums, the order change info storing and the volume accounting mode.
+    ParameterManager: (),
+}
+
+impl core::ops::Eq for Role {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Pauser, Self::Pauser) => true,
+            (Self::FeeManager, Self::FeeManager) => true,
+            (Self::ParameterManager, Self::ParameterManager) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Hash for Role {
+    fn hash(self, ref mut state: Hasher) {
+        match self {
+            Self::Pauser => {
+                0_u8.hash(state);
+            }
+            Self::FeeManager => {
+                1_u8.hash(state);
+            }
+            Self::ParameterManager => {
+                2_u8.hash(state);
+            }
+        }
+    }
+}
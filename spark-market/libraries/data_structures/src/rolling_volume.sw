@@ -0,0 +1,58 @@
+library;
+
+/// The number of daily buckets in the rolling volume window.
+pub const ROLLING_VOLUME_DAYS: u64 = 30;
+
+pub struct RollingVolume {
+    // The last day the volume was updated
+    day: u64,
+    // Daily volumes indexed by day % ROLLING_VOLUME_DAYS
+    volumes: [u64; ROLLING_VOLUME_DAYS],
+}
+
+impl RollingVolume {
+    pub fn new() -> Self {
+        Self {
+            day: 0,
+            volumes: [0; ROLLING_VOLUME_DAYS],
+        }
+    }
+
+    pub fn get(self, day: u64) -> u64 {
+        if self.day + ROLLING_VOLUME_DAYS <= day {
+            return 0;
+        }
+        let mut idx = window_start(day);
+        let mut volume = 0;
+        while idx <= self.day {
+            volume += self.volumes[idx % ROLLING_VOLUME_DAYS];
+            idx += 1;
+        }
+        volume
+    }
+
+    pub fn update(ref mut self, day: u64, volume: u64) -> Self {
+        if self.day + ROLLING_VOLUME_DAYS <= day {
+            self.volumes = [0; ROLLING_VOLUME_DAYS];
+            self.day = day;
+        } else if self.day < day {
+            // Clear the buckets of the days passed since the last update
+            let mut idx = self.day + 1;
+            while idx <= day {
+                self.volumes[idx % ROLLING_VOLUME_DAYS] = 0;
+                idx += 1;
+            }
+            self.day = day;
+        }
+        self.volumes[day % ROLLING_VOLUME_DAYS] += volume;
+        self
+    }
+}
+
+pub fn window_start(day: u64) -> u64 {
+    if day + 1 > ROLLING_VOLUME_DAYS {
+        day + 1 - ROLLING_VOLUME_DAYS
+    } else {
+        0
+    }
+}
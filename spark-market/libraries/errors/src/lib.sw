@@ -14,6 +14,7 @@ pub enum ValueError {
     InvalidEpoch: (u64, u64, u64, u64),
     InvalidFeeSorting: (),
     InvalidFeeZeroBased: (),
+    InvalidFeeRebate: (),
     InvalidValueSame: (),
     InvalidMarketSame: (),
 }
@@ -40,6 +41,11 @@ pub enum AuthError {
     Unauthorized: (),
 }
 
+pub enum EmergencyError {
+    Enabled: (),
+    NotEnabled: (),
+}
+
 pub enum AccountError {
     InsufficientBalance: (u64, u64, bool),
 }
@@ -9,6 +9,7 @@ use spark_data_structures::{
     order_change::OrderChangeInfo,
     order_type::OrderType,
     protocol_fee::ProtocolFee,
+    role::Role,
 };
 
 abi SparkMarket {
@@ -60,6 +61,9 @@ abi SparkMarket {
     #[storage(write)]
     fn set_protocol_fee(protocol_fee: Vec<ProtocolFee>);
 
+    #[storage(read, write)]
+    fn set_maker_rebate(maker_rebate: Vec<u64>);
+
     #[storage(read, write)]
     fn set_matcher_fee(amount: u64);
 
@@ -71,12 +75,30 @@ abi SparkMarket {
 
     #[storage(read, write)]
     fn set_min_order_price(price: u64);
+
+    #[storage(read, write)]
+    fn set_rolling_volume(rolling: bool);
+
+    #[storage(read, write)]
+    fn grant_role(role: Role, account: Identity);
+
+    #[storage(read, write)]
+    fn revoke_role(role: Role, account: Identity);
+
+    #[storage(read, write)]
+    fn enable_emergency();
+
+    #[storage(read, write)]
+    fn emergency_exit();
 }
 
 abi SparkMarketInfo {
     #[storage(read)]
     fn account(user: Identity) -> Account;
 
+    #[storage(read)]
+    fn accounts(users: Vec<Identity>) -> Vec<Account>;
+
     #[storage(read)]
     fn get_epoch() -> (u64, u64);
 
@@ -86,6 +108,12 @@ abi SparkMarketInfo {
     #[storage(read)]
     fn protocol_fee() -> Vec<ProtocolFee>;
 
+    #[storage(read)]
+    fn maker_rebate() -> Vec<u64>;
+
+    #[storage(read)]
+    fn order_maker_rebate(order: b256) -> u64;
+
     #[storage(read)]
     fn protocol_fee_user(user: Identity) -> (u64, u64);
 
@@ -95,15 +123,30 @@ abi SparkMarketInfo {
     #[storage(read)]
     fn order(order: b256) -> Option<Order>;
 
+    #[storage(read)]
+    fn orders(orders: Vec<b256>) -> Vec<Option<Order>>;
+
     #[storage(read)]
     fn user_orders(user: Identity) -> Vec<b256>;
 
+    #[storage(read)]
+    fn user_orders_paged(user: Identity, offset: u64, limit: u64) -> Vec<b256>;
+
+    #[storage(read)]
+    fn user_orders_count(user: Identity) -> u64;
+
     #[storage(read)]
     fn user_order_height(user: Identity) -> u64;
 
     #[storage(read)]
     fn order_change_info(order_id: b256) -> Vec<OrderChangeInfo>;
 
+    #[storage(read)]
+    fn order_change_info_paged(order_id: b256, offset: u64, limit: u64) -> Vec<OrderChangeInfo>;
+
+    #[storage(read)]
+    fn order_change_info_count(order_id: b256) -> u64;
+
     #[storage(read)]
     fn min_order_size() -> u64;
 
@@ -123,4 +166,19 @@ abi SparkMarketInfo {
 
     #[storage(read)]
     fn store_order_change_info() -> bool;
+
+    #[storage(read)]
+    fn rolling_volume() -> bool;
+
+    #[storage(read)]
+    fn user_volume(user: Identity) -> (u64, u64);
+
+    #[storage(read)]
+    fn user_fee_tier(user: Identity) -> (u64, ProtocolFee, Option<u64>);
+
+    #[storage(read)]
+    fn has_role(role: Role, account: Identity) -> bool;
+
+    #[storage(read)]
+    fn emergency() -> bool;
 }
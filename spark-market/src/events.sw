@@ -6,6 +6,7 @@ use ::data_structures::{
     limit_type::LimitType,
     order_type::OrderType,
     protocol_fee::ProtocolFee,
+    role::Role,
 };
 
 pub struct DepositEvent {
@@ -48,6 +49,14 @@ pub struct TradeOrderEvent {
     pub b_balance: Account,
     pub seller_is_maker: bool,
 }
+
+pub struct MakerRebateEvent {
+    pub order_id: b256,
+    pub user: Identity,
+    pub amount: u64,
+    pub asset: AssetId,
+}
+
 pub struct WithdrawEvent {
     pub amount: u64,
     pub asset: AssetId,
@@ -72,6 +81,10 @@ pub struct SetProtocolFeeEvent {
     pub protocol_fee: Vec<ProtocolFee>,
 }
 
+pub struct SetMakerRebateEvent {
+    pub maker_rebate: Vec<u64>,
+}
+
 pub struct SetMatcherRewardEvent {
     pub amount: u64,
 }
@@ -80,6 +93,10 @@ pub struct SetStoreOrderChangeInfoEvent {
     pub store: bool,
 }
 
+pub struct SetRollingVolumeEvent {
+    pub rolling: bool,
+}
+
 pub struct SetMinOrderSizeEvent {
     pub size: u64,
 }
@@ -87,3 +104,24 @@ pub struct SetMinOrderSizeEvent {
 pub struct SetMinOrderPriceEvent {
     pub price: u64,
 }
+
+pub struct GrantRoleEvent {
+    pub role: Role,
+    pub account: Identity,
+}
+
+pub struct RevokeRoleEvent {
+    pub role: Role,
+    pub account: Identity,
+}
+
+pub struct EnableEmergencyEvent {
+    pub caller: Identity,
+}
+
+pub struct EmergencyExitEvent {
+    pub user: Identity,
+    pub orders: u64,
+    pub base: u64,
+    pub quote: u64,
+}
@@ -17,18 +17,36 @@ use ::data_structures::{
     order_change::OrderChangeType,
     order_type::OrderType,
     protocol_fee::*,
+    role::Role,
+    rolling_volume::*,
     user_volume::UserVolume,
 };
-use ::errors::{AccountError, AssetError, AuthError, MatchError, MathError, OrderError, ValueError};
+use ::errors::{
+    AccountError,
+    AssetError,
+    AuthError,
+    EmergencyError,
+    MatchError,
+    MathError,
+    OrderError,
+    ValueError,
+};
 use ::events::{
     CancelOrderEvent,
     DepositEvent,
+    EmergencyExitEvent,
+    EnableEmergencyEvent,
+    GrantRoleEvent,
+    MakerRebateEvent,
     OpenOrderEvent,
+    RevokeRoleEvent,
     SetEpochEvent,
+    SetMakerRebateEvent,
     SetMatcherRewardEvent,
     SetMinOrderPriceEvent,
     SetMinOrderSizeEvent,
     SetProtocolFeeEvent,
+    SetRollingVolumeEvent,
     SetStoreOrderChangeInfoEvent,
     TradeOrderEvent,
     WithdrawEvent,
@@ -72,6 +90,7 @@ const ZERO_VALUE = 0;
 const TRUE_VALUE = true;
 // 1 month (86400 * 365.25 / 12)
 const ONE_MONTH_SECONDS = 2629800;
+const ONE_DAY_SECONDS = 86400;
 
 configurable {
     BASE_ASSET: AssetId = AssetId::zero(),
@@ -107,23 +126,35 @@ storage {
     order_change_info: StorageMap<b256, StorageVec<OrderChangeInfo>> = StorageMap {},
     /// Protocol fee.
     protocol_fee: StorageVec<ProtocolFee> = StorageVec {},
+    /// Maker rebate of each protocol fee tier.
+    protocol_fee_rebate: StorageVec<u64> = StorageVec {},
+    /// Maker rebate of each open order, set when the order is opened.
+    order_rebates: StorageMap<b256, u64> = StorageMap {},
     /// User trade volumes.
     user_volumes: StorageMap<Identity, UserVolume> = StorageMap {},
     /// Order height.
     order_heights: StorageMap<Identity, u64> = StorageMap {},
+    /// Use rolling window user volumes for protocol fee tiers.
+    rolling_volume: bool = false,
+    /// User rolling window trade volumes.
+    user_rolling_volumes: StorageMap<Identity, RollingVolume> = StorageMap {},
+    /// Administrative roles granted by the owner.
+    roles: StorageMap<(Role, Identity), bool> = StorageMap {},
+    /// Emergency mode, disables deposits, orders and matching for good.
+    emergency: bool = false,
 }
 
 impl Pausable for Contract {
     #[storage(write)]
     fn pause() {
-        ownership_only_owner();
+        only_owner_or_role(Role::Pauser);
         require_not_paused();
         _pause();
     }
 
     #[storage(write)]
     fn unpause() {
-        ownership_only_owner();
+        only_owner_or_role(Role::Pauser);
         require_paused();
         _unpause();
     }
@@ -155,10 +186,12 @@ impl SparkMarket for Contract {
     ///
     /// * When `msg_amount` == 0.
     /// * When `msg_asset` is neither BASE_ASSET nor QUOTE_ASSET.
+    /// * When the emergency mode is enabled.
     #[payable]
     #[storage(read, write)]
     fn deposit() {
         require_not_paused();
+        require_not_emergency();
         reentrancy_guard();
 
         let user = msg_sender().unwrap();
@@ -188,10 +221,12 @@ impl SparkMarket for Contract {
     ///
     /// * When `msg_amount` == 0.
     /// * When `msg_asset` is neither BASE_ASSET nor QUOTE_ASSET.
+    /// * When the emergency mode is enabled.
     #[payable]
     #[storage(read, write)]
     fn deposit_for(user: Identity) {
         require_not_paused();
+        require_not_emergency();
         reentrancy_guard();
 
         let caller = msg_sender().unwrap();
@@ -295,9 +330,11 @@ impl SparkMarket for Contract {
     ///
     /// * When `amount` == 0 or `amount` exeeds user unlocked asset amount.
     /// * When `price` == 0.
+    /// * When the emergency mode is enabled.
     #[storage(read, write)]
     fn open_order(amount: u64, order_type: OrderType, price: u64) -> b256 {
         require_not_paused();
+        require_not_emergency();
         reentrancy_guard();
 
         open_order_internal(amount, order_type, price, read_matcher_fee())
@@ -332,9 +369,11 @@ impl SparkMarket for Contract {
     /// * When orders with `order0_id` or `order1_id` not found.
     /// * When orders are in same direction ([sell, sell] or [buy, buy]).
     /// * When order buy price lower than order sell price.
+    /// * When the emergency mode is enabled.
     #[storage(read, write)]
     fn match_order_pair(order0_id: b256, order1_id: b256) {
         require_not_paused();
+        require_not_emergency();
         reentrancy_guard();
 
         let order0 = storage.orders.get(order0_id).try_read();
@@ -367,9 +406,11 @@ impl SparkMarket for Contract {
     ///
     /// * When order vector length is less than 2.
     /// * When no any orders can be matched.
+    /// * When the emergency mode is enabled.
     #[storage(read, write)]
     fn match_order_many(orders: Vec<b256>) {
         require_not_paused();
+        require_not_emergency();
         reentrancy_guard();
 
         require(orders.len() >= 2, ValueError::InvalidArrayLength);
@@ -465,6 +506,7 @@ impl SparkMarket for Contract {
     ///
     /// * When order vector length is less than 1.
     /// * When no any orders can be fulfilled.
+    /// * When the emergency mode is enabled.
     #[storage(read, write)]
     fn fulfill_order_many(
         amount: u64,
@@ -475,6 +517,7 @@ impl SparkMarket for Contract {
         orders: Vec<b256>,
     ) -> b256 {
         require_not_paused();
+        require_not_emergency();
         reentrancy_guard();
 
         require(orders.len() > 0, ValueError::InvalidArrayLength);
@@ -552,12 +595,12 @@ impl SparkMarket for Contract {
     ///
     /// ### Reverts
     ///
-    /// * When called by non-owner.
+    /// * When called by neither the owner nor a parameter manager.
     /// * When epoch start less than current epoch start.
     /// * When epoch end less than current time.
     #[storage(write)]
     fn set_epoch(epoch: u64, epoch_duration: u64) {
-        ownership_only_owner();
+        only_owner_or_role(Role::ParameterManager);
 
         let current_epoch = read_epoch();
         let now = block_timestamp();
@@ -588,15 +631,16 @@ impl SparkMarket for Contract {
     ///
     /// * `protocol_fee`: [Vec<ProtocolFee>] - A vector of 'ProtocolFee' structures that define the fee rates and their corresponding volume thresholds.
     ///    The first element must have a volume threshold of zero, and the list must be sorted by volume threshold.
+    ///    The maker rebates of the previous tiers are reset.
     ///
     /// ### Reverts
     ///
-    /// * When called by non-owner.
+    /// * When called by neither the owner nor a fee manager.
     /// * When `protocol_fee` vector length is zero.
     /// * When `protocol_fee` vector contains non-sorted volumes or volume duplicates.
     #[storage(write)]
     fn set_protocol_fee(protocol_fee: Vec<ProtocolFee>) {
-        ownership_only_owner();
+        only_owner_or_role(Role::FeeManager);
 
         if protocol_fee.len() > 0 {
             require(
@@ -613,10 +657,54 @@ impl SparkMarket for Contract {
             ValueError::InvalidFeeSorting,
         );
         storage.protocol_fee.store_vec(protocol_fee);
+        if storage.protocol_fee_rebate.len() > 0 {
+            storage.protocol_fee_rebate.store_vec(Vec::new());
+            log(SetMakerRebateEvent {
+                maker_rebate: Vec::new(),
+            });
+        }
 
         log(SetProtocolFeeEvent { protocol_fee });
     }
 
+    /// Sets the maker rebates of the protocol fee tiers.
+    ///
+    /// ### Additional Information
+    ///
+    /// A maker rebate pays the maker part of the counterparty taker fee instead of charging a maker fee.
+    /// The rebates are kept apart from the protocol fee tiers and are reset when the tiers are set.
+    /// An order takes the rebate of its owner tier when it is opened.
+    ///
+    /// ### Arguments
+    ///
+    /// * `maker_rebate`: [Vec<u64>] - The maker rebate of each protocol fee tier, in the tier order (10_000 == 100%).
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by neither the owner nor a fee manager.
+    /// * When `maker_rebate` vector length differs from the protocol fee tier count.
+    /// * When `maker_rebate` vector contains a maker rebate of a tier with non-zero maker fee or exceeding its taker fee.
+    #[storage(read, write)]
+    fn set_maker_rebate(maker_rebate: Vec<u64>) {
+        only_owner_or_role(Role::FeeManager);
+
+        let protocol_fee = storage.protocol_fee.load_vec();
+        require(
+            maker_rebate
+                .len() == protocol_fee
+                .len(),
+            ValueError::InvalidArrayLength,
+        );
+        require(
+            protocol_fee
+                .is_maker_rebate_valid(maker_rebate),
+            ValueError::InvalidFeeRebate,
+        );
+        storage.protocol_fee_rebate.store_vec(maker_rebate);
+
+        log(SetMakerRebateEvent { maker_rebate });
+    }
+
     /// Sets the matcher fee to a specified amount.
     ///
     /// ### Additional Information
@@ -631,11 +719,11 @@ impl SparkMarket for Contract {
     ///
     /// ### Reverts
     ///
-    /// * When called by non-owner.
+    /// * When called by neither the owner nor a fee manager.
     /// * When `matcher_fee` is same as set before.
     #[storage(read, write)]
     fn set_matcher_fee(amount: u64) {
-        ownership_only_owner();
+        only_owner_or_role(Role::FeeManager);
         require(amount != read_matcher_fee(), ValueError::InvalidValueSame);
         storage.matcher_fee.write(amount);
 
@@ -654,11 +742,11 @@ impl SparkMarket for Contract {
     ///
     /// ### Reverts
     ///
-    /// * When called by non-owner.
+    /// * When called by neither the owner nor a parameter manager.
     /// * When `store` is same as set before.
     #[storage(read, write)]
     fn set_store_order_change_info(store: bool) {
-        ownership_only_owner();
+        only_owner_or_role(Role::ParameterManager);
         require(
             store != read_store_order_change_info(),
             ValueError::InvalidValueSame,
@@ -682,11 +770,11 @@ impl SparkMarket for Contract {
     ///
     /// ### Reverts
     ///
-    /// * When called by non-owner.
+    /// * When called by neither the owner nor a parameter manager.
     /// * When `min_order_size` is same as set before.
     #[storage(read, write)]
     fn set_min_order_size(size: u64) {
-        ownership_only_owner();
+        only_owner_or_role(Role::ParameterManager);
         require(size != read_min_order_size(), ValueError::InvalidValueSame);
         storage.min_order_size.write(size);
 
@@ -707,11 +795,11 @@ impl SparkMarket for Contract {
     ///
     /// ### Reverts
     ///
-    /// * When called by non-owner.
+    /// * When called by neither the owner nor a parameter manager.
     /// * When `min_order_price` is same as set before.
     #[storage(read, write)]
     fn set_min_order_price(price: u64) {
-        ownership_only_owner();
+        only_owner_or_role(Role::ParameterManager);
         require(
             price != read_min_order_price(),
             ValueError::InvalidValueSame,
@@ -720,6 +808,160 @@ impl SparkMarket for Contract {
 
         log(SetMinOrderPriceEvent { price });
     }
+
+    /// Sets the user volume accounting mode.
+    ///
+    /// ### Additional Information
+    ///
+    /// This function allows the contract owner to switch the protocol fee tiers between epoch based user volumes
+    /// and user volumes of a rolling window of the last 30 days.
+    /// Both volumes are accumulated in either mode, the mode selects the one the fee tiers read.
+    ///
+    /// ### Arguments
+    ///
+    /// * `rolling`: [bool] The new rolling volume boolean value.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by neither the owner nor a parameter manager.
+    /// * When `rolling` is same as set before.
+    #[storage(read, write)]
+    fn set_rolling_volume(rolling: bool) {
+        only_owner_or_role(Role::ParameterManager);
+        require(
+            rolling != read_rolling_volume(),
+            ValueError::InvalidValueSame,
+        );
+        storage.rolling_volume.write(rolling);
+
+        log(SetRollingVolumeEvent { rolling });
+    }
+
+    /// Grants an administrative role to an account.
+    ///
+    /// ### Additional Information
+    ///
+    /// This function allows the contract owner to delegate a part of the market administration.
+    /// A pauser can pause and unpause the market, a fee manager can set the protocol and matcher fees
+    /// and a parameter manager can set the epoch, the order minimums, the order change info storing and the volume accounting mode.
+    ///
+    /// ### Arguments
+    ///
+    /// * `role`: [Role] The role to grant.
+    /// * `account`: [Identity] The account to grant the role to.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When `account` already has the `role`.
+    #[storage(read, write)]
+    fn grant_role(role: Role, account: Identity) {
+        ownership_only_owner();
+        require(!read_role(role, account), ValueError::InvalidValueSame);
+        storage.roles.insert((role, account), true);
+
+        log(GrantRoleEvent { role, account });
+    }
+
+    /// Revokes an administrative role from an account.
+    ///
+    /// ### Arguments
+    ///
+    /// * `role`: [Role] The role to revoke.
+    /// * `account`: [Identity] The account to revoke the role from.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When `account` does not have the `role`.
+    #[storage(read, write)]
+    fn revoke_role(role: Role, account: Identity) {
+        ownership_only_owner();
+        require(read_role(role, account), ValueError::InvalidValueSame);
+        let _ = storage.roles.remove((role, account));
+
+        log(RevokeRoleEvent { role, account });
+    }
+
+    /// Enables the emergency mode.
+    ///
+    /// ### Additional Information
+    ///
+    /// This function allows the contract owner to stop the market when its matching state can not be trusted.
+    /// Deposits, new orders and matching are disabled for good, the market has to be redeployed to trade again.
+    /// The users leave the market with `emergency_exit`, which works while the market is paused.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When the emergency mode is already enabled.
+    #[storage(read, write)]
+    fn enable_emergency() {
+        ownership_only_owner();
+        require_not_emergency();
+        storage.emergency.write(true);
+
+        log(EnableEmergencyEvent {
+            caller: msg_sender().unwrap(),
+        });
+    }
+
+    /// Cancels all of the caller's orders and withdraws the whole caller's balance.
+    ///
+    /// ### Additional Information
+    ///
+    /// The funds locked by the cancelled orders are unlocked and withdrawn along with the liquid funds,
+    /// so is any balance left locked without an order, such as the quote rounded up by partial fills.
+    /// A caller with too many orders to cancel within the gas limit cancels a part of them with `cancel_order` first.
+    ///
+    /// ### Reverts
+    ///
+    /// * When the emergency mode is not enabled.
+    #[storage(read, write)]
+    fn emergency_exit() {
+        require(read_emergency(), EmergencyError::NotEnabled);
+        reentrancy_guard();
+
+        let user = msg_sender().unwrap();
+
+        let orders = storage.user_orders.get(user).len();
+        while storage.user_orders.get(user).len() > 0 {
+            let order_id = storage.user_orders.get(user).last().unwrap().read();
+            cancel_read_order(order_id, read_order(order_id));
+        }
+
+        // A balance still locked once the orders are cancelled is withdrawn as well, it is not locked by any order
+        let account = storage.account.get(user).try_read().unwrap_or(Account::new());
+        let base = account.liquid.base + account.locked.base;
+        let quote = account.liquid.quote + account.locked.quote;
+        storage.account.insert(user, Account::new());
+
+        if base > 0 {
+            transfer(user, BASE_ASSET, base);
+            log(WithdrawEvent {
+                amount: base,
+                asset: BASE_ASSET,
+                user,
+                account: Account::new(),
+            });
+        }
+        if quote > 0 {
+            transfer(user, QUOTE_ASSET, quote);
+            log(WithdrawEvent {
+                amount: quote,
+                asset: QUOTE_ASSET,
+                user,
+                account: Account::new(),
+            });
+        }
+
+        log(EmergencyExitEvent {
+            user,
+            orders,
+            base,
+            quote,
+        });
+    }
 }
 
 impl SparkMarketInfo for Contract {
@@ -737,6 +979,27 @@ impl SparkMarketInfo for Contract {
         storage.account.get(user).try_read().unwrap_or(Account::new())
     }
 
+    /// Get the account information of several users.
+    ///
+    /// ### Arguments
+    ///
+    /// * `users`: [Vec<Identity>] The user ids to retrive info.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<Account>] - The user accounts in the order of `users`.
+    #[storage(read)]
+    fn accounts(users: Vec<Identity>) -> Vec<Account> {
+        let mut accounts = Vec::new();
+        let mut idx = 0;
+        while idx < users.len() {
+            let user = users.get(idx).unwrap();
+            accounts.push(storage.account.get(user).try_read().unwrap_or(Account::new()));
+            idx += 1;
+        }
+        accounts
+    }
+
     /// Get the epoch start time and its duration.
     ///
     /// ### Returns
@@ -767,6 +1030,30 @@ impl SparkMarketInfo for Contract {
         storage.protocol_fee.load_vec()
     }
 
+    /// Get the maker rebate of each protocol fee tier.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<u64>] - A maker rebate percent of each tier (10_000 == 100%), empty if no rebates are set.
+    #[storage(read)]
+    fn maker_rebate() -> Vec<u64> {
+        storage.protocol_fee_rebate.load_vec()
+    }
+
+    /// Get the maker rebate an open order takes when matched as the maker.
+    ///
+    /// ### Arguments
+    ///
+    /// * `order`: [b256] The order id.
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - A maker rebate percent (10_000 == 100%).
+    #[storage(read)]
+    fn order_maker_rebate(order: b256) -> u64 {
+        read_order_rebate(order)
+    }
+
     /// Get the user protocol fee of its current volume.
     ///
     /// ### Arguments
@@ -810,6 +1097,26 @@ impl SparkMarketInfo for Contract {
         storage.orders.get(order).try_read()
     }
 
+    /// Get the info of several orders.
+    ///
+    /// ### Arguments
+    ///
+    /// * `orders`: [Vec<b256>] The order ids.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<Option<Order>>] - The Some<Order> struct for each found id otherwise None, in the order of `orders`.
+    #[storage(read)]
+    fn orders(orders: Vec<b256>) -> Vec<Option<Order>> {
+        let mut result = Vec::new();
+        let mut idx = 0;
+        while idx < orders.len() {
+            result.push(storage.orders.get(orders.get(idx).unwrap()).try_read());
+            idx += 1;
+        }
+        result
+    }
+
     /// Get user order list.
     ///
     /// ### Arguments
@@ -824,6 +1131,40 @@ impl SparkMarketInfo for Contract {
         storage.user_orders.get(user).load_vec()
     }
 
+    /// Get a page of user order list.
+    ///
+    /// ### Additional Information
+    ///
+    /// Removing an order moves the last user order to its place, so pages may shift between calls.
+    ///
+    /// ### Arguments
+    ///
+    /// * `user`: [Identity] The user identity.
+    /// * `offset`: [u64] The index of the first order id to return.
+    /// * `limit`: [u64] The maximum number of order ids to return.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<b256>] - The vector of user order ids.
+    #[storage(read)]
+    fn user_orders_paged(user: Identity, offset: u64, limit: u64) -> Vec<b256> {
+        load_vec_page(storage.user_orders.get(user), offset, limit)
+    }
+
+    /// Get user order count.
+    ///
+    /// ### Arguments
+    ///
+    /// * `user`: [Identity] The user identity.
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The number of user open orders.
+    #[storage(read)]
+    fn user_orders_count(user: Identity) -> u64 {
+        storage.user_orders.get(user).len()
+    }
+
     /// Get user order height.
     ///
     /// ### Arguments
@@ -852,6 +1193,36 @@ impl SparkMarketInfo for Contract {
         storage.order_change_info.get(order_id).load_vec()
     }
 
+    /// Get a page of order change list.
+    ///
+    /// ### Arguments
+    ///
+    /// * `order_id`: [b256] The order id.
+    /// * `offset`: [u64] The index of the first order change info to return.
+    /// * `limit`: [u64] The maximum number of order change infos to return.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<OrderChangeInfo>] - The vector of order change info.
+    #[storage(read)]
+    fn order_change_info_paged(order_id: b256, offset: u64, limit: u64) -> Vec<OrderChangeInfo> {
+        load_vec_page(storage.order_change_info.get(order_id), offset, limit)
+    }
+
+    /// Get order change count.
+    ///
+    /// ### Arguments
+    ///
+    /// * `order_id`: [b256] The order id.
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The number of order change infos.
+    #[storage(read)]
+    fn order_change_info_count(order_id: b256) -> u64 {
+        storage.order_change_info.get(order_id).len()
+    }
+
     /// Get the minimum order size in BASE_ASSET units.
     ///
     /// ### Returns
@@ -936,6 +1307,86 @@ impl SparkMarketInfo for Contract {
     fn store_order_change_info() -> bool {
         read_store_order_change_info()
     }
+
+    /// Get rolling volume flag.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - The True if protocol fee tiers use rolling window volumes otherwise false.
+    #[storage(read)]
+    fn rolling_volume() -> bool {
+        read_rolling_volume()
+    }
+
+    /// Get the user trade volume used for protocol fee tiers.
+    ///
+    /// ### Arguments
+    ///
+    /// * `user`: [Identity] The user id to retrive info.
+    ///
+    /// ### Returns
+    ///
+    /// * [(u64, u64)] - A volume period start time (epoch or rolling window start) and volume in `QUOTE_ASSET` units.
+    #[storage(read)]
+    fn user_volume(user: Identity) -> (u64, u64) {
+        user_volume(user)
+    }
+
+    /// Get the user protocol fee tier of its current volume.
+    ///
+    /// ### Arguments
+    ///
+    /// * `user`: [Identity] The user id to retrive info.
+    ///
+    /// ### Returns
+    ///
+    /// * [(u64, ProtocolFee, Option<u64>)] - A fee tier index, the fee tier and the volume threshold of the next tier if any.
+    #[storage(read)]
+    fn user_fee_tier(user: Identity) -> (u64, ProtocolFee, Option<u64>) {
+        let (_, volume) = user_volume(user);
+        storage.protocol_fee.get_volume_protocol_fee_tier(volume)
+    }
+
+    /// Checks whether an account was granted an administrative role.
+    ///
+    /// ### Arguments
+    ///
+    /// * `role`: [Role] The role to check.
+    /// * `account`: [Identity] The account to check.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - True if the role was granted to the account, the owner passes the role checks without a grant.
+    #[storage(read)]
+    fn has_role(role: Role, account: Identity) -> bool {
+        read_role(role, account)
+    }
+
+    /// Checks whether the emergency mode is enabled.
+    ///
+    /// ### Returns
+    ///
+    /// * [bool] - True if deposits, new orders and matching are disabled and the users leave with `emergency_exit`.
+    #[storage(read)]
+    fn emergency() -> bool {
+        read_emergency()
+    }
+}
+
+#[storage(read)]
+fn load_vec_page<V>(vec: StorageKey<StorageVec<V>>, offset: u64, limit: u64) -> Vec<V> {
+    let len = vec.len();
+    let mut page = Vec::new();
+    if offset >= len {
+        return page;
+    }
+    let end = offset + min(limit, len - offset);
+    let mut idx = offset;
+    while idx < end {
+        page.push(vec.get(idx).unwrap().read());
+        idx += 1;
+    }
+    page
 }
 
 #[storage(read)]
@@ -965,6 +1416,11 @@ fn read_store_order_change_info() -> bool {
     storage.store_order_change_info.try_read().unwrap_or(TRUE_VALUE)
 }
 
+#[storage(read)]
+fn read_rolling_volume() -> bool {
+    storage.rolling_volume.try_read().unwrap_or(false)
+}
+
 #[storage(read)]
 fn read_min_order_size() -> u64 {
     storage.min_order_size.try_read().unwrap_or(ZERO_VALUE)
@@ -975,6 +1431,16 @@ fn read_min_order_price() -> u64 {
     storage.min_order_price.try_read().unwrap_or(ZERO_VALUE)
 }
 
+#[storage(read)]
+fn read_emergency() -> bool {
+    storage.emergency.try_read().unwrap_or(false)
+}
+
+#[storage(read)]
+fn require_not_emergency() {
+    require(!read_emergency(), EmergencyError::Enabled);
+}
+
 #[storage(read)]
 fn owner_identity() -> Identity {
     match ownership_owner() {
@@ -983,6 +1449,20 @@ fn owner_identity() -> Identity {
     }
 }
 
+#[storage(read)]
+fn read_role(role: Role, account: Identity) -> bool {
+    storage.roles.get((role, account)).try_read().unwrap_or(false)
+}
+
+/// Reverts with `AccessError::NotOwner` unless the caller is the owner or holds the `role`.
+#[storage(read)]
+fn only_owner_or_role(role: Role) {
+    if read_role(role, msg_sender().unwrap()) {
+        return;
+    }
+    ownership_only_owner();
+}
+
 fn get_asset_type(asset_id: AssetId) -> AssetType {
     if asset_id == BASE_ASSET {
         AssetType::Base
@@ -1030,10 +1510,44 @@ fn lock_order_amount(order: Order) -> u64 {
     }
 }
 
+#[storage(read)]
+fn user_volume(user: Identity) -> (u64, u64) {
+    if read_rolling_volume() {
+        let day = block_timestamp() / ONE_DAY_SECONDS;
+        let volume = storage.user_rolling_volumes.get(user).try_read().unwrap_or(RollingVolume::new()).get(day);
+        (window_start(day) * ONE_DAY_SECONDS, volume)
+    } else {
+        let epoch = read_epoch();
+        let volume = storage.user_volumes.get(user).try_read().unwrap_or(UserVolume::new()).get(epoch);
+        (epoch, volume)
+    }
+}
+
+#[storage(read)]
+fn protocol_fee_tier_user(user: Identity) -> ProtocolFee {
+    let (_, volume) = user_volume(user);
+    storage.protocol_fee.get_volume_protocol_fee(volume)
+}
+
+#[storage(read)]
+fn protocol_fee_rebate_user(user: Identity) -> (ProtocolFee, u64) {
+    let (_, volume) = user_volume(user);
+    let (index, protocol_fee, _) = storage.protocol_fee.get_volume_protocol_fee_tier(volume);
+    let maker_rebate = match storage.protocol_fee_rebate.get(index) {
+        Some(rebate) => rebate.read(),
+        None => 0,
+    };
+    (protocol_fee, maker_rebate)
+}
+
+#[storage(read)]
+fn read_order_rebate(order_id: b256) -> u64 {
+    storage.order_rebates.get(order_id).try_read().unwrap_or(0)
+}
+
 #[storage(read)]
 fn protocol_fee_user(user: Identity) -> (u64, u64) {
-    let volume = storage.user_volumes.get(user).try_read().unwrap_or(UserVolume::new()).get(read_epoch());
-    let protocol_fee = storage.protocol_fee.get_volume_protocol_fee(volume);
+    let protocol_fee = protocol_fee_tier_user(user);
     (protocol_fee.maker_fee, protocol_fee.taker_fee)
 }
 
@@ -1105,7 +1619,7 @@ fn open_order_internal(
     matcher_fee: u64,
 ) -> b256 {
     let user = msg_sender().unwrap();
-    let (protocol_maker_fee, protocol_taker_fee) = protocol_fee_user(user);
+    let (protocol_fee, maker_rebate) = protocol_fee_rebate_user(user);
 
     require(
         valid_order_amount(amount),
@@ -1122,8 +1636,8 @@ fn open_order_internal(
         block_height(),
         next_order_height(user),
         matcher_fee,
-        protocol_maker_fee,
-        protocol_taker_fee,
+        protocol_fee.maker_fee,
+        protocol_fee.taker_fee,
         read_min_order_price(),
     );
 
@@ -1146,6 +1660,9 @@ fn open_order_internal(
 
     // Store the new or updated order
     storage.orders.insert(order_id, order);
+    if maker_rebate > 0 {
+        storage.order_rebates.insert(order_id, maker_rebate);
+    }
 
     // Update user account balance
     let mut account = storage.account.get(user).try_read().unwrap_or(Account::new());
@@ -1264,6 +1781,19 @@ fn increase_user_volume(user: Identity, volume: u64) {
                 .unwrap_or(UserVolume::new())
                 .update(read_epoch(), volume),
         );
+    // Accumulated in both modes so that switching to the rolling window keeps the volumes of the last days
+    let day = block_timestamp() / ONE_DAY_SECONDS;
+    storage
+        .user_rolling_volumes
+        .insert(
+            user,
+            storage
+                .user_rolling_volumes
+                .get(user)
+                .try_read()
+                .unwrap_or(RollingVolume::new())
+                .update(day, volume),
+        );
 }
 
 #[storage(read, write)]
@@ -1274,6 +1804,7 @@ fn remove_order(user: Identity, order_id: b256) {
             .remove(order_id),
         OrderError::FailedToRemove(order_id),
     );
+    let _ = storage.order_rebates.remove(order_id);
 
     let index = storage.user_order_indexes.get(user).get(order_id).read();
     let order_count = storage.user_orders.get(user).len();
@@ -1318,11 +1849,13 @@ fn remove_order(user: Identity, order_id: b256) {
 
 #[storage(read, write)]
 fn execute_trade(
+    s_id: b256,
     s_order: Order,
+    b_id: b256,
     b_order: Order,
     trade_size: u64,
     matcher: Identity,
-) -> (u64, u64, u64) {
+) -> (u64, u64, u64, u64) {
     let asset_type = s_order.asset_type;
     // The volume of the trade for the seller
     let s_trade_volume = quote_of_base_amount(trade_size, s_order.price);
@@ -1337,6 +1870,24 @@ fn execute_trade(
     // The protocol fee for the seller's order based on the trade size and maker/taker conditions
     let s_order_protocol_fee = s_order.protocol_fee_of_amount(b_order, s_trade_volume);
     let b_order_protocol_fee = b_order.protocol_fee_of_amount(s_order, s_trade_volume);
+    // The maker rebate for the seller's order funded from the buyer's protocol fee
+    let s_order_rebate = if s_order.is_maker(b_order) {
+        min(
+            s_trade_volume * read_order_rebate(s_id) / HUNDRED_PERCENT,
+            b_order_protocol_fee,
+        )
+    } else {
+        0
+    };
+    // The maker rebate for the buyer's order funded from the seller's protocol fee
+    let b_order_rebate = if b_order.is_maker(s_order) {
+        min(
+            s_trade_volume * read_order_rebate(b_id) / HUNDRED_PERCENT,
+            s_order_protocol_fee,
+        )
+    } else {
+        0
+    };
 
     // The seller and buyer are the same entity (same owner)
     if s_order.owner == b_order.owner {
@@ -1408,6 +1959,44 @@ fn execute_trade(
         }
     }
 
+    // Handle the maker rebate paid by the seller to the buyer
+    if b_order_rebate > 0 {
+        // If the seller is the buyer
+        if s_order.owner == b_order.owner {
+            let mut account = storage.account.get(s_order.owner).read();
+            account.unlock_amount(b_order_rebate, !asset_type);
+            storage.account.insert(s_order.owner, account);
+        } else {
+            // Transfer the rebate from the seller's protocol fee to the buyer
+            let mut s_account = storage.account.get(s_order.owner).read();
+            let mut b_account = storage.account.get(b_order.owner).read();
+            s_account.transfer_locked_amount(b_account, b_order_rebate, !asset_type);
+            storage.account.insert(s_order.owner, s_account);
+            storage.account.insert(b_order.owner, b_account);
+        }
+    }
+
+    // Handle the maker rebate paid by the buyer to the seller
+    if s_order_rebate > 0 {
+        // If the buyer is the seller
+        if b_order.owner == s_order.owner {
+            let mut account = storage.account.get(b_order.owner).read();
+            account.unlock_amount(s_order_rebate, !asset_type);
+            storage.account.insert(b_order.owner, account);
+        } else {
+            // Transfer the rebate from the buyer's protocol fee to the seller
+            let mut b_account = storage.account.get(b_order.owner).read();
+            let mut s_account = storage.account.get(s_order.owner).read();
+            b_account.transfer_locked_amount(s_account, s_order_rebate, !asset_type);
+            storage.account.insert(b_order.owner, b_account);
+            storage.account.insert(s_order.owner, s_account);
+        }
+    }
+
+    // The rest of the protocol fees goes to the protocol owner
+    let s_order_protocol_fee = s_order_protocol_fee - b_order_rebate;
+    let b_order_protocol_fee = b_order_protocol_fee - s_order_rebate;
+
     let owner = owner_identity();
 
     // Handle the protocol fee related to the seller
@@ -1443,7 +2032,12 @@ fn execute_trade(
             storage.account.insert(owner, o_account);
         }
     }
-    (s_trade_volume, s_order_matcher_fee, b_order_matcher_fee)
+    (
+        s_trade_volume,
+        s_order_matcher_fee,
+        b_order_matcher_fee,
+        s_order_rebate + b_order_rebate,
+    )
 }
 
 #[storage(read, write)]
@@ -1486,7 +2080,7 @@ fn match_order_internal(
     let trade_size = min(s_order.amount, b_order.amount);
 
     // Execute the trade and update balances
-    let (trade_volume, s_order_matcher_fee, b_order_matcher_fee) = execute_trade(s_order, b_order, trade_size, matcher);
+    let (trade_volume, s_order_matcher_fee, b_order_matcher_fee, maker_rebate) = execute_trade(s_id, s_order, b_id, b_order, trade_size, matcher);
 
     increase_user_volume(s_order.owner, trade_volume);
     increase_user_volume(b_order.owner, trade_volume);
@@ -1510,6 +2104,21 @@ fn match_order_internal(
         b_account,
     );
 
+    // Emit the maker rebate paid out of the taker protocol fee
+    if maker_rebate > 0 {
+        let (maker_id, maker) = if s_order.is_maker(b_order) {
+            (s_id, s_order.owner)
+        } else {
+            (b_id, b_order.owner)
+        };
+        log(MakerRebateEvent {
+            order_id: maker_id,
+            user: maker,
+            amount: maker_rebate,
+            asset: get_asset_id(!s_order.asset_type),
+        });
+    }
+
     // Handle partial or full order fulfillment
     let (match_result, partial_order_id) = update_order_storage(
         trade_size,
@@ -0,0 +1,32 @@
+//! Signer process for the `ProcessSigner` tests.
+//! Signs the hex message read from stdin with the secret key given as the first argument.
+
+use fuels::{
+    crypto::{Message, SecretKey, Signature},
+    types::Bytes32,
+};
+use std::{io::BufRead, str::FromStr};
+
+fn main() {
+    let secret = std::env::args()
+        .nth(1)
+        .expect("Missing secret key argument");
+    let secret = SecretKey::from_str(&secret).expect("Invalid secret key");
+
+    let mut line = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .expect("Failed to read message");
+    let message = Bytes32::from_str(line.trim()).expect("Invalid message");
+
+    let signature = Signature::sign(&secret, &Message::from_bytes(*message));
+
+    println!(
+        "{}",
+        signature
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    );
+}
@@ -406,6 +406,7 @@ mod revert {
 
     use super::*;
     use fuels::types::Bits256;
+    use spark_market_sdk::{OrderError, SparkMarketError};
 
     #[tokio::test]
     #[should_panic(expected = "OrderNotFound")]
@@ -423,6 +424,27 @@ mod revert {
         contract.cancel_order(Bits256([0u8; 32])).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn returns_order_not_found_error() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Revert
+        let order_id = Bits256([0u8; 32]);
+        let error = contract.cancel_order(order_id).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            SparkMarketError::Order(OrderError::OrderNotFound(id)) if id == order_id
+        ));
+    }
+
     #[tokio::test]
     #[should_panic(expected = "Unauthorized")]
     async fn when_user_is_not_owner() {
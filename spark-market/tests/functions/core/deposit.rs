@@ -5,7 +5,13 @@ mod success {
 
     use super::*;
     use crate::setup::create_account;
-    use spark_market_sdk::DepositEvent;
+    use fuels::{
+        accounts::{Account, ViewOnlyAccount},
+        crypto::SecretKey,
+        prelude::{TxPolicies, WalletUnlocked},
+        types::Identity,
+    };
+    use spark_market_sdk::{DepositEvent, ProcessSigner};
 
     #[tokio::test]
     async fn base_asset() -> anyhow::Result<()> {
@@ -52,6 +58,48 @@ mod success {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn base_asset_with_process_signer() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let provider = owner.wallet.try_provider()?.clone();
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let wallet = WalletUnlocked::new_from_private_key(secret, Some(provider.clone()));
+        for asset in [*provider.base_asset_id(), assets.base.id] {
+            owner
+                .wallet
+                .transfer(wallet.address(), 1_000_000, asset, TxPolicies::default())
+                .await?;
+        }
+
+        let signer = ProcessSigner::new(
+            wallet.address().clone(),
+            env!("CARGO_BIN_EXE_test-signer"),
+            vec![secret.iter().map(|byte| format!("{:02x}", byte)).collect()],
+            Some(provider),
+        );
+
+        let deposit_amount = 100;
+        let _ = contract
+            .with_account(&signer)
+            .deposit(deposit_amount, assets.base.id)
+            .await?;
+
+        let user = Identity::Address(signer.address().into());
+        assert_eq!(
+            contract.account(user).await?.value,
+            create_account(deposit_amount, 0, 0, 0)
+        );
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     #[ignore]
     async fn fuzz_base_asset() -> anyhow::Result<()> {
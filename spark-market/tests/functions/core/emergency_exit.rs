@@ -0,0 +1,205 @@
+use crate::setup::{setup, Defaults};
+use spark_market_sdk::OrderType;
+
+mod success {
+
+    use super::*;
+    use crate::setup::create_account;
+    use spark_market_sdk::EmergencyExitEvent;
+
+    #[tokio::test]
+    async fn cancels_orders_and_withdraws_all() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let base_amount = 100;
+        let quote_amount = 1_000;
+        let order_amount = 10;
+        let price = 70_000_000_000_000_u64;
+
+        let _ = contract.deposit(base_amount, assets.base.id).await?;
+        let _ = contract.deposit(quote_amount, assets.quote.id).await?;
+        let _ = contract
+            .open_order(order_amount, OrderType::Sell, price)
+            .await?;
+        let _ = contract
+            .open_order(order_amount, OrderType::Sell, price * 2)
+            .await?;
+
+        // Orders of other users are left untouched
+        let other = contract.with_account(&user.wallet);
+        let _ = other.deposit(base_amount, assets.base.id).await?;
+        let other_id = other
+            .open_order(order_amount, OrderType::Sell, price)
+            .await?
+            .value;
+
+        let _ = contract.enable_emergency().await?;
+        // The exit path is kept open while paused
+        let _ = contract.pause().await?;
+
+        let base_balance = owner.balance(&assets.base.id).await;
+        let quote_balance = owner.balance(&assets.quote.id).await;
+
+        let response = contract.emergency_exit().await?;
+
+        let log = response
+            .decode_logs_with_type::<EmergencyExitEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            EmergencyExitEvent {
+                user: owner.identity(),
+                orders: 2,
+                base: base_amount,
+                quote: quote_amount,
+            }
+        );
+
+        assert_eq!(
+            owner.balance(&assets.base.id).await,
+            base_balance + base_amount
+        );
+        assert_eq!(
+            owner.balance(&assets.quote.id).await,
+            quote_balance + quote_amount
+        );
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(0, 0, 0, 0)
+        );
+        assert!(contract
+            .user_orders(owner.identity())
+            .await?
+            .value
+            .is_empty());
+
+        assert_eq!(
+            contract.user_orders(user.identity()).await?.value,
+            vec![other_id]
+        );
+        assert_eq!(
+            contract.account(user.identity()).await?.value,
+            create_account(base_amount - order_amount, 0, order_amount, 0)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exits_without_balance() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.enable_emergency().await?;
+
+        let response = contract.emergency_exit().await?;
+
+        let log = response
+            .decode_logs_with_type::<EmergencyExitEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            EmergencyExitEvent {
+                user: owner.identity(),
+                orders: 0,
+                base: 0,
+                quote: 0,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn withdraws_balance_locked_without_order() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        // A quote unit per base unit and a half, a trade of one base unit rounds its quote down
+        let price = 150_000_000_000_u64;
+        let quote_amount = 3;
+
+        let _ = contract.deposit(quote_amount, assets.quote.id).await?;
+        let buy_id = contract.open_order(2, OrderType::Buy, price).await?.value;
+        let seller = contract.with_account(&user.wallet);
+        let _ = seller.deposit(1, assets.base.id).await?;
+        let sell_id = seller.open_order(1, OrderType::Sell, price).await?.value;
+        let _ = contract.match_order_pair(sell_id, buy_id).await?;
+
+        // The remaining order locks a unit less than the account
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(1, 0, 0, 2)
+        );
+
+        let _ = contract.enable_emergency().await?;
+
+        let quote_balance = owner.balance(&assets.quote.id).await;
+
+        let response = contract.emergency_exit().await?;
+
+        let log = response
+            .decode_logs_with_type::<EmergencyExitEvent>()
+            .unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            EmergencyExitEvent {
+                user: owner.identity(),
+                orders: 1,
+                base: 1,
+                quote: quote_amount - 1,
+            }
+        );
+        assert_eq!(
+            owner.balance(&assets.quote.id).await,
+            quote_balance + quote_amount - 1
+        );
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(0, 0, 0, 0)
+        );
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotEnabled")]
+    async fn reverts_when_not_enabled() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.deposit(100, assets.base.id).await.unwrap();
+
+        // Reverts
+        contract.emergency_exit().await.unwrap();
+    }
+}
@@ -0,0 +1,195 @@
+use crate::setup::{setup, Defaults};
+use spark_market_sdk::OrderType;
+
+mod success {
+
+    use super::*;
+    use spark_market_sdk::{AssetType, EnableEmergencyEvent};
+
+    #[tokio::test]
+    async fn enables_emergency() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        // Assert precondition of the normal mode
+        assert!(!contract.emergency().await?.value);
+
+        let response = contract.enable_emergency().await?;
+
+        // Log should be emitted when the emergency mode is enabled
+        let log = response
+            .decode_logs_with_type::<EnableEmergencyEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            EnableEmergencyEvent {
+                caller: owner.identity(),
+            }
+        );
+
+        assert!(contract.emergency().await?.value);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keeps_cancel_and_withdraw() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let deposit_amount = 100;
+        let _ = contract.deposit(deposit_amount, assets.base.id).await?;
+        let id = contract
+            .open_order(1, OrderType::Sell, 70_000_000_000_000_u64)
+            .await?
+            .value;
+
+        let _ = contract.enable_emergency().await?;
+
+        let _ = contract.cancel_order(id).await?;
+        let _ = contract.withdraw(deposit_amount, AssetType::Base).await?;
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .enable_emergency()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Enabled")]
+    async fn reverts_when_already_enabled() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.enable_emergency().await.unwrap();
+
+        // Reverts
+        contract.enable_emergency().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Enabled")]
+    async fn reverts_deposit_when_enabled() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.enable_emergency().await.unwrap();
+
+        // Reverts
+        contract.deposit(100, assets.base.id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Enabled")]
+    async fn reverts_open_order_when_enabled() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.deposit(100, assets.base.id).await.unwrap();
+        let _ = contract.enable_emergency().await.unwrap();
+
+        // Reverts
+        contract
+            .open_order(1, OrderType::Sell, 70_000_000_000_000_u64)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Enabled")]
+    async fn reverts_match_when_enabled() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user0, user1, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+        let _ = contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await
+            .unwrap();
+        let _ = contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount, assets.quote.id)
+            .await
+            .unwrap();
+
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await
+            .unwrap()
+            .value;
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await
+            .unwrap()
+            .value;
+
+        let _ = contract.enable_emergency().await.unwrap();
+
+        // Reverts
+        contract.match_order_pair(id0, id1).await.unwrap();
+    }
+}
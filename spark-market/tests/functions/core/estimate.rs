@@ -0,0 +1,101 @@
+use crate::setup::{create_account, setup, Defaults};
+use spark_market_sdk::{AccountError, AssetType, OrderType, SparkMarketError};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn open_order_is_not_submitted() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(10, assets.base.id).await?;
+
+        let price = 70_000_000_000_000_u64;
+        let estimate = contract
+            .estimate(contract.open_order_call_handler(2, OrderType::Sell, price))
+            .await?;
+
+        assert!(estimate.gas_used > 0);
+        assert!(estimate
+            .logs
+            .iter()
+            .any(|log| log.starts_with("OpenOrderEvent")));
+
+        assert!(contract
+            .user_orders(owner.identity())
+            .await?
+            .value
+            .is_empty());
+        assert_eq!(contract.order(estimate.value).await?.value, None);
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(10, 0, 0, 0)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn withdraw_is_not_submitted() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(10, assets.base.id).await?;
+        let balance = owner.balance(&assets.base.id).await;
+
+        let estimate = contract
+            .estimate(contract.withdraw_call_handler(10, AssetType::Base))
+            .await?;
+
+        assert!(estimate.gas_used > 0);
+        assert_eq!(owner.balance(&assets.base.id).await, balance);
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(10, 0, 0, 0)
+        );
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn when_insufficient_balance() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.deposit(10, assets.base.id).await.unwrap();
+
+        // Revert
+        let error = contract
+            .estimate(contract.withdraw_call_handler(11, AssetType::Base))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            SparkMarketError::Account(AccountError::InsufficientBalance(_))
+        ));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::setup::{setup, Defaults};
+use spark_market_sdk::Role;
+
+mod success {
+
+    use super::*;
+    use spark_market_sdk::GrantRoleEvent;
+
+    #[tokio::test]
+    async fn grants_role() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        // Assert precondition of no role
+        assert!(
+            !contract
+                .has_role(Role::Pauser, user.identity())
+                .await?
+                .value
+        );
+
+        let response = contract.grant_role(Role::Pauser, user.identity()).await?;
+
+        // Log should be emitted when the role is granted
+        let log = response.decode_logs_with_type::<GrantRoleEvent>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            GrantRoleEvent {
+                role: Role::Pauser,
+                account: user.identity(),
+            }
+        );
+
+        assert!(
+            contract
+                .has_role(Role::Pauser, user.identity())
+                .await?
+                .value
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fee_manager_sets_fee_only() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract
+            .grant_role(Role::FeeManager, user.identity())
+            .await?;
+
+        let new_fee = 5;
+        let _ = contract
+            .with_account(&user.wallet)
+            .set_matcher_fee(new_fee)
+            .await?;
+        assert_eq!(contract.matcher_fee().await?.value, new_fee);
+
+        // Parameters are left to the parameter manager
+        assert!(contract
+            .with_account(&user.wallet)
+            .set_min_order_size(new_fee)
+            .await
+            .is_err());
+        assert!(contract.with_account(&user.wallet).pause().await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parameter_manager_sets_parameters() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract
+            .grant_role(Role::ParameterManager, user.identity())
+            .await?;
+
+        let min_order_size = 10;
+        let _ = contract
+            .with_account(&user.wallet)
+            .set_min_order_size(min_order_size)
+            .await?;
+        assert_eq!(contract.min_order_size().await?.value, min_order_size);
+
+        // Fees are left to the fee manager
+        assert!(contract
+            .with_account(&user.wallet)
+            .set_matcher_fee(5)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .grant_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_role_holder_grants() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, user2, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract
+            .grant_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .grant_role(Role::Pauser, user2.identity())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidValueSame")]
+    async fn reverts_when_already_granted() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract
+            .grant_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+
+        // Reverts
+        contract
+            .grant_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+    }
+}
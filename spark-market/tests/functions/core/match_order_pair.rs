@@ -4,6 +4,7 @@ use spark_market_sdk::{OrderType, ProtocolFee};
 mod success_same_asset_type {
 
     use super::*;
+    use spark_market_sdk::MakerRebateEvent;
 
     #[tokio::test]
     async fn match_same_base_asset_type_orders_same_price() -> anyhow::Result<()> {
@@ -618,6 +619,187 @@ mod success_same_asset_type {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn match_same_base_asset_type_orders_same_price_with_seller_maker_rebate(
+    ) -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, user0, user1, matcher, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let protocol_fee = vec![ProtocolFee {
+            maker_fee: 0,
+            taker_fee: 15,
+            volume_threshold: 0,
+        }];
+        let _ = contract.set_protocol_fee(protocol_fee.clone()).await?;
+        let _ = contract.set_maker_rebate(vec![5]).await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+        let maker_rebate = quote_amount * 5 / 10_000;
+        let taker_protocol_fee = quote_amount * 15 / 10_000;
+
+        contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await?;
+        contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount + taker_protocol_fee, assets.quote.id)
+            .await?;
+
+        // The seller is the maker
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+
+        assert_eq!(contract.order_maker_rebate(id0).await?.value, 5);
+
+        let response = contract
+            .with_account(&matcher.wallet)
+            .match_order_pair(id0, id1)
+            .await?;
+
+        // The rebate is logged for the maker order
+        let log = response
+            .decode_logs_with_type::<MakerRebateEvent>()
+            .unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            MakerRebateEvent {
+                order_id: id0,
+                user: user0.identity(),
+                amount: maker_rebate,
+                asset: assets.quote.id,
+            }
+        );
+        assert_eq!(contract.order_maker_rebate(id0).await?.value, 0);
+
+        let expected_account0 = create_account(0, quote_amount + maker_rebate, 0, 0);
+        let expected_account1 = create_account(base_amount, 0, 0, 0);
+        let expected_account = create_account(0, taker_protocol_fee - maker_rebate, 0, 0);
+
+        assert_eq!(
+            contract.account(user0.identity()).await?.value,
+            expected_account0
+        );
+        assert_eq!(
+            contract.account(user1.identity()).await?.value,
+            expected_account1
+        );
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            expected_account
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn match_same_base_asset_type_orders_same_price_with_buyer_maker_rebate(
+    ) -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, user0, user1, matcher, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let protocol_fee = vec![ProtocolFee {
+            maker_fee: 0,
+            taker_fee: 15,
+            volume_threshold: 0,
+        }];
+        let _ = contract.set_protocol_fee(protocol_fee.clone()).await?;
+        let _ = contract.set_maker_rebate(vec![5]).await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+        let maker_rebate = quote_amount * 5 / 10_000;
+        let taker_protocol_fee = quote_amount * 15 / 10_000;
+
+        contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await?;
+        contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount + taker_protocol_fee, assets.quote.id)
+            .await?;
+
+        // The buyer is the maker
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+
+        assert_eq!(contract.order_maker_rebate(id1).await?.value, 5);
+
+        let response = contract
+            .with_account(&matcher.wallet)
+            .match_order_pair(id0, id1)
+            .await?;
+
+        // The rebate is logged for the maker order
+        let log = response
+            .decode_logs_with_type::<MakerRebateEvent>()
+            .unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            MakerRebateEvent {
+                order_id: id1,
+                user: user1.identity(),
+                amount: maker_rebate,
+                asset: assets.quote.id,
+            }
+        );
+        assert_eq!(contract.order_maker_rebate(id1).await?.value, 0);
+
+        let expected_account0 = create_account(0, quote_amount - taker_protocol_fee, 0, 0);
+        let expected_account1 =
+            create_account(base_amount, taker_protocol_fee + maker_rebate, 0, 0);
+        let expected_account = create_account(0, taker_protocol_fee - maker_rebate, 0, 0);
+
+        assert_eq!(
+            contract.account(user0.identity()).await?.value,
+            expected_account0
+        );
+        assert_eq!(
+            contract.account(user1.identity()).await?.value,
+            expected_account1
+        );
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            expected_account
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn match_same_base_asset_type_orders_same_price_with_protocol_fee_same_user(
     ) -> anyhow::Result<()> {
@@ -1,18 +1,26 @@
 mod cancel_order;
 mod deposit;
 mod deposit_for;
+mod emergency_exit;
+mod enable_emergency;
+mod estimate;
 mod fulfill_order_many;
+mod grant_role;
 mod initialize_ownership;
 mod match_order_many;
 mod match_order_pair;
 mod open_order;
 mod pause;
+mod revoke_role;
 mod set_epoch;
+mod set_maker_rebate;
 mod set_matcher_fee;
 mod set_min_order_price;
 mod set_min_order_size;
 mod set_protocol_fee;
+mod set_rolling_volume;
 mod set_store_order_change_info;
 mod transfer_ownership;
+mod tx_builder;
 mod withdraw;
 mod withdraw_to_market;
@@ -1,4 +1,5 @@
 use crate::setup::{setup, Defaults};
+use spark_market_sdk::Role;
 
 mod success {
 
@@ -31,6 +32,31 @@ mod success {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn pause_by_pauser() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.grant_role(Role::Pauser, user.identity()).await?;
+        let pauser = contract.with_account(&user.wallet);
+
+        let _ = pauser.pause().await?;
+        assert_eq!(contract.is_paused().await?.value, true);
+
+        let _ = pauser.unpause().await?;
+        assert_eq!(contract.is_paused().await?.value, false);
+
+        // A pauser can not change the fees
+        assert!(pauser.set_matcher_fee(5).await.is_err());
+
+        Ok(())
+    }
 }
 
 mod revert {
@@ -0,0 +1,95 @@
+use crate::setup::{setup, Defaults};
+use spark_market_sdk::Role;
+
+mod success {
+
+    use super::*;
+    use spark_market_sdk::RevokeRoleEvent;
+
+    #[tokio::test]
+    async fn revokes_role() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.grant_role(Role::Pauser, user.identity()).await?;
+
+        let response = contract.revoke_role(Role::Pauser, user.identity()).await?;
+
+        // Log should be emitted when the role is revoked
+        let log = response.decode_logs_with_type::<RevokeRoleEvent>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RevokeRoleEvent {
+                role: Role::Pauser,
+                account: user.identity(),
+            }
+        );
+
+        assert!(
+            !contract
+                .has_role(Role::Pauser, user.identity())
+                .await?
+                .value
+        );
+
+        // The former pauser can no longer pause
+        assert!(contract.with_account(&user.wallet).pause().await.is_err());
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract
+            .grant_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .revoke_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidValueSame")]
+    async fn reverts_when_not_granted() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Reverts
+        contract
+            .revoke_role(Role::Pauser, user.identity())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,162 @@
+use crate::setup::{setup, Defaults};
+use spark_market_sdk::ProtocolFee;
+
+fn protocol_fee() -> Vec<ProtocolFee> {
+    vec![
+        ProtocolFee {
+            maker_fee: 10,
+            taker_fee: 15,
+            volume_threshold: 0,
+        },
+        ProtocolFee {
+            maker_fee: 0,
+            taker_fee: 12,
+            volume_threshold: 10000,
+        },
+    ]
+}
+
+mod success {
+
+    use super::*;
+    use spark_market_sdk::SetMakerRebateEvent;
+
+    #[tokio::test]
+    async fn sets_maker_rebate() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.set_protocol_fee(protocol_fee()).await?;
+        assert!(contract.maker_rebate().await?.value.is_empty());
+
+        let response = contract.set_maker_rebate(vec![0, 5]).await?;
+
+        // Log should be emitted when the rebate is changed
+        let log = response
+            .decode_logs_with_type::<SetMakerRebateEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            SetMakerRebateEvent {
+                maker_rebate: vec![0, 5]
+            }
+        );
+        assert_eq!(contract.maker_rebate().await?.value, vec![0, 5]);
+        // The fee tiers keep their stored layout
+        assert_eq!(contract.protocol_fee().await?.value, protocol_fee());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resets_maker_rebate_with_protocol_fee() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.set_protocol_fee(protocol_fee()).await?;
+        let _ = contract.set_maker_rebate(vec![0, 5]).await?;
+
+        let response = contract.set_protocol_fee(protocol_fee()).await?;
+
+        let log = response
+            .decode_logs_with_type::<SetMakerRebateEvent>()
+            .unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            SetMakerRebateEvent {
+                maker_rebate: vec![]
+            }
+        );
+        assert!(contract.maker_rebate().await?.value.is_empty());
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.set_protocol_fee(protocol_fee()).await.unwrap();
+
+        contract
+            .with_account(&user.wallet)
+            .set_maker_rebate(vec![0, 5])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidArrayLength")]
+    async fn reverts_when_tier_count_differs() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.set_protocol_fee(protocol_fee()).await.unwrap();
+
+        contract.set_maker_rebate(vec![5]).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidFeeRebate")]
+    async fn maker_rebate_with_non_zero_maker_fee() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.set_protocol_fee(protocol_fee()).await.unwrap();
+
+        contract.set_maker_rebate(vec![5, 5]).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidFeeRebate")]
+    async fn maker_rebate_exceeds_taker_fee() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let _ = contract.set_protocol_fee(protocol_fee()).await.unwrap();
+
+        contract.set_maker_rebate(vec![0, 20]).await.unwrap();
+    }
+}
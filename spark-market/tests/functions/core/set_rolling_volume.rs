@@ -0,0 +1,76 @@
+use crate::setup::{setup, Defaults};
+
+mod success {
+
+    use super::*;
+    use spark_market_sdk::SetRollingVolumeEvent;
+
+    #[tokio::test]
+    async fn set_rolling_volume() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        // Assert precondition of epoch based volumes
+        assert_eq!(contract.rolling_volume().await?.value, false);
+
+        let response = contract.set_rolling_volume(true).await?;
+
+        // Log should be emitted when volume mode is changed
+        let log = response
+            .decode_logs_with_type::<SetRollingVolumeEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(*event, SetRollingVolumeEvent { rolling: true });
+
+        // Check volume mode has changed
+        assert_eq!(contract.rolling_volume().await?.value, true);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .set_rolling_volume(true)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidValueSame")]
+    async fn reverts_when_same_value() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _, _, _, _) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Reverts
+        contract.set_rolling_volume(false).await.unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+use crate::setup::{clone_market, create_account, setup, Defaults};
+use spark_market_sdk::{MarketCallResult, OrderType};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn deposit_open_open_cancel() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let price = 70_000_000_000_000_u64;
+        let response = contract
+            .tx_builder()
+            .deposit(&contract, 10, assets.base.id)
+            .open_order(&contract, 2, OrderType::Sell, price)
+            .open_order(&contract, 3, OrderType::Sell, price + 1)
+            .call()
+            .await?;
+
+        let results = response.value;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], MarketCallResult::Deposit);
+        let (id0, id1) = match (&results[1], &results[2]) {
+            (MarketCallResult::OpenOrder(id0), MarketCallResult::OpenOrder(id1)) => (*id0, *id1),
+            _ => panic!("Unexpected results: {:?}", results),
+        };
+        assert_eq!(
+            contract.user_orders(owner.identity()).await?.value,
+            vec![id0, id1]
+        );
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(5, 0, 5, 0)
+        );
+
+        let response = contract
+            .tx_builder()
+            .cancel_order(&contract, id0)
+            .cancel_order(&contract, id1)
+            .open_order(&contract, 4, OrderType::Sell, price)
+            .call()
+            .await?;
+
+        let results = response.value;
+        assert_eq!(results[0], MarketCallResult::CancelOrder);
+        assert_eq!(results[1], MarketCallResult::CancelOrder);
+        let id2 = match results[2] {
+            MarketCallResult::OpenOrder(id) => id,
+            _ => panic!("Unexpected results: {:?}", results),
+        };
+        assert_eq!(
+            contract.user_orders(owner.identity()).await?.value,
+            vec![id2]
+        );
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(6, 0, 4, 0)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn across_markets() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+        let market = clone_market(owner.wallet.clone(), &contract).await?;
+
+        let response = contract
+            .tx_builder()
+            .deposit(&contract, 10, assets.base.id)
+            .deposit(&market, 20, assets.base.id)
+            .open_order(&market, 1, OrderType::Sell, 70_000_000_000_000_u64)
+            .call()
+            .await?;
+
+        assert_eq!(response.value.len(), 3);
+        assert_eq!(
+            contract.account(owner.identity()).await?.value,
+            create_account(10, 0, 0, 0)
+        );
+        assert_eq!(
+            market.account(owner.identity()).await?.value,
+            create_account(19, 0, 1, 0)
+        );
+        assert_eq!(market.user_orders(owner.identity()).await?.value.len(), 1);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+    use spark_market_sdk::{AccountError, SparkMarketError};
+
+    #[tokio::test]
+    async fn when_insufficient_balance_to_open() {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Revert
+        let error = contract
+            .tx_builder()
+            .deposit(&contract, 1, assets.base.id)
+            .open_order(&contract, 2, OrderType::Sell, 70_000_000_000_000_u64)
+            .call()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            SparkMarketError::Account(AccountError::InsufficientBalance(_))
+        ));
+        // Deposit is reverted as well
+        assert_eq!(
+            contract.account(owner.identity()).await.unwrap().value,
+            create_account(0, 0, 0, 0)
+        );
+    }
+}
@@ -208,7 +208,7 @@ mod success {
 mod revert {
 
     use super::*;
-    use spark_market_sdk::AssetType;
+    use spark_market_sdk::{AccountError, AssetType, SparkMarketError};
 
     #[tokio::test]
     #[should_panic(expected = "InsufficientBalance")]
@@ -284,4 +284,34 @@ mod revert {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn returns_insufficient_balance_error() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let deposit_amount = 100;
+
+        let _ = contract
+            .deposit(deposit_amount, assets.base.id)
+            .await
+            .unwrap();
+
+        // Revert
+        let error = contract
+            .withdraw(deposit_amount + 1, AssetType::Base)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            SparkMarketError::Account(AccountError::InsufficientBalance(_))
+        ));
+    }
 }
@@ -0,0 +1,52 @@
+mod success {
+
+    use crate::setup::{create_account, setup, Defaults};
+
+    #[tokio::test]
+    async fn returns_no_accounts() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        assert_eq!(contract.accounts(vec![]).await?.value, vec![]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_accounts_info() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(100, assets.base.id).await?;
+        let _ = contract
+            .with_account(&user.wallet)
+            .deposit(200, assets.quote.id)
+            .await?;
+
+        let accounts = contract
+            .accounts(vec![user.identity(), owner.identity(), user.identity()])
+            .await?
+            .value;
+
+        assert_eq!(
+            accounts,
+            vec![
+                create_account(0, 200, 0, 0),
+                create_account(100, 0, 0, 0),
+                create_account(0, 200, 0, 0),
+            ]
+        );
+
+        Ok(())
+    }
+}
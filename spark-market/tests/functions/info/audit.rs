@@ -0,0 +1,120 @@
+use crate::setup::{setup, Defaults};
+use spark_market_sdk::{audit, AuditIssue, OrderType, ProtocolFee};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn solvent_after_partial_match() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user0, user1, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.set_matcher_fee(1_000).await?;
+        let _ = contract
+            .set_protocol_fee(vec![ProtocolFee {
+                maker_fee: 10,
+                taker_fee: 15,
+                volume_threshold: 0,
+            }])
+            .await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount * 2;
+
+        let seller = contract.with_account(&user0.wallet);
+        let buyer = contract.with_account(&user1.wallet);
+        let _ = seller.deposit(base_amount, assets.base.id).await?;
+        let _ = buyer.deposit(quote_amount, assets.quote.id).await?;
+
+        let id0 = seller
+            .open_order(base_amount / 2, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = buyer
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        let _ = contract.match_order_pair(id0, id1).await?;
+
+        let report = audit(&contract, &[user0.identity(), user1.identity()]).await?;
+
+        // The owner is audited as the receiver of the fees
+        assert_eq!(report.users, 3);
+        assert_eq!(report.orders, 1);
+        assert!(report.is_solvent());
+        for asset_audit in report.assets.values() {
+            assert_eq!(asset_audit.contract, asset_audit.accounts());
+        }
+        assert!(!report
+            .issues
+            .iter()
+            .any(|issue| matches!(issue, AuditIssue::Surplus { .. })));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn open_order_locks_match_account() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let _ = contract.deposit(1_000_000_000, assets.quote.id).await?;
+        let _ = contract.open_order(1_000, OrderType::Buy, price).await?;
+
+        let report = audit(&contract, &[owner.identity()]).await?;
+
+        assert_eq!(report.users, 1);
+        assert!(report.issues.is_empty());
+        let quote = report.assets.get(&assets.quote.id).unwrap();
+        assert_eq!(quote.orders_locked, quote.locked);
+        assert!(quote.locked > 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn surplus_when_user_left_out() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let deposit_amount = 100;
+        let _ = contract
+            .with_account(&user.wallet)
+            .deposit(deposit_amount, assets.base.id)
+            .await?;
+
+        let report = audit(&contract, &[]).await?;
+
+        assert!(report.is_solvent());
+        assert_eq!(
+            report.issues,
+            vec![AuditIssue::Surplus {
+                asset: assets.base.id,
+                accounts: 0,
+                contract: deposit_amount,
+            }]
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 mod success {
 
     use crate::setup::{setup, Defaults};
-    use spark_market_sdk::SparkMarketContract;
+    use fuels::{accounts::ViewOnlyAccount, types::ContractId};
+    use spark_market_sdk::{
+        SparkMarketContract, SparkMarketError, SparkMarketReader, VersionPolicy,
+    };
 
     #[tokio::test]
     async fn returns_config() -> anyhow::Result<()> {
@@ -28,4 +31,82 @@ mod success {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn returns_config_read_only() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let provider = owner.wallet.try_provider()?.clone();
+        let reader = SparkMarketReader::read_only(contract.contract_id().into(), provider).await?;
+
+        assert_eq!(reader.config().await?.value, contract.config().await?.value);
+        assert_eq!(
+            reader.account(owner.identity()).await?.value,
+            contract.account(owner.identity()).await?.value
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checks_version_policy() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (_contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let sdk = SparkMarketContract::sdk_version();
+        let deploy = |version: u32| {
+            SparkMarketContract::deploy(
+                assets.base.id,
+                assets.base.decimals,
+                assets.quote.id,
+                assets.quote.decimals,
+                owner.wallet.clone(),
+                defaults.price_decimals,
+                version,
+            )
+        };
+
+        // Patch version bump
+        let market = deploy(sdk + 1).await?;
+        let id: ContractId = market.contract_id().into();
+        assert!(SparkMarketContract::new(id, owner.wallet.clone())
+            .await
+            .is_ok());
+        assert!(SparkMarketContract::new_with_policy(
+            id,
+            owner.wallet.clone(),
+            VersionPolicy::Minor
+        )
+        .await
+        .is_ok());
+        assert!(matches!(
+            SparkMarketContract::new_with_policy(id, owner.wallet.clone(), VersionPolicy::Exact)
+                .await,
+            Err(SparkMarketError::VersionMismatch { contract, sdk: s }) if contract == sdk + 1 && s == sdk
+        ));
+
+        // Major version bump
+        let market = deploy(sdk + 0x010000).await?;
+        let id: ContractId = market.contract_id().into();
+        assert!(matches!(
+            SparkMarketContract::new(id, owner.wallet.clone()).await,
+            Err(SparkMarketError::VersionMismatch { .. })
+        ));
+
+        let market = SparkMarketContract::connect_unchecked(id, owner.wallet.clone()).await?;
+        assert_eq!(market.contract_version().await?, sdk + 0x010000);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,23 @@
+mod success {
+
+    use crate::setup::{setup, Defaults};
+
+    #[tokio::test]
+    async fn returns_emergency() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        assert!(!contract.emergency().await?.value);
+
+        let _ = contract.enable_emergency().await?;
+
+        assert!(contract.emergency().await?.value);
+
+        Ok(())
+    }
+}
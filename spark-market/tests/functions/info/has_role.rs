@@ -0,0 +1,41 @@
+mod success {
+
+    use crate::setup::{setup, Defaults};
+    use spark_market_sdk::Role;
+
+    #[tokio::test]
+    async fn returns_granted_role_only() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract
+            .grant_role(Role::FeeManager, user.identity())
+            .await?;
+
+        assert!(
+            contract
+                .has_role(Role::FeeManager, user.identity())
+                .await?
+                .value
+        );
+        assert!(
+            !contract
+                .has_role(Role::Pauser, user.identity())
+                .await?
+                .value
+        );
+        assert!(
+            !contract
+                .has_role(Role::ParameterManager, user.identity())
+                .await?
+                .value
+        );
+
+        Ok(())
+    }
+}
@@ -1,11 +1,22 @@
 mod account;
+mod accounts;
+mod audit;
 mod config;
+mod emergency;
+mod has_role;
 mod matcher_fee;
 mod min_order_price;
 mod min_order_size;
 mod order;
+mod order_change_info_paged;
 mod order_id;
+mod orders;
+mod portfolio;
 mod protocol_fee;
 mod protocol_fee_user;
 mod protocol_fee_user_amount;
+mod upgrade;
+mod user_fee_tier;
 mod user_orders;
+mod user_orders_paged;
+mod user_volume;
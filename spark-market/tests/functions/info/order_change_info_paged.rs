@@ -0,0 +1,106 @@
+mod success {
+
+    use crate::setup::{setup, Defaults};
+    use spark_market_sdk::{OrderChangeType, OrderType};
+
+    #[tokio::test]
+    async fn returns_zero_changes() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let order_id = fuels::types::Bits256([1u8; 32]);
+        assert_eq!(contract.order_change_info_count(order_id).await?.value, 0);
+        assert_eq!(
+            contract
+                .order_change_info_paged(order_id, 0, 10)
+                .await?
+                .value,
+            vec![]
+        );
+        assert_eq!(contract.order_change_info_all(order_id, 10).await?, vec![]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_changes_page() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(1000, assets.base.id).await?;
+        let order_id = contract
+            .open_order(2, OrderType::Sell, 70_000_000_000_000_u64)
+            .await?
+            .value;
+        let _ = contract.cancel_order(order_id).await?;
+
+        assert_eq!(contract.order_change_info_count(order_id).await?.value, 2);
+
+        let first = contract
+            .order_change_info_paged(order_id, 0, 1)
+            .await?
+            .value;
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].change_type, OrderChangeType::OrderOpened);
+
+        let second = contract
+            .order_change_info_paged(order_id, 1, 10)
+            .await?
+            .value;
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].change_type, OrderChangeType::OrderCancelled);
+
+        assert_eq!(
+            contract
+                .order_change_info_paged(order_id, 2, 10)
+                .await?
+                .value,
+            vec![]
+        );
+
+        let mut pages = contract.order_change_info_pages(order_id, 1)?;
+        assert_eq!(pages.next().await?, Some(first));
+        assert_eq!(pages.next().await?, Some(second));
+        assert_eq!(pages.next().await?, None);
+
+        assert_eq!(
+            contract.order_change_info_all(order_id, 10).await?,
+            contract.order_change_info(order_id).await?.value
+        );
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{setup, Defaults};
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroPageSize")]
+    async fn when_page_size_is_zero() {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        let order_id = fuels::types::Bits256([1u8; 32]);
+
+        // Revert
+        let _ = contract.order_change_info_pages(order_id, 0).unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+mod success {
+
+    use crate::setup::{setup, Defaults};
+    use fuels::types::Bits256;
+    use spark_market_sdk::OrderType;
+
+    #[tokio::test]
+    async fn returns_none() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        assert_eq!(contract.orders(vec![]).await?.value, vec![]);
+        assert_eq!(
+            contract.orders(vec![Bits256([0u8; 32])]).await?.value,
+            vec![None]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_orders() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(100, assets.base.id).await?;
+        let id1 = contract
+            .open_order(1, OrderType::Sell, 70_000_000_000_000_u64)
+            .await?
+            .value;
+        let id2 = contract
+            .open_order(2, OrderType::Sell, 75_000_000_000_000_u64)
+            .await?
+            .value;
+
+        let orders = contract
+            .orders(vec![id2, Bits256([0u8; 32]), id1])
+            .await?
+            .value;
+
+        assert_eq!(orders.len(), 3);
+        assert_eq!(orders[0], contract.order(id2).await?.value);
+        assert_eq!(orders[1], None);
+        assert_eq!(orders[2], contract.order(id1).await?.value);
+        assert_eq!(orders[0].as_ref().unwrap().amount, 2);
+        assert_eq!(orders[2].as_ref().unwrap().amount, 1);
+
+        Ok(())
+    }
+}
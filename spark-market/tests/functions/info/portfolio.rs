@@ -0,0 +1,86 @@
+use crate::setup::{clone_market, create_account, setup, Defaults};
+use fuels::types::ContractId;
+use spark_market_sdk::{portfolio, AssetPosition, OrderType};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_positions() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let portfolio = portfolio(&[contract], user.identity()).await?;
+
+        assert_eq!(portfolio.markets.len(), 1);
+        assert_eq!(portfolio.markets[0].account, create_account(0, 0, 0, 0));
+        assert_eq!(portfolio.markets[0].open_orders, 0);
+        assert_eq!(
+            portfolio.assets.get(&assets.base.id),
+            Some(&AssetPosition::default())
+        );
+        assert_eq!(
+            portfolio.assets.get(&assets.quote.id),
+            Some(&AssetPosition::default())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn aggregates_balances_across_markets() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+        let other = clone_market(owner.wallet.clone(), &contract).await?;
+
+        let price = 70_000_000_000_000_u64;
+        let _ = contract.deposit(10, assets.base.id).await?;
+        let _ = contract.deposit(1_000, assets.quote.id).await?;
+        let _ = contract.open_order(2, OrderType::Sell, price).await?;
+        let _ = other.deposit(5, assets.base.id).await?;
+
+        let contract_id = ContractId::from(contract.contract_id());
+        let other_id = ContractId::from(other.contract_id());
+        let portfolio = portfolio(&[contract, other], owner.identity()).await?;
+
+        assert_eq!(portfolio.user, owner.identity());
+        assert_eq!(portfolio.markets.len(), 2);
+        assert_eq!(portfolio.markets[0].market, contract_id);
+        assert_eq!(portfolio.markets[0].account, create_account(8, 1_000, 2, 0));
+        assert_eq!(portfolio.markets[0].open_orders, 1);
+        assert_eq!(portfolio.markets[1].market, other_id);
+        assert_eq!(portfolio.markets[1].account, create_account(5, 0, 0, 0));
+        assert_eq!(portfolio.markets[1].open_orders, 0);
+
+        assert_eq!(portfolio.assets.len(), 2);
+        assert_eq!(
+            portfolio.assets.get(&assets.base.id),
+            Some(&AssetPosition {
+                liquid: 13,
+                locked: 2,
+                open_orders: 1,
+            })
+        );
+        assert_eq!(
+            portfolio.assets.get(&assets.quote.id),
+            Some(&AssetPosition {
+                liquid: 1_000,
+                locked: 0,
+                open_orders: 0,
+            })
+        );
+
+        Ok(())
+    }
+}
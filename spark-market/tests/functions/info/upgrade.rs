@@ -0,0 +1,272 @@
+use serde_json::{json, Value};
+use spark_market_sdk::{check_upgrade, MarketBuild, UpgradeIssue};
+use std::{fs, path::PathBuf};
+
+/// Build output of the market deployed before the upgrade checks were added
+const BASELINE_BUILD_PATH: &str = "tests/artifacts/spark-market-baseline";
+
+fn read_build_json(file: &str) -> anyhow::Result<Value> {
+    let out = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("out/release");
+    Ok(serde_json::from_str(&fs::read_to_string(out.join(file))?)?)
+}
+
+fn read_source() -> anyhow::Result<String> {
+    Ok(fs::read_to_string(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/main.sw"),
+    )?)
+}
+
+fn baseline() -> anyhow::Result<MarketBuild> {
+    MarketBuild::load(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(BASELINE_BUILD_PATH))
+}
+
+/// Parsed ABI and storage slots of the current market build
+fn build_json() -> anyhow::Result<(Value, Value)> {
+    Ok((
+        read_build_json("spark-market-abi.json")?,
+        read_build_json("spark-market-storage_slots.json")?,
+    ))
+}
+
+/// Build with the storage types of the current market source
+fn build(abi: Value, storage_slots: Value) -> anyhow::Result<MarketBuild> {
+    MarketBuild::new(abi, storage_slots)?.with_storage_source(&read_source()?)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn compatible_with_same_build() -> anyhow::Result<()> {
+        let previous = MarketBuild::bundled()?;
+        let (abi, storage_slots) = build_json()?;
+        let new = build(abi, storage_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert!(report.issues.is_empty());
+        assert!(report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn added_storage_slot_is_compatible() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+        let mut new_slots = storage_slots;
+        let key = "ff".repeat(32);
+        new_slots
+            .as_array_mut()
+            .unwrap()
+            .push(json!({ "key": key, "value": "00".repeat(32) }));
+        let new = build(abi, new_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(report.issues, vec![UpgradeIssue::StorageSlotAdded { key }]);
+        assert!(report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_removed_storage_slot() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+        let mut new_slots = storage_slots;
+        let removed = new_slots.as_array_mut().unwrap().remove(0);
+        let new = build(abi, new_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(
+            report.issues,
+            vec![UpgradeIssue::StorageSlotRemoved {
+                key: removed["key"].as_str().unwrap().to_string()
+            }]
+        );
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_removed_function() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+        let mut new_abi = abi;
+        new_abi["functions"]
+            .as_array_mut()
+            .unwrap()
+            .retain(|function| function["name"] != "cancel_order");
+        let new = build(new_abi, storage_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(
+            report.issues,
+            vec![UpgradeIssue::FunctionRemoved {
+                name: "cancel_order".to_string()
+            }]
+        );
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_changed_event_layout() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+
+        // Renames a field of the trade event, the concrete type id stays the same
+        let mut new_abi = abi;
+        let event = new_abi["metadataTypes"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|ty| ty["type"] == "struct events::TradeOrderEvent")
+            .unwrap();
+        event["components"][0]["name"] = json!("sell_order_id");
+        let new = build(new_abi, storage_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(report.issues.len(), 1);
+        assert!(matches!(
+            &report.issues[0],
+            UpgradeIssue::EventChanged { name, .. } if name == "struct events::TradeOrderEvent"
+        ));
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_changed_storage_value_layout() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+
+        // Renames a field of the stored orders
+        let mut new_abi = abi;
+        let order = new_abi["metadataTypes"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|ty| ty["type"] == "struct data_structures::order::Order")
+            .unwrap();
+        order["components"][0]["name"] = json!("size");
+        let new = build(new_abi, storage_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert!(report.issues.iter().any(|issue| matches!(
+            issue,
+            UpgradeIssue::StorageFieldChanged { name, .. } if name == "orders"
+        )));
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_changed_storage_field_type() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+        let source = read_source()?.replace(
+            "order_rebates: StorageMap<b256, u64>",
+            "order_rebates: StorageMap<b256, u32>",
+        );
+        let new = MarketBuild::new(abi, storage_slots)?.with_storage_source(&source)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(
+            report.issues,
+            vec![UpgradeIssue::StorageFieldChanged {
+                name: "order_rebates".to_string(),
+                previous: "StorageMap<b256, u64>".to_string(),
+                new: "StorageMap<b256, u32>".to_string(),
+            }]
+        );
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_removed_storage_field() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = build(abi.clone(), storage_slots.clone())?;
+        let source = read_source()?
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("user_orders:"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let new = MarketBuild::new(abi, storage_slots)?.with_storage_source(&source)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(
+            report.issues,
+            vec![UpgradeIssue::StorageFieldRemoved {
+                name: "user_orders".to_string()
+            }]
+        );
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn warns_of_undeclared_storage_types() -> anyhow::Result<()> {
+        let (abi, storage_slots) = build_json()?;
+        let previous = MarketBuild::new(abi.clone(), storage_slots.clone())?;
+        let new = build(abi, storage_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert_eq!(report.issues, vec![UpgradeIssue::StorageTypesUndeclared]);
+        assert!(report.is_compatible());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checks_baseline_build_without_source() -> anyhow::Result<()> {
+        let previous = baseline()?;
+        let (abi, storage_slots) = build_json()?;
+        let new = build(abi, storage_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        // The deployed build has no source next to it, its storage types are not checked
+        assert!(report
+            .issues
+            .contains(&UpgradeIssue::StorageTypesUndeclared));
+        assert!(!UpgradeIssue::StorageTypesUndeclared.is_breaking());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_changed_storage_slot_against_baseline() -> anyhow::Result<()> {
+        let previous = baseline()?;
+        let (abi, storage_slots) = build_json()?;
+
+        // Changes the initial value of a storage field of the baseline layout
+        let mut new_slots = storage_slots;
+        let slot = new_slots
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|slot| slot["value"] != json!("00".repeat(32)))
+            .unwrap();
+        let key = slot["key"].as_str().unwrap().to_string();
+        let value = slot["value"].as_str().unwrap().to_string();
+        slot["value"] = json!("00".repeat(32));
+        let new = build(abi, new_slots)?;
+
+        let report = check_upgrade(&previous, &new)?;
+
+        assert!(report.issues.contains(&UpgradeIssue::StorageSlotChanged {
+            key,
+            previous: value,
+            new: "00".repeat(32),
+        }));
+        assert!(!report.is_compatible());
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+mod success {
+
+    use crate::setup::{setup, Defaults};
+    use spark_market_sdk::{OrderType, ProtocolFee};
+
+    #[tokio::test]
+    async fn returns_default_fee_tier() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let protocol_fee = ProtocolFee {
+            maker_fee: 0,
+            taker_fee: 0,
+            volume_threshold: 0,
+        };
+        assert_eq!(
+            contract.user_fee_tier(user.identity()).await?.value,
+            (0, protocol_fee, None)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_next_fee_tier() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user0, user1, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+
+        let protocol_fee = vec![
+            ProtocolFee {
+                maker_fee: 0,
+                taker_fee: 0,
+                volume_threshold: 0,
+            },
+            ProtocolFee {
+                maker_fee: 0,
+                taker_fee: 0,
+                volume_threshold: quote_amount,
+            },
+        ];
+        let _ = contract.set_protocol_fee(protocol_fee.clone()).await?;
+
+        assert_eq!(
+            contract.user_fee_tier(user0.identity()).await?.value,
+            (0, protocol_fee[0].clone(), Some(quote_amount))
+        );
+
+        contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await?;
+        contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount, assets.quote.id)
+            .await?;
+
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        contract.match_order_pair(id0, id1).await?;
+
+        assert_eq!(
+            contract.user_fee_tier(user0.identity()).await?.value,
+            (1, protocol_fee[1].clone(), None)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+mod success {
+
+    use crate::setup::{setup, Defaults};
+    use spark_market_sdk::OrderType;
+
+    #[tokio::test]
+    async fn returns_zero_orders() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        assert_eq!(contract.user_orders_count(owner.identity()).await?.value, 0);
+        let orders = contract
+            .user_orders_paged(owner.identity(), 0, 10)
+            .await?
+            .value;
+        assert_eq!(orders, vec![]);
+        assert_eq!(contract.user_orders_all(owner.identity(), 2).await?, vec![]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_orders_page() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(1000, assets.base.id).await?;
+        let mut ids = vec![];
+        for price in [
+            70_000_000_000_000_u64,
+            71_000_000_000_000_u64,
+            72_000_000_000_000_u64,
+        ] {
+            ids.push(contract.open_order(2, OrderType::Sell, price).await?.value);
+        }
+
+        assert_eq!(contract.user_orders_count(owner.identity()).await?.value, 3);
+        assert_eq!(
+            contract
+                .user_orders_paged(owner.identity(), 0, 2)
+                .await?
+                .value,
+            ids[0..2].to_vec()
+        );
+        assert_eq!(
+            contract
+                .user_orders_paged(owner.identity(), 2, 2)
+                .await?
+                .value,
+            ids[2..].to_vec()
+        );
+        assert_eq!(
+            contract
+                .user_orders_paged(owner.identity(), 3, 2)
+                .await?
+                .value,
+            vec![]
+        );
+        assert_eq!(
+            contract
+                .user_orders_paged(owner.identity(), 1, u64::MAX)
+                .await?
+                .value,
+            ids[1..].to_vec()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pages_through_all_orders() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let _ = contract.deposit(1000, assets.base.id).await?;
+        let mut ids = vec![];
+        for price in [
+            70_000_000_000_000_u64,
+            71_000_000_000_000_u64,
+            72_000_000_000_000_u64,
+        ] {
+            ids.push(contract.open_order(2, OrderType::Sell, price).await?.value);
+        }
+
+        let mut pages = contract.user_orders_pages(owner.identity(), 2)?;
+        assert_eq!(pages.next().await?, Some(ids[0..2].to_vec()));
+        assert_eq!(pages.next().await?, Some(ids[2..].to_vec()));
+        assert_eq!(pages.next().await?, None);
+
+        assert_eq!(contract.user_orders_all(owner.identity(), 2).await?, ids);
+        assert_eq!(contract.user_orders_all(owner.identity(), 3).await?, ids);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{setup, Defaults};
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroPageSize")]
+    async fn when_page_size_is_zero() {
+        let defaults = Defaults::default();
+        let (contract, owner, _user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await
+        .unwrap();
+
+        // Revert
+        contract.user_orders_all(owner.identity(), 0).await.unwrap();
+    }
+}
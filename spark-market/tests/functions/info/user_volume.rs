@@ -0,0 +1,200 @@
+mod success {
+
+    use crate::setup::{now_tai64, setup, Defaults};
+    use spark_market_sdk::{OrderType, ProtocolFee};
+
+    #[tokio::test]
+    async fn returns_zero_volume() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user, _, _, _assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        assert_eq!(contract.user_volume(user.identity()).await?.value, (0, 0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_epoch_volume() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user0, user1, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let tai64_epoch = now_tai64();
+        let epoch_duration = 60 * 60 * 24 * 30;
+        let _ = contract.set_epoch(tai64_epoch, epoch_duration).await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+
+        contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await?;
+        contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount, assets.quote.id)
+            .await?;
+
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        contract.match_order_pair(id0, id1).await?;
+
+        assert_eq!(
+            contract.user_volume(user0.identity()).await?.value,
+            (tai64_epoch, quote_amount)
+        );
+        assert_eq!(
+            contract.user_volume(user1.identity()).await?.value,
+            (tai64_epoch, quote_amount)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_rolling_volume() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user0, user1, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+
+        let protocol_fee = vec![
+            ProtocolFee {
+                maker_fee: 0,
+                taker_fee: 0,
+                volume_threshold: 0,
+            },
+            ProtocolFee {
+                maker_fee: 8,
+                taker_fee: 12,
+                volume_threshold: quote_amount,
+            },
+        ];
+        let _ = contract.set_protocol_fee(protocol_fee.clone()).await?;
+        let _ = contract.set_rolling_volume(true).await?;
+
+        contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await?;
+        contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount, assets.quote.id)
+            .await?;
+
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        contract.match_order_pair(id0, id1).await?;
+
+        assert_eq!(
+            contract.user_volume(user0.identity()).await?.value.1,
+            quote_amount
+        );
+        assert_eq!(
+            contract.user_volume(user1.identity()).await?.value.1,
+            quote_amount
+        );
+        assert_eq!(
+            contract.protocol_fee_user(user0.identity()).await?.value,
+            (protocol_fee[1].maker_fee, protocol_fee[1].taker_fee)
+        );
+
+        // Epoch based volumes are used again when rolling volume is disabled
+        let _ = contract.set_rolling_volume(false).await?;
+        assert_eq!(
+            contract.user_volume(user0.identity()).await?.value.1,
+            quote_amount
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keeps_volume_traded_before_rolling_volume_enabled() -> anyhow::Result<()> {
+        let defaults = Defaults::default();
+        let (contract, _owner, user0, user1, _, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        let to_quote_scale =
+            10_u64.pow(defaults.price_decimals + defaults.base_decimals - defaults.quote_decimals);
+        let price = 70_000 * 10_u64.pow(defaults.price_decimals);
+        let base_amount = 100_000_u64; // 0.001 BTC
+        let quote_amount = price / to_quote_scale * base_amount;
+
+        contract
+            .with_account(&user0.wallet)
+            .deposit(base_amount, assets.base.id)
+            .await?;
+        contract
+            .with_account(&user1.wallet)
+            .deposit(quote_amount, assets.quote.id)
+            .await?;
+
+        let id0 = contract
+            .with_account(&user0.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = contract
+            .with_account(&user1.wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        contract.match_order_pair(id0, id1).await?;
+
+        // The rolling window volume is accumulated while the epoch based volume is read
+        let _ = contract.set_rolling_volume(true).await?;
+
+        assert_eq!(
+            contract.user_volume(user0.identity()).await?.value.1,
+            quote_amount
+        );
+        assert_eq!(
+            contract.user_volume(user1.identity()).await?.value.1,
+            quote_amount
+        );
+
+        Ok(())
+    }
+}
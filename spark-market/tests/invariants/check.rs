@@ -0,0 +1,110 @@
+use super::{op::Model, Env};
+use fuels::types::Bytes32;
+use spark_market_sdk::{audit, AuditIssue, OrderType};
+use std::collections::HashSet;
+
+/// Checks the invariants of the market after a step, returns the first one broken
+///
+/// - the market holds exactly the deposits net of the withdrawals, and exactly what the
+///   accounts are credited with
+/// - the locked balance of each user covers the amounts its open orders lock, partial fills
+///   of a buy order may leave a unit of quote locked above them, so at most a unit of quote
+///   per buy order the user opened
+/// - each order indexed for a user is stored and owned by the user, once, and each stored
+///   order is indexed for its owner
+pub(crate) async fn check(env: &Env, model: &Model) -> anyhow::Result<Option<String>> {
+    let users = env.identities();
+    let report = audit(&env.contract, &users).await?;
+
+    for issue in report.issues {
+        match issue {
+            AuditIssue::LockSurplus {
+                user,
+                asset,
+                locked,
+                orders_locked,
+            } if asset == env.assets.quote.id => {
+                let buys = model
+                    .orders
+                    .iter()
+                    .filter(|opened| {
+                        users[opened.user] == user && opened.order_type == OrderType::Buy
+                    })
+                    .count() as u64;
+                if locked - orders_locked > buys {
+                    return Ok(Some(format!(
+                        "User {:?} locks {} of quote above its orders for {} buy orders opened",
+                        user,
+                        locked - orders_locked,
+                        buys
+                    )));
+                }
+            }
+            issue => return Ok(Some(issue.to_string())),
+        }
+    }
+
+    for (asset, net) in [
+        (env.assets.base.id, model.base),
+        (env.assets.quote.id, model.quote),
+    ] {
+        let contract = report.assets[&asset].contract;
+        if contract != net {
+            return Ok(Some(format!(
+                "Market holds {} of asset 0x{} for {} deposited net of the withdrawals",
+                contract, asset, net
+            )));
+        }
+    }
+
+    let mut indexed = Vec::with_capacity(users.len());
+    for user in &users {
+        let order_ids = env.contract.user_orders(*user).await?.value;
+        let count = env.contract.user_orders_count(*user).await?.value;
+        if count != order_ids.len() as u64 {
+            return Ok(Some(format!(
+                "User {:?} counts {} orders for {} indexed",
+                user,
+                count,
+                order_ids.len()
+            )));
+        }
+
+        let mut unique = HashSet::new();
+        for order_id in &order_ids {
+            if !unique.insert(order_id.0) {
+                return Ok(Some(format!(
+                    "Order 0x{} indexed twice for user {:?}",
+                    Bytes32::from(order_id.0),
+                    user
+                )));
+            }
+            match env.contract.order(*order_id).await?.value {
+                Some(order) if order.owner == *user => {}
+                _ => {
+                    return Ok(Some(format!(
+                        "Order 0x{} indexed for user {:?} is not its stored order",
+                        Bytes32::from(order_id.0),
+                        user
+                    )))
+                }
+            }
+        }
+        indexed.push(unique);
+    }
+
+    for opened in &model.orders {
+        let stored = env.contract.order(opened.id).await?.value.is_some();
+        if stored != indexed[opened.user].contains(&opened.id.0) {
+            return Ok(Some(format!(
+                "Order 0x{} of user {:?} is {} but {} for the user",
+                Bytes32::from(opened.id.0),
+                users[opened.user],
+                if stored { "stored" } else { "not stored" },
+                if stored { "not indexed" } else { "indexed" }
+            )));
+        }
+    }
+
+    Ok(None)
+}
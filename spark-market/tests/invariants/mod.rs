@@ -0,0 +1,259 @@
+//! Stateful invariant tests of the market
+//!
+//! A case is a random sequence of deposits, withdrawals, order openings, cancellations,
+//! matches and fulfillments across the users of a fresh market, its steps may revert. The
+//! invariants of `check` are verified after each step. A failing case is shrunk by dropping
+//! steps and simplifying the remaining ones while it still fails, then reported with its seed.
+//!
+//! `invariants_hold` runs with fixed seeds so it is deterministic, `fuzz_invariants` with a
+//! random one. Set `INVARIANT_SEED` to replay the cases of a reported seed.
+
+mod check;
+mod op;
+
+use crate::setup::{setup, Assets, Defaults, User};
+use check::check;
+use fuels::{prelude::AssetId, types::Identity};
+use op::{Model, Op};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use spark_market_sdk::{AssetType, ProtocolFee, SparkMarketContract};
+use std::fmt;
+
+pub(crate) struct Env {
+    pub(crate) contract: SparkMarketContract,
+    pub(crate) users: Vec<User>,
+    pub(crate) assets: Assets,
+    defaults: Defaults,
+}
+
+impl Env {
+    async fn new(fees: &Fees) -> anyhow::Result<Self> {
+        let defaults = Defaults::default();
+        let (contract, owner, user0, user1, user2, assets) = setup(
+            defaults.base_decimals,
+            defaults.quote_decimals,
+            defaults.price_decimals,
+        )
+        .await?;
+
+        if fees.matcher_fee != 0 {
+            let _ = contract.set_matcher_fee(fees.matcher_fee).await?;
+        }
+        if !fees.protocol_fee.is_empty() {
+            let _ = contract.set_protocol_fee(fees.protocol_fee.clone()).await?;
+        }
+        if !fees.maker_rebate.is_empty() {
+            let _ = contract.set_maker_rebate(fees.maker_rebate.clone()).await?;
+        }
+
+        Ok(Self {
+            contract,
+            users: vec![owner, user0, user1, user2],
+            assets,
+            defaults,
+        })
+    }
+
+    /// Price `tick` steps of 10 USDC away from 70k USDC
+    pub(crate) fn price(&self, tick: i64) -> u64 {
+        let unit = 10_i64.pow(self.defaults.price_decimals);
+        (70_000 * unit + tick * 10 * unit) as u64
+    }
+
+    pub(crate) fn asset_id(&self, asset: &AssetType) -> AssetId {
+        match asset {
+            AssetType::Base => self.assets.base.id,
+            AssetType::Quote => self.assets.quote.id,
+        }
+    }
+
+    pub(crate) fn identities(&self) -> Vec<Identity> {
+        self.users.iter().map(User::identity).collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Fees {
+    matcher_fee: u64,
+    protocol_fee: Vec<ProtocolFee>,
+    maker_rebate: Vec<u64>,
+}
+
+impl Fees {
+    fn generate(rng: &mut StdRng) -> Self {
+        let (protocol_fee, maker_rebate) = match rng.gen_range(0..3) {
+            0 => (vec![], vec![]),
+            1 => (
+                vec![ProtocolFee {
+                    maker_fee: 10,
+                    taker_fee: 15,
+                    volume_threshold: 0,
+                }],
+                vec![],
+            ),
+            _ => (
+                vec![ProtocolFee {
+                    maker_fee: 0,
+                    taker_fee: 15,
+                    volume_threshold: 0,
+                }],
+                vec![5],
+            ),
+        };
+        Self {
+            matcher_fee: rng.gen_range(0..2) * 1_000,
+            protocol_fee,
+            maker_rebate,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.matcher_fee == 0 && self.protocol_fee.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Case {
+    fees: Fees,
+    ops: Vec<Op>,
+}
+
+impl Case {
+    fn generate(rng: &mut StdRng, steps: usize) -> Self {
+        Self {
+            fees: Fees::generate(rng),
+            ops: (0..steps).map(|_| Op::generate(rng, 4)).collect(),
+        }
+    }
+
+    /// Simpler cases, the ones dropping most steps first
+    fn shrink(&self) -> Vec<Self> {
+        let mut cases = Vec::new();
+        if !self.fees.is_zero() {
+            cases.push(Self {
+                fees: Fees {
+                    matcher_fee: 0,
+                    protocol_fee: vec![],
+                    maker_rebate: vec![],
+                },
+                ops: self.ops.clone(),
+            });
+        }
+
+        let mut chunk = self.ops.len() / 2;
+        while chunk > 0 {
+            for start in (0..self.ops.len()).step_by(chunk) {
+                let mut ops = self.ops.clone();
+                ops.drain(start..(start + chunk).min(ops.len()));
+                cases.push(Self {
+                    fees: self.fees.clone(),
+                    ops,
+                });
+            }
+            chunk /= 2;
+        }
+
+        for (idx, op) in self.ops.iter().enumerate() {
+            if let Some(op) = op.shrink() {
+                let mut ops = self.ops.clone();
+                ops[idx] = op;
+                cases.push(Self {
+                    fees: self.fees.clone(),
+                    ops,
+                });
+            }
+        }
+        cases
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "matcher fee {}, protocol fee {:?}, maker rebate {:?}",
+            self.fees.matcher_fee, self.fees.protocol_fee, self.fees.maker_rebate
+        )?;
+        for (step, op) in self.ops.iter().enumerate() {
+            writeln!(f, "{:>4}: {}", step, op)?;
+        }
+        Ok(())
+    }
+}
+
+struct Failure {
+    step: usize,
+    message: String,
+}
+
+/// Runs the case on a fresh market, returns the first broken invariant
+async fn run(case: &Case) -> anyhow::Result<Option<Failure>> {
+    let env = Env::new(&case.fees).await?;
+    let mut model = Model::default();
+
+    for (step, op) in case.ops.iter().enumerate() {
+        op.apply(&env, &mut model).await?;
+        if let Some(message) = check(&env, &model).await? {
+            return Ok(Some(Failure { step, message }));
+        }
+    }
+    Ok(None)
+}
+
+/// Replaces the case with the first simpler case still failing until none does
+async fn shrink(mut case: Case, mut failure: Failure) -> anyhow::Result<(Case, Failure)> {
+    case.ops.truncate(failure.step + 1);
+    'shrink: loop {
+        for mut candidate in case.shrink() {
+            if let Some(candidate_failure) = run(&candidate).await? {
+                candidate.ops.truncate(candidate_failure.step + 1);
+                case = candidate;
+                failure = candidate_failure;
+                continue 'shrink;
+            }
+        }
+        return Ok((case, failure));
+    }
+}
+
+/// Seeds of `invariants_hold` when `INVARIANT_SEED` is not set
+const DEFAULT_SEEDS: [u64; 4] = [0x5eed, 0x5eed1, 0x5eed2, 0x5eed3];
+
+/// Runs `cases` cases of `steps` steps generated from `INVARIANT_SEED`, otherwise from `seed`
+/// or a random seed when it is `None`
+async fn check_invariants(cases: usize, steps: usize, seed: Option<u64>) -> anyhow::Result<()> {
+    let seed = match std::env::var("INVARIANT_SEED") {
+        Ok(seed) => seed.parse()?,
+        Err(_) => seed.unwrap_or_else(|| rand::thread_rng().gen()),
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for _ in 0..cases {
+        let case = Case::generate(&mut rng, steps);
+        if let Some(failure) = run(&case).await? {
+            let (case, failure) = shrink(case, failure).await?;
+            anyhow::bail!(
+                "Invariant broken at step {} with INVARIANT_SEED={}: {}\n{}",
+                failure.step,
+                seed,
+                failure.message,
+                case
+            );
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn invariants_hold() -> anyhow::Result<()> {
+    for seed in DEFAULT_SEEDS {
+        check_invariants(2, 30, Some(seed)).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn fuzz_invariants() -> anyhow::Result<()> {
+    check_invariants(20, 60, None).await
+}
@@ -0,0 +1,314 @@
+use super::Env;
+use fuels::types::Bits256;
+use rand::{rngs::StdRng, Rng};
+use spark_market_sdk::{AssetType, LimitType, OrderType};
+use std::fmt;
+
+/// Price deviation of the orders, in basis points of the reference price
+const SLIPPAGE: u64 = 100;
+
+/// Order opened by a step, kept after the order is filled or cancelled
+#[derive(Clone, Debug)]
+pub(crate) struct OpenedOrder {
+    pub(crate) user: usize,
+    pub(crate) order_type: OrderType,
+    pub(crate) id: Bits256,
+}
+
+/// Flows of the market observed by the steps of a case
+#[derive(Default)]
+pub(crate) struct Model {
+    /// Every order opened, in the order the steps opened them
+    pub(crate) orders: Vec<OpenedOrder>,
+    /// Base deposited net of the withdrawals
+    pub(crate) base: u64,
+    /// Quote deposited net of the withdrawals
+    pub(crate) quote: u64,
+}
+
+impl Model {
+    fn net(&mut self, asset: &AssetType) -> &mut u64 {
+        match asset {
+            AssetType::Base => &mut self.base,
+            AssetType::Quote => &mut self.quote,
+        }
+    }
+}
+
+/// Step of a case, users and orders are indexes wrapped around the users of the market and
+/// the orders opened so far so that any subsequence of a case stays runnable
+#[derive(Clone, Debug)]
+pub(crate) enum Op {
+    Deposit {
+        user: usize,
+        asset: AssetType,
+        amount: u64,
+    },
+    Withdraw {
+        user: usize,
+        asset: AssetType,
+        percent: u64,
+    },
+    Open {
+        user: usize,
+        order_type: OrderType,
+        amount: u64,
+        tick: i64,
+    },
+    Cancel {
+        order: usize,
+    },
+    MatchPair {
+        order0: usize,
+        order1: usize,
+    },
+    Fulfill {
+        user: usize,
+        order_type: OrderType,
+        limit_type: LimitType,
+        amount: u64,
+        tick: i64,
+    },
+}
+
+impl Op {
+    pub(crate) fn generate(rng: &mut StdRng, users: usize) -> Self {
+        let user = rng.gen_range(0..users);
+        let order_type = match rng.gen_bool(0.5) {
+            true => OrderType::Buy,
+            false => OrderType::Sell,
+        };
+        let amount = rng.gen_range(1_000..10_000_000); // 0.00001 BTC to 0.1 BTC
+        let tick = rng.gen_range(-10..=10);
+
+        match rng.gen_range(0..100) {
+            0..=24 => {
+                let (asset, amount) = match rng.gen_bool(0.5) {
+                    true => (AssetType::Base, rng.gen_range(1_000..100_000_000)), // up to 1 BTC
+                    false => (AssetType::Quote, rng.gen_range(1_000..100_000_000_000)), // up to 100k USDC
+                };
+                Self::Deposit {
+                    user,
+                    asset,
+                    amount,
+                }
+            }
+            25..=34 => Self::Withdraw {
+                user,
+                asset: match rng.gen_bool(0.5) {
+                    true => AssetType::Base,
+                    false => AssetType::Quote,
+                },
+                percent: rng.gen_range(1..=100),
+            },
+            35..=64 => Self::Open {
+                user,
+                order_type,
+                amount,
+                tick,
+            },
+            65..=74 => Self::Cancel {
+                order: rng.gen_range(0..64),
+            },
+            75..=89 => Self::MatchPair {
+                order0: rng.gen_range(0..64),
+                order1: rng.gen_range(0..64),
+            },
+            _ => Self::Fulfill {
+                user,
+                order_type,
+                limit_type: match rng.gen_range(0..3) {
+                    0 => LimitType::GTC,
+                    1 => LimitType::IOC,
+                    _ => LimitType::FOK,
+                },
+                amount,
+                tick,
+            },
+        }
+    }
+
+    /// Simpler step with a halved amount or a price closer to the reference price
+    pub(crate) fn shrink(&self) -> Option<Self> {
+        let mut op = self.clone();
+        match &mut op {
+            Self::Deposit { amount, .. } if *amount > 1 => *amount /= 2,
+            Self::Open { amount, tick, .. } | Self::Fulfill { amount, tick, .. } => {
+                if *tick != 0 {
+                    *tick = 0;
+                } else if *amount > 1 {
+                    *amount /= 2;
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+        Some(op)
+    }
+
+    /// Executes the step, a step the market reverts leaves the model unchanged
+    pub(crate) async fn apply(&self, env: &Env, model: &mut Model) -> anyhow::Result<()> {
+        match self {
+            Self::Deposit {
+                user,
+                asset,
+                amount,
+            } => {
+                let user = &env.users[user % env.users.len()];
+                let deposit = env
+                    .contract
+                    .with_account(&user.wallet)
+                    .deposit(*amount, env.asset_id(asset))
+                    .await;
+                if deposit.is_ok() {
+                    *model.net(asset) += amount;
+                }
+            }
+            Self::Withdraw {
+                user,
+                asset,
+                percent,
+            } => {
+                let user = &env.users[user % env.users.len()];
+                let account = env.contract.account(user.identity()).await?.value;
+                let liquid = match asset {
+                    AssetType::Base => account.liquid.base,
+                    AssetType::Quote => account.liquid.quote,
+                };
+                let amount = (liquid as u128 * *percent as u128 / 100) as u64;
+                let withdraw = env
+                    .contract
+                    .with_account(&user.wallet)
+                    .withdraw(amount, asset.clone())
+                    .await;
+                if withdraw.is_ok() {
+                    *model.net(asset) -= amount;
+                }
+            }
+            Self::Open {
+                user,
+                order_type,
+                amount,
+                tick,
+            } => {
+                let user = user % env.users.len();
+                let open = env
+                    .contract
+                    .with_account(&env.users[user].wallet)
+                    .open_order(*amount, order_type.clone(), env.price(*tick))
+                    .await;
+                if let Ok(response) = open {
+                    model.orders.push(OpenedOrder {
+                        user,
+                        order_type: order_type.clone(),
+                        id: response.value,
+                    });
+                }
+            }
+            Self::Cancel { order } => {
+                if model.orders.is_empty() {
+                    return Ok(());
+                }
+                let opened = &model.orders[order % model.orders.len()];
+                let _ = env
+                    .contract
+                    .with_account(&env.users[opened.user].wallet)
+                    .cancel_order(opened.id)
+                    .await;
+            }
+            Self::MatchPair { order0, order1 } => {
+                if model.orders.is_empty() {
+                    return Ok(());
+                }
+                let id0 = model.orders[order0 % model.orders.len()].id;
+                let id1 = model.orders[order1 % model.orders.len()].id;
+                let _ = env.contract.match_order_pair(id0, id1).await;
+            }
+            Self::Fulfill {
+                user,
+                order_type,
+                limit_type,
+                amount,
+                tick,
+            } => {
+                let user = user % env.users.len();
+                let orders = model
+                    .orders
+                    .iter()
+                    .filter(|opened| opened.order_type != *order_type)
+                    .map(|opened| opened.id)
+                    .collect::<Vec<_>>();
+                if orders.is_empty() {
+                    return Ok(());
+                }
+                let fulfill = env
+                    .contract
+                    .with_account(&env.users[user].wallet)
+                    .fulfill_many(
+                        *amount,
+                        order_type.clone(),
+                        limit_type.clone(),
+                        env.price(*tick),
+                        SLIPPAGE,
+                        orders,
+                    )
+                    .await;
+                if let Ok(response) = fulfill {
+                    model.orders.push(OpenedOrder {
+                        user,
+                        order_type: order_type.clone(),
+                        id: response.value,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deposit {
+                user,
+                asset,
+                amount,
+            } => write!(f, "user {} deposits {} {:?}", user, amount, asset),
+            Self::Withdraw {
+                user,
+                asset,
+                percent,
+            } => write!(
+                f,
+                "user {} withdraws {}% of liquid {:?}",
+                user, percent, asset
+            ),
+            Self::Open {
+                user,
+                order_type,
+                amount,
+                tick,
+            } => write!(
+                f,
+                "user {} opens {:?} {} at tick {}",
+                user, order_type, amount, tick
+            ),
+            Self::Cancel { order } => write!(f, "owner of order {} cancels it", order),
+            Self::MatchPair { order0, order1 } => {
+                write!(f, "matcher matches orders {} and {}", order0, order1)
+            }
+            Self::Fulfill {
+                user,
+                order_type,
+                limit_type,
+                amount,
+                tick,
+            } => write!(
+                f,
+                "user {} fulfills {:?} {:?} {} at tick {}",
+                user, limit_type, order_type, amount, tick
+            ),
+        }
+    }
+}
@@ -177,7 +177,7 @@ pub(crate) async fn setup(
                 );
 
                 let market =
-                    SparkMarketContract::new(proxy.contract_id().into(), owner.clone()).await;
+                    SparkMarketContract::new(proxy.contract_id().into(), owner.clone()).await?;
 
                 let _ = market.initialize_ownership(owner.address().into()).await?;
                 assert_eq!(
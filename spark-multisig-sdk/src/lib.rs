@@ -0,0 +1,310 @@
+use fuels::{
+    accounts::{impersonated_account::ImpersonatedAccount, Account},
+    prelude::{
+        abigen, Address, AssetId, Bech32ContractId, Contract, ContractId, LoadConfiguration,
+        Provider, StorageConfiguration, TxPolicies, VariableOutputPolicy, WalletUnlocked,
+    },
+    programs::{
+        calls::{CallHandler, ContractCall, Execution},
+        responses::CallResponse,
+    },
+    types::{Bytes, Bytes32, Identity},
+};
+use rand::Rng;
+use std::path::PathBuf;
+
+abigen!(Contract(
+    name = "SparkMultisig",
+    abi = "spark-multisig/out/release/spark-multisig-abi.json"
+));
+
+const SPARK_MULTISIG_CONTRACT_BINARY_PATH: &str = "spark-multisig/out/release/spark-multisig.bin";
+const SPARK_MULTISIG_CONTRACT_STORAGE_PATH: &str =
+    "spark-multisig/out/release/spark-multisig-storage_slots.json";
+
+/// Number of the attempts to find the contracts called by an executed proposal
+const MISSING_CONTRACTS_ATTEMPTS: u64 = 5;
+
+/// Call of an owner method of a contract, proposed to the multisig owning the contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminCall {
+    pub target: ContractId,
+    pub function_selector: Vec<u8>,
+    pub calldata: Vec<u8>,
+}
+
+impl AdminCall {
+    /// Takes the target and the encoded call of a call handler of the market, registry or proxy sdk
+    pub fn from_call_handler<A, T>(
+        handler: &CallHandler<A, ContractCall, T>,
+    ) -> anyhow::Result<Self> {
+        let calldata = handler
+            .call
+            .encoded_args
+            .as_ref()
+            .map_err(|err| anyhow::anyhow!("Failed to encode the call: {}", err))?
+            .clone();
+        Ok(Self {
+            target: ContractId::from(&handler.call.contract_id),
+            function_selector: handler.call.encoded_selector.clone(),
+            calldata,
+        })
+    }
+}
+
+pub struct SparkMultisigContract<A = WalletUnlocked> {
+    instance: SparkMultisig<A>,
+}
+
+/// Multisig client for the read methods that does not hold any private key
+pub type SparkMultisigReader = SparkMultisigContract<ImpersonatedAccount>;
+
+impl<A: Account> SparkMultisigContract<A> {
+    pub async fn new(contract_id: ContractId, wallet: A) -> Self {
+        Self {
+            instance: SparkMultisig::new(contract_id, wallet),
+        }
+    }
+
+    pub fn id(&self) -> Bytes32 {
+        self.instance.contract_id().hash
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        self.instance.contract_id()
+    }
+
+    /// Identity to set as the owner of the market, the registry or the proxy
+    pub fn identity(&self) -> Identity {
+        Identity::ContractId(ContractId::from(self.instance.contract_id()))
+    }
+
+    pub async fn owners(&self) -> anyhow::Result<CallResponse<Vec<Identity>>> {
+        Ok(self
+            .instance
+            .methods()
+            .owners()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn threshold(&self) -> anyhow::Result<CallResponse<u64>> {
+        Ok(self
+            .instance
+            .methods()
+            .threshold()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn proposals_count(&self) -> anyhow::Result<CallResponse<u64>> {
+        Ok(self
+            .instance
+            .methods()
+            .proposals_count()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn proposal(&self, id: u64) -> anyhow::Result<CallResponse<Option<ProposalInfo>>> {
+        Ok(self
+            .instance
+            .methods()
+            .proposal(id)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn approved(&self, id: u64, owner: Identity) -> anyhow::Result<CallResponse<bool>> {
+        Ok(self
+            .instance
+            .methods()
+            .approved(id, owner)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn config(&self) -> anyhow::Result<CallResponse<u32>> {
+        Ok(self
+            .instance
+            .methods()
+            .config()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+}
+
+impl SparkMultisigReader {
+    /// Connects to the multisig for the read methods with the provider only
+    pub async fn read_only(contract_id: ContractId, provider: Provider) -> Self {
+        let account = ImpersonatedAccount::new(Address::zeroed().into(), Some(provider));
+        Self::new(contract_id, account).await
+    }
+}
+
+impl<A: Account> SparkMultisigContract<A> {
+    /// Deploys a multisig executing the proposals approved by `threshold` of the `owners`
+    pub async fn deploy(
+        deployer: A,
+        owners: Vec<Identity>,
+        threshold: u64,
+        version: u32,
+    ) -> anyhow::Result<Self> {
+        let mut rng = rand::thread_rng();
+        let salt = rng.gen::<[u8; 32]>();
+
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let storage_configuration = StorageConfiguration::default()
+            .add_slot_overrides_from_file(root.join(SPARK_MULTISIG_CONTRACT_STORAGE_PATH));
+
+        // Only the deployer can initialize, so that the initialization can not be front-run
+        let configurables = SparkMultisigConfigurables::default()
+            .with_VERSION(version)
+            .unwrap()
+            .with_DEPLOYER(deployer.address().into())
+            .unwrap();
+
+        let contract_configuration = LoadConfiguration::default()
+            .with_storage_configuration(storage_configuration?)
+            .with_configurables(configurables);
+
+        let contract_id = Contract::load_from(
+            root.join(SPARK_MULTISIG_CONTRACT_BINARY_PATH),
+            contract_configuration,
+        )?
+        .with_salt(salt)
+        .deploy(&deployer, TxPolicies::default())
+        .await?;
+
+        let multisig = SparkMultisig::new(contract_id.clone(), deployer.clone());
+
+        multisig
+            .methods()
+            .initialize(owners, threshold)
+            .call()
+            .await?;
+
+        Ok(Self { instance: multisig })
+    }
+
+    pub fn with_account<B: Account>(&self, account: &B) -> SparkMultisigContract<B> {
+        SparkMultisigContract {
+            instance: self.instance.clone().with_account(account.clone()),
+        }
+    }
+
+    pub async fn initialize(
+        &self,
+        owners: Vec<Identity>,
+        threshold: u64,
+    ) -> anyhow::Result<CallResponse<()>> {
+        Ok(self
+            .instance
+            .methods()
+            .initialize(owners, threshold)
+            .call()
+            .await?)
+    }
+
+    /// Proposes the admin call, the proposal id is returned in the call response
+    pub async fn propose(&self, call: AdminCall) -> anyhow::Result<CallResponse<u64>> {
+        Ok(self.propose_call_handler(call).call().await?)
+    }
+
+    pub fn propose_call_handler(&self, call: AdminCall) -> CallHandler<A, ContractCall, u64> {
+        self.instance.methods().propose(
+            call.target,
+            Bytes(call.function_selector),
+            Bytes(call.calldata),
+        )
+    }
+
+    pub async fn approve(&self, id: u64) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.approve_call_handler(id).call().await?)
+    }
+
+    pub fn approve_call_handler(&self, id: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().approve(id)
+    }
+
+    pub async fn revoke(&self, id: u64) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.revoke_call_handler(id).call().await?)
+    }
+
+    pub fn revoke_call_handler(&self, id: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().revoke(id)
+    }
+
+    pub async fn execute(&self, id: u64) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.execute_call_handler(id).await?.call().await?)
+    }
+
+    /// Includes the contracts called by the proposal, as the implementation behind a market proxy,
+    /// and an output for a proposed transfer to an address
+    pub async fn execute_call_handler(
+        &self,
+        id: u64,
+    ) -> anyhow::Result<CallHandler<A, ContractCall, ()>> {
+        Ok(self
+            .instance
+            .methods()
+            .execute(id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .determine_missing_contracts(Some(MISSING_CONTRACTS_ATTEMPTS))
+            .await?)
+    }
+
+    pub async fn cancel(&self, id: u64) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.cancel_call_handler(id).call().await?)
+    }
+
+    pub fn cancel_call_handler(&self, id: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().cancel(id)
+    }
+
+    /// Adds an owner when executed by the multisig on itself, proposed as an `AdminCall`
+    pub fn add_owner_call_handler(&self, owner: Identity) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().add_owner(owner)
+    }
+
+    /// Removes an owner when executed by the multisig on itself, proposed as an `AdminCall`
+    pub fn remove_owner_call_handler(&self, owner: Identity) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().remove_owner(owner)
+    }
+
+    /// Changes the threshold when executed by the multisig on itself, proposed as an `AdminCall`
+    pub fn set_threshold_call_handler(&self, threshold: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_threshold(threshold)
+    }
+
+    /// Transfers an asset held by the multisig when executed by the multisig on itself, proposed
+    /// as an `AdminCall`
+    pub fn transfer_call_handler(
+        &self,
+        asset: AssetId,
+        amount: u64,
+        recipient: Identity,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().transfer(asset, amount, recipient)
+    }
+}
+
+impl SparkMultisigContract {
+    pub fn sdk_version() -> u32 {
+        let s_version = Self::sdk_str_version();
+        // Converts "0.1.1" string version to 257u32 (0x000101)
+        let version = s_version.split('.').collect::<Vec<&str>>();
+        let len = version.len();
+        version
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x.parse::<u32>().unwrap() << (8 * (len - i - 1)))
+            .collect::<Vec<u32>>()
+            .iter()
+            .sum()
+    }
+
+    pub fn sdk_str_version() -> String {
+        env!("CARGO_PKG_VERSION").into()
+    }
+}
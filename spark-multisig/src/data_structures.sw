@@ -0,0 +1,38 @@
+library;
+
+use std::bytes::Bytes;
+
+pub enum ProposalStatus {
+    Pending: (),
+    Executed: (),
+    Cancelled: (),
+}
+
+impl core::ops::Eq for ProposalStatus {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Pending, Self::Pending) => true,
+            (Self::Executed, Self::Executed) => true,
+            (Self::Cancelled, Self::Cancelled) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Proposal stored per id, the function selector and the calldata are stored apart as bytes
+pub struct Proposal {
+    pub target: ContractId,
+    pub proposer: Identity,
+    pub status: ProposalStatus,
+}
+
+/// Proposed call with the number of approvals by the current owners
+pub struct ProposalInfo {
+    pub id: u64,
+    pub target: ContractId,
+    pub function_selector: Bytes,
+    pub calldata: Bytes,
+    pub proposer: Identity,
+    pub status: ProposalStatus,
+    pub approvals: u64,
+}
@@ -0,0 +1,18 @@
+library;
+
+pub enum MultisigError {
+    AlreadyInitialized: (),
+    NotDeployer: (),
+    NotOwner: (),
+    NotMultisig: (),
+    OwnerAlreadyAdded: (),
+    OwnerNotFound: (),
+    InvalidThreshold: (),
+    ProposalNotFound: (),
+    ProposalNotPending: (),
+    AlreadyApproved: (),
+    NotApproved: (),
+    NotProposer: (),
+    ThresholdNotReached: u64,
+    ZeroAmount: (),
+}
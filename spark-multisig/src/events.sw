@@ -0,0 +1,49 @@
+library;
+
+pub struct InitializeEvent {
+    pub owners: Vec<Identity>,
+    pub threshold: u64,
+}
+
+pub struct ProposeEvent {
+    pub id: u64,
+    pub proposer: Identity,
+    pub target: ContractId,
+}
+
+pub struct ApproveEvent {
+    pub id: u64,
+    pub owner: Identity,
+}
+
+pub struct RevokeEvent {
+    pub id: u64,
+    pub owner: Identity,
+}
+
+pub struct ExecuteEvent {
+    pub id: u64,
+    pub executor: Identity,
+}
+
+pub struct CancelEvent {
+    pub id: u64,
+}
+
+pub struct AddOwnerEvent {
+    pub owner: Identity,
+}
+
+pub struct RemoveOwnerEvent {
+    pub owner: Identity,
+}
+
+pub struct SetThresholdEvent {
+    pub threshold: u64,
+}
+
+pub struct TransferEvent {
+    pub asset: AssetId,
+    pub amount: u64,
+    pub recipient: Identity,
+}
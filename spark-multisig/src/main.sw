@@ -0,0 +1,532 @@
+contract;
+
+mod data_structures;
+mod errors;
+mod events;
+
+use data_structures::*;
+use errors::*;
+use events::*;
+
+use std::{
+    bytes::Bytes,
+    low_level_call::{
+        call_with_function_selector,
+        CallParams,
+    },
+    registers::global_gas,
+    storage::{
+        storage_bytes::*,
+        storage_vec::*,
+    },
+};
+
+configurable {
+    VERSION: u32 = 0,
+    /// The only identity allowed to initialize the multisig, set by the deployment
+    DEPLOYER: Identity = Identity::Address(Address::zero()),
+}
+
+storage {
+    owners: StorageVec<Identity> = StorageVec {},
+    threshold: u64 = 0,
+    /// Proposals by id, the id is the position in the list
+    proposals: StorageVec<Proposal> = StorageVec {},
+    proposal_selectors: StorageMap<u64, StorageBytes> = StorageMap {},
+    proposal_calldata: StorageMap<u64, StorageBytes> = StorageMap {},
+    /// Approvals by proposal id, owner and owner nonce
+    approvals: StorageMap<(u64, Identity, u64), bool> = StorageMap {},
+    /// Incremented when an owner is removed, so that its approvals do not count once added again
+    owner_nonces: StorageMap<Identity, u64> = StorageMap {},
+}
+
+abi SparkMultisig {
+    #[storage(read, write)]
+    fn initialize(owners: Vec<Identity>, threshold: u64);
+
+    #[storage(read, write)]
+    fn propose(target: ContractId, function_selector: Bytes, calldata: Bytes) -> u64;
+
+    #[storage(read, write)]
+    fn approve(id: u64);
+
+    #[storage(read, write)]
+    fn revoke(id: u64);
+
+    #[storage(read, write)]
+    fn execute(id: u64);
+
+    #[storage(read, write)]
+    fn cancel(id: u64);
+
+    #[storage(read, write)]
+    fn add_owner(owner: Identity);
+
+    #[storage(read, write)]
+    fn remove_owner(owner: Identity);
+
+    #[storage(read, write)]
+    fn set_threshold(threshold: u64);
+
+    fn transfer(asset: AssetId, amount: u64, recipient: Identity);
+
+    #[storage(read)]
+    fn owners() -> Vec<Identity>;
+
+    #[storage(read)]
+    fn threshold() -> u64;
+
+    #[storage(read)]
+    fn proposals_count() -> u64;
+
+    #[storage(read)]
+    fn proposal(id: u64) -> Option<ProposalInfo>;
+
+    #[storage(read)]
+    fn approved(id: u64, owner: Identity) -> bool;
+
+    fn config() -> u32;
+}
+
+impl SparkMultisig for Contract {
+    /// Sets the owners and the number of approvals required to execute a proposal.
+    ///
+    /// ### Additional Information
+    ///
+    /// Called once right after the deployment by the deployer, the owners and the threshold are changed by proposals afterwards.
+    ///
+    /// ### Arguments
+    ///
+    /// * `owners`: [Vec<Identity>] - The owners allowed to propose, approve and execute.
+    /// * `threshold`: [u64] - The number of approvals required to execute a proposal.
+    ///
+    /// ### Reverts
+    ///
+    /// * When not called by the deployer.
+    /// * When the multisig is already initialized.
+    /// * When an owner is listed twice.
+    /// * When the threshold is zero or greater than the number of owners.
+    #[storage(read, write)]
+    fn initialize(owners: Vec<Identity>, threshold: u64) {
+        require(msg_sender().unwrap() == DEPLOYER, MultisigError::NotDeployer);
+        require(
+            storage.threshold.read() == 0,
+            MultisigError::AlreadyInitialized,
+        );
+        require(
+            threshold > 0 && threshold <= owners.len(),
+            MultisigError::InvalidThreshold,
+        );
+
+        let mut idx = 0;
+        while idx < owners.len() {
+            let owner = owners.get(idx).unwrap();
+            require(!is_owner(owner), MultisigError::OwnerAlreadyAdded);
+            storage.owners.push(owner);
+            idx += 1;
+        }
+        storage.threshold.write(threshold);
+
+        log(InitializeEvent {
+            owners,
+            threshold,
+        });
+    }
+
+    /// Proposes a call of the target contract, approved by the proposer.
+    ///
+    /// ### Additional Information
+    ///
+    /// The function selector and the calldata are encoded as by the SDK call handlers, so that any admin call
+    /// of the market, the registry or the proxy owned by the multisig can be proposed.
+    ///
+    /// ### Arguments
+    ///
+    /// * `target`: [ContractId] - The contract to call.
+    /// * `function_selector`: [Bytes] - The encoded function selector.
+    /// * `calldata`: [Bytes] - The encoded arguments.
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The id of the proposal.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    #[storage(read, write)]
+    fn propose(target: ContractId, function_selector: Bytes, calldata: Bytes) -> u64 {
+        let proposer = only_multisig_owner();
+
+        let id = storage.proposals.len();
+        storage.proposals.push(Proposal {
+            target,
+            proposer,
+            status: ProposalStatus::Pending,
+        });
+        storage.proposal_selectors.get(id).write_slice(function_selector);
+        storage.proposal_calldata.get(id).write_slice(calldata);
+        storage.approvals.insert((id, proposer, owner_nonce(proposer)), true);
+
+        log(ProposeEvent {
+            id,
+            proposer,
+            target,
+        });
+        log(ApproveEvent {
+            id,
+            owner: proposer,
+        });
+        id
+    }
+
+    /// Approves a pending proposal.
+    ///
+    /// ### Arguments
+    ///
+    /// * `id`: [u64] - The id of the proposal.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When the proposal is not found or not pending.
+    /// * When the caller already approved the proposal.
+    #[storage(read, write)]
+    fn approve(id: u64) {
+        let owner = only_multisig_owner();
+        let _ = pending_proposal(id);
+        require(!is_approved(id, owner), MultisigError::AlreadyApproved);
+
+        storage.approvals.insert((id, owner, owner_nonce(owner)), true);
+
+        log(ApproveEvent { id, owner });
+    }
+
+    /// Withdraws the approval of the caller from a pending proposal.
+    ///
+    /// ### Arguments
+    ///
+    /// * `id`: [u64] - The id of the proposal.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When the proposal is not found or not pending.
+    /// * When the caller has not approved the proposal.
+    #[storage(read, write)]
+    fn revoke(id: u64) {
+        let owner = only_multisig_owner();
+        let _ = pending_proposal(id);
+        require(is_approved(id, owner), MultisigError::NotApproved);
+
+        storage.approvals.insert((id, owner, owner_nonce(owner)), false);
+
+        log(RevokeEvent { id, owner });
+    }
+
+    /// Calls the target of a proposal approved by the threshold of the current owners.
+    ///
+    /// ### Additional Information
+    ///
+    /// The approvals are counted for the current owners only, the approvals of removed owners do not count.
+    /// The proposal is marked executed before the call, a reverting call reverts the execution as a whole.
+    ///
+    /// ### Arguments
+    ///
+    /// * `id`: [u64] - The id of the proposal.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When the proposal is not found or not pending.
+    /// * When the proposal has less approvals than the threshold.
+    /// * When the call of the target reverts.
+    #[storage(read, write)]
+    fn execute(id: u64) {
+        let executor = only_multisig_owner();
+        let mut proposal = pending_proposal(id);
+        let approvals = approvals_count(id);
+        require(
+            approvals >= storage.threshold.read(),
+            MultisigError::ThresholdNotReached(approvals),
+        );
+
+        let target = proposal.target;
+        proposal.status = ProposalStatus::Executed;
+        storage.proposals.set(id, proposal);
+
+        let function_selector = storage.proposal_selectors.get(id).read_slice().unwrap_or(Bytes::new());
+        let calldata = storage.proposal_calldata.get(id).read_slice().unwrap_or(Bytes::new());
+        call_with_function_selector(
+            target,
+            function_selector,
+            calldata,
+            CallParams {
+                coins: 0,
+                asset_id: AssetId::base(),
+                gas: global_gas(),
+            },
+        );
+
+        log(ExecuteEvent { id, executor });
+    }
+
+    /// Cancels a pending proposal.
+    ///
+    /// ### Arguments
+    ///
+    /// * `id`: [u64] - The id of the proposal.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by another owner than the proposer.
+    /// * When the proposal is not found or not pending.
+    #[storage(read, write)]
+    fn cancel(id: u64) {
+        let owner = only_multisig_owner();
+        let mut proposal = pending_proposal(id);
+        require(proposal.proposer == owner, MultisigError::NotProposer);
+
+        proposal.status = ProposalStatus::Cancelled;
+        storage.proposals.set(id, proposal);
+
+        log(CancelEvent { id });
+    }
+
+    /// Adds an owner.
+    ///
+    /// ### Additional Information
+    ///
+    /// Called by the multisig itself, through an executed proposal.
+    ///
+    /// ### Arguments
+    ///
+    /// * `owner`: [Identity] - The owner to add.
+    ///
+    /// ### Reverts
+    ///
+    /// * When not called by the multisig.
+    /// * When the owner is already added.
+    #[storage(read, write)]
+    fn add_owner(owner: Identity) {
+        only_self();
+        require(!is_owner(owner), MultisigError::OwnerAlreadyAdded);
+
+        storage.owners.push(owner);
+
+        log(AddOwnerEvent { owner });
+    }
+
+    /// Removes an owner.
+    ///
+    /// ### Additional Information
+    ///
+    /// Called by the multisig itself, through an executed proposal. The approvals of the owner stop counting,
+    /// also once the owner is added again.
+    ///
+    /// ### Arguments
+    ///
+    /// * `owner`: [Identity] - The owner to remove.
+    ///
+    /// ### Reverts
+    ///
+    /// * When not called by the multisig.
+    /// * When the owner is not found.
+    /// * When fewer owners than the threshold would remain.
+    #[storage(read, write)]
+    fn remove_owner(owner: Identity) {
+        only_self();
+        let len = storage.owners.len();
+        require(
+            len > storage.threshold.read(),
+            MultisigError::InvalidThreshold,
+        );
+
+        let mut idx = 0;
+        while idx < len {
+            if storage.owners.get(idx).unwrap().read() == owner {
+                let _ = storage.owners.swap_remove(idx);
+                storage.owner_nonces.insert(owner, owner_nonce(owner) + 1);
+                log(RemoveOwnerEvent { owner });
+                return;
+            }
+            idx += 1;
+        }
+        require(false, MultisigError::OwnerNotFound);
+    }
+
+    /// Changes the number of approvals required to execute a proposal.
+    ///
+    /// ### Additional Information
+    ///
+    /// Called by the multisig itself, through an executed proposal. Applies to the pending proposals as well.
+    ///
+    /// ### Arguments
+    ///
+    /// * `threshold`: [u64] - The number of approvals.
+    ///
+    /// ### Reverts
+    ///
+    /// * When not called by the multisig.
+    /// * When the threshold is zero or greater than the number of owners.
+    #[storage(read, write)]
+    fn set_threshold(threshold: u64) {
+        only_self();
+        require(
+            threshold > 0 && threshold <= storage.owners.len(),
+            MultisigError::InvalidThreshold,
+        );
+
+        storage.threshold.write(threshold);
+
+        log(SetThresholdEvent { threshold });
+    }
+
+    /// Transfers an asset held by the multisig, such as the fees withdrawn from a market it owns.
+    ///
+    /// ### Additional Information
+    ///
+    /// Called by the multisig itself, through an executed proposal.
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to transfer.
+    /// * `amount`: [u64] - The amount to transfer.
+    /// * `recipient`: [Identity] - The receiver of the asset.
+    ///
+    /// ### Reverts
+    ///
+    /// * When not called by the multisig.
+    /// * When the amount is zero.
+    /// * When the multisig holds less than the amount.
+    fn transfer(asset: AssetId, amount: u64, recipient: Identity) {
+        only_self();
+        require(amount > 0, MultisigError::ZeroAmount);
+
+        std::asset::transfer(recipient, asset, amount);
+
+        log(TransferEvent {
+            asset,
+            amount,
+            recipient,
+        });
+    }
+
+    #[storage(read)]
+    fn owners() -> Vec<Identity> {
+        storage.owners.load_vec()
+    }
+
+    #[storage(read)]
+    fn threshold() -> u64 {
+        storage.threshold.read()
+    }
+
+    #[storage(read)]
+    fn proposals_count() -> u64 {
+        storage.proposals.len()
+    }
+
+    /// Returns the proposed call with its status and the number of approvals by the current owners.
+    ///
+    /// ### Arguments
+    ///
+    /// * `id`: [u64] - The id of the proposal.
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<ProposalInfo>] - The proposal, none when not found.
+    #[storage(read)]
+    fn proposal(id: u64) -> Option<ProposalInfo> {
+        match storage.proposals.get(id) {
+            Some(proposal) => {
+                let proposal = proposal.read();
+                Some(ProposalInfo {
+                    id,
+                    target: proposal.target,
+                    function_selector: storage.proposal_selectors.get(id).read_slice().unwrap_or(Bytes::new()),
+                    calldata: storage.proposal_calldata.get(id).read_slice().unwrap_or(Bytes::new()),
+                    proposer: proposal.proposer,
+                    status: proposal.status,
+                    approvals: approvals_count(id),
+                })
+            }
+            None => None,
+        }
+    }
+
+    #[storage(read)]
+    fn approved(id: u64, owner: Identity) -> bool {
+        is_approved(id, owner)
+    }
+
+    fn config() -> u32 {
+        VERSION
+    }
+}
+
+#[storage(read)]
+fn is_owner(identity: Identity) -> bool {
+    let len = storage.owners.len();
+    let mut idx = 0;
+    while idx < len {
+        if storage.owners.get(idx).unwrap().read() == identity {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
+#[storage(read)]
+fn only_multisig_owner() -> Identity {
+    let sender = msg_sender().unwrap();
+    require(is_owner(sender), MultisigError::NotOwner);
+    sender
+}
+
+fn only_self() {
+    require(
+        msg_sender().unwrap() == Identity::ContractId(ContractId::this()),
+        MultisigError::NotMultisig,
+    );
+}
+
+#[storage(read)]
+fn pending_proposal(id: u64) -> Proposal {
+    let proposal = match storage.proposals.get(id) {
+        Some(proposal) => proposal.read(),
+        None => {
+            require(false, MultisigError::ProposalNotFound);
+            revert(0)
+        }
+    };
+    require(
+        proposal.status == ProposalStatus::Pending,
+        MultisigError::ProposalNotPending,
+    );
+    proposal
+}
+
+#[storage(read)]
+fn owner_nonce(owner: Identity) -> u64 {
+    storage.owner_nonces.get(owner).try_read().unwrap_or(0)
+}
+
+#[storage(read)]
+fn is_approved(id: u64, owner: Identity) -> bool {
+    storage.approvals.get((id, owner, owner_nonce(owner))).try_read().unwrap_or(false)
+}
+
+#[storage(read)]
+fn approvals_count(id: u64) -> u64 {
+    let len = storage.owners.len();
+    let mut count = 0;
+    let mut idx = 0;
+    while idx < len {
+        if is_approved(id, storage.owners.get(idx).unwrap().read()) {
+            count += 1;
+        }
+        idx += 1;
+    }
+    count
+}
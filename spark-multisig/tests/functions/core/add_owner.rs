@@ -0,0 +1,64 @@
+mod success {
+
+    use crate::setup::{approved_proposal, setup};
+    use spark_multisig_sdk::{AddOwnerEvent, AdminCall};
+
+    #[tokio::test]
+    async fn adds_owner() -> anyhow::Result<()> {
+        let (contract, owners, user) = setup().await?;
+        let call = AdminCall::from_call_handler(&contract.add_owner_call_handler(user.identity()))?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+
+        let response = contract.execute(id).await?;
+
+        let log = response.decode_logs_with_type::<AddOwnerEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            AddOwnerEvent {
+                owner: user.identity(),
+            }
+        );
+        assert!(contract.owners().await?.value.contains(&user.identity()));
+        // The new owner proposes
+        contract
+            .with_account(&user.wallet)
+            .propose(AdminCall::from_call_handler(
+                &contract.set_threshold_call_handler(3),
+            )?)
+            .await?;
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{approved_proposal, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotMultisig")]
+    async fn reverts_when_not_proposed() {
+        let (contract, _, user) = setup().await.unwrap();
+
+        // Reverts, owners are changed by proposals only
+        contract
+            .with_account(&user.wallet)
+            .add_owner_call_handler(user.identity())
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OwnerAlreadyAdded")]
+    async fn reverts_when_owner() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let call =
+            AdminCall::from_call_handler(&contract.add_owner_call_handler(owners[2].identity()))
+                .unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+mod success {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, ApproveEvent};
+
+    #[tokio::test]
+    async fn approves_proposal() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let id = contract
+            .propose(AdminCall::from_call_handler(&market.pause_call_handler())?)
+            .await?
+            .value;
+
+        let response = contract.with_account(&owners[1].wallet).approve(id).await?;
+
+        let log = response.decode_logs_with_type::<ApproveEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            ApproveEvent {
+                id,
+                owner: owners[1].identity(),
+            }
+        );
+        assert!(contract.approved(id, owners[1].identity()).await?.value);
+        assert_eq!(contract.proposal(id).await?.value.unwrap().approvals, 2);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{approved_proposal, deploy_market, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, owners, user) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .approve(id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyApproved")]
+    async fn reverts_when_already_approved() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+
+        // Reverts
+        contract.approve(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProposalNotFound")]
+    async fn reverts_when_not_found() {
+        let (contract, _, _) = setup().await.unwrap();
+
+        // Reverts
+        contract.approve(0).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProposalNotPending")]
+    async fn reverts_when_executed() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+        contract.execute(id).await.unwrap();
+
+        // Reverts
+        contract
+            .with_account(&owners[2].wallet)
+            .approve(id)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,58 @@
+mod success {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, CancelEvent, ProposalStatus};
+
+    #[tokio::test]
+    async fn cancels_proposal() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.pause_call_handler())?;
+        let id = contract.propose(call).await?.value;
+
+        let response = contract.cancel(id).await?;
+
+        let log = response.decode_logs_with_type::<CancelEvent>().unwrap();
+        assert_eq!(*log.first().unwrap(), CancelEvent { id });
+        assert_eq!(
+            contract.proposal(id).await?.value.unwrap().status,
+            ProposalStatus::Cancelled
+        );
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotProposer")]
+    async fn reverts_when_not_proposer() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+
+        // Reverts
+        contract
+            .with_account(&owners[1].wallet)
+            .cancel(id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProposalNotPending")]
+    async fn reverts_when_cancelled() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+        contract.cancel(id).await.unwrap();
+
+        // Reverts
+        contract.cancel(id).await.unwrap();
+    }
+}
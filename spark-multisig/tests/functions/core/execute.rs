@@ -0,0 +1,85 @@
+mod success {
+
+    use crate::setup::{approved_proposal, deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, ExecuteEvent, ProposalStatus};
+
+    #[tokio::test]
+    async fn executes_admin_call() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.pause_call_handler())?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+        assert!(!market.is_paused().await?.value);
+
+        let response = contract.with_account(&owners[2].wallet).execute(id).await?;
+
+        let log = response.decode_logs_with_type::<ExecuteEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            ExecuteEvent {
+                id,
+                executor: owners[2].identity(),
+            }
+        );
+        assert!(market.is_paused().await?.value);
+        assert_eq!(
+            contract.proposal(id).await?.value.unwrap().status,
+            ProposalStatus::Executed
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn executes_admin_call_with_arguments() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.set_matcher_fee_call_handler(42))?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+
+        contract.execute(id).await?;
+
+        assert_eq!(market.matcher_fee().await?.value, 42);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{approved_proposal, deploy_market, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "ThresholdNotReached")]
+    async fn reverts_when_threshold_not_reached() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProposalNotPending")]
+    async fn reverts_when_executed() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+        contract.execute(id).await.unwrap();
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_call_by_owner_key() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+
+        // Reverts, the owner keys of the multisig do not own the market
+        market.pause().await.unwrap();
+    }
+}
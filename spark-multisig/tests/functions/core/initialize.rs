@@ -0,0 +1,85 @@
+mod success {
+
+    use crate::setup::{deploy_uninitialized, setup, User};
+
+    #[tokio::test]
+    async fn initializes_by_deployer() -> anyhow::Result<()> {
+        let (_, owners, _) = setup().await?;
+        let contract = deploy_uninitialized(&owners[0]).await?;
+
+        contract
+            .initialize(owners.iter().map(User::identity).collect(), 2)
+            .await?;
+
+        assert_eq!(
+            contract.owners().await?.value,
+            owners.iter().map(User::identity).collect::<Vec<_>>()
+        );
+        assert_eq!(contract.threshold().await?.value, 2);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_uninitialized, setup, User};
+    use spark_multisig_sdk::SparkMultisigContract;
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyInitialized")]
+    async fn reverts_when_initialized() {
+        let (contract, owners, _) = setup().await.unwrap();
+
+        // Reverts
+        contract
+            .initialize(vec![owners[0].identity()], 1)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotDeployer")]
+    async fn reverts_when_not_deployer() {
+        let (_, owners, user) = setup().await.unwrap();
+        let contract = deploy_uninitialized(&owners[0]).await.unwrap();
+
+        // Reverts, the initialization can not be front-run
+        contract
+            .with_account(&user.wallet)
+            .initialize(vec![user.identity()], 1)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidThreshold")]
+    async fn reverts_when_threshold_above_owners() {
+        let (_, owners, _) = setup().await.unwrap();
+
+        // Reverts
+        SparkMultisigContract::deploy(
+            owners[0].wallet.clone(),
+            owners.iter().map(User::identity).collect(),
+            4,
+            0xFAFBFC,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OwnerAlreadyAdded")]
+    async fn reverts_when_owner_listed_twice() {
+        let (_, owners, _) = setup().await.unwrap();
+
+        // Reverts
+        SparkMultisigContract::deploy(
+            owners[0].wallet.clone(),
+            vec![owners[0].identity(), owners[0].identity()],
+            1,
+            0xFAFBFC,
+        )
+        .await
+        .unwrap();
+    }
+}
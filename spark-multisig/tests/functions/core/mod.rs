@@ -0,0 +1,10 @@
+mod add_owner;
+mod approve;
+mod cancel;
+mod execute;
+mod initialize;
+mod propose;
+mod remove_owner;
+mod revoke;
+mod set_threshold;
+mod transfer;
@@ -0,0 +1,86 @@
+mod success {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, ApproveEvent, ProposalStatus, ProposeEvent};
+
+    #[tokio::test]
+    async fn proposes_call() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.pause_call_handler())?;
+
+        let response = contract.propose(call.clone()).await?;
+
+        assert_eq!(response.value, 0);
+        let log = response.decode_logs_with_type::<ProposeEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            ProposeEvent {
+                id: 0,
+                proposer: owners[0].identity(),
+                target: call.target,
+            }
+        );
+        // The proposer approves its proposal
+        let log = response.decode_logs_with_type::<ApproveEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            ApproveEvent {
+                id: 0,
+                owner: owners[0].identity(),
+            }
+        );
+
+        let proposal = contract.proposal(0).await?.value.unwrap();
+        assert_eq!(proposal.target, call.target);
+        assert_eq!(proposal.function_selector.0, call.function_selector);
+        assert_eq!(proposal.calldata.0, call.calldata);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+        assert_eq!(proposal.approvals, 1);
+        assert_eq!(contract.proposals_count().await?.value, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn numbers_proposals() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+
+        let first = contract
+            .propose(AdminCall::from_call_handler(&market.pause_call_handler())?)
+            .await?
+            .value;
+        let second = contract
+            .with_account(&owners[1].wallet)
+            .propose(AdminCall::from_call_handler(
+                &market.set_matcher_fee_call_handler(10),
+            )?)
+            .await?
+            .value;
+
+        assert_eq!((first, second), (0, 1));
+        assert_eq!(contract.proposals_count().await?.value, 2);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, owners, user) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .propose(call)
+            .await
+            .unwrap();
+    }
+}
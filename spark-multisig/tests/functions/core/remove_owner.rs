@@ -0,0 +1,148 @@
+mod success {
+
+    use crate::setup::{approved_proposal, deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, RemoveOwnerEvent};
+
+    #[tokio::test]
+    async fn removes_owner() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let call = AdminCall::from_call_handler(
+            &contract.remove_owner_call_handler(owners[2].identity()),
+        )?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+
+        let response = contract.execute(id).await?;
+
+        let log = response
+            .decode_logs_with_type::<RemoveOwnerEvent>()
+            .unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            RemoveOwnerEvent {
+                owner: owners[2].identity(),
+            }
+        );
+        assert_eq!(
+            contract.owners().await?.value,
+            vec![owners[0].identity(), owners[1].identity()]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stops_counting_approvals_of_removed_owner() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let pause = AdminCall::from_call_handler(&market.pause_call_handler())?;
+        let pause_id = contract
+            .with_account(&owners[2].wallet)
+            .propose(pause)
+            .await?
+            .value;
+        assert_eq!(
+            contract.proposal(pause_id).await?.value.unwrap().approvals,
+            1
+        );
+
+        let call = AdminCall::from_call_handler(
+            &contract.remove_owner_call_handler(owners[2].identity()),
+        )?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+        contract.execute(id).await?;
+
+        assert_eq!(
+            contract.proposal(pause_id).await?.value.unwrap().approvals,
+            0
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn does_not_count_approvals_of_re_added_owner() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let pause = AdminCall::from_call_handler(&market.pause_call_handler())?;
+        let pause_id = contract
+            .with_account(&owners[2].wallet)
+            .propose(pause)
+            .await?
+            .value;
+
+        let call = AdminCall::from_call_handler(
+            &contract.remove_owner_call_handler(owners[2].identity()),
+        )?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+        contract.execute(id).await?;
+        let call =
+            AdminCall::from_call_handler(&contract.add_owner_call_handler(owners[2].identity()))?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+        contract.execute(id).await?;
+
+        // The approval given before the removal is not restored
+        assert!(
+            !contract
+                .approved(pause_id, owners[2].identity())
+                .await?
+                .value
+        );
+        assert_eq!(
+            contract.proposal(pause_id).await?.value.unwrap().approvals,
+            0
+        );
+
+        contract
+            .with_account(&owners[2].wallet)
+            .approve(pause_id)
+            .await?;
+        assert_eq!(
+            contract.proposal(pause_id).await?.value.unwrap().approvals,
+            1
+        );
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{approved_proposal, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "OwnerNotFound")]
+    async fn reverts_when_not_owner() {
+        let (contract, owners, user) = setup().await.unwrap();
+        let call =
+            AdminCall::from_call_handler(&contract.remove_owner_call_handler(user.identity()))
+                .unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidThreshold")]
+    async fn reverts_when_below_threshold() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let call = AdminCall::from_call_handler(&contract.set_threshold_call_handler(3)).unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+        contract.execute(id).await.unwrap();
+        let call =
+            AdminCall::from_call_handler(&contract.remove_owner_call_handler(owners[2].identity()))
+                .unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+        contract
+            .with_account(&owners[1].wallet)
+            .approve(id)
+            .await
+            .unwrap();
+        contract
+            .with_account(&owners[2].wallet)
+            .approve(id)
+            .await
+            .unwrap();
+
+        // Reverts, 3 of 2 owners
+        contract.execute(id).await.unwrap();
+    }
+}
@@ -0,0 +1,49 @@
+mod success {
+
+    use crate::setup::{approved_proposal, deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, RevokeEvent};
+
+    #[tokio::test]
+    async fn revokes_approval() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.pause_call_handler())?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+
+        let response = contract.with_account(&owners[1].wallet).revoke(id).await?;
+
+        let log = response.decode_logs_with_type::<RevokeEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            RevokeEvent {
+                id,
+                owner: owners[1].identity(),
+            }
+        );
+        assert!(!contract.approved(id, owners[1].identity()).await?.value);
+        assert_eq!(contract.proposal(id).await?.value.unwrap().approvals, 1);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotApproved")]
+    async fn reverts_when_not_approved() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let market = deploy_market(&contract, &owners[0]).await.unwrap();
+        let call = AdminCall::from_call_handler(&market.pause_call_handler()).unwrap();
+        let id = contract.propose(call).await.unwrap().value;
+
+        // Reverts
+        contract
+            .with_account(&owners[1].wallet)
+            .revoke(id)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,58 @@
+mod success {
+
+    use crate::setup::{approved_proposal, deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, SetThresholdEvent};
+
+    #[tokio::test]
+    async fn sets_threshold() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let call = AdminCall::from_call_handler(&contract.set_threshold_call_handler(3))?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+
+        let response = contract.execute(id).await?;
+
+        let log = response
+            .decode_logs_with_type::<SetThresholdEvent>()
+            .unwrap();
+        assert_eq!(*log.first().unwrap(), SetThresholdEvent { threshold: 3 });
+        assert_eq!(contract.threshold().await?.value, 3);
+
+        // Two approvals are not enough anymore
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.pause_call_handler())?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+        assert!(contract.execute(id).await.is_err());
+        contract.with_account(&owners[2].wallet).approve(id).await?;
+        contract.execute(id).await?;
+        assert!(market.is_paused().await?.value);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{approved_proposal, setup};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidThreshold")]
+    async fn reverts_when_zero() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let call = AdminCall::from_call_handler(&contract.set_threshold_call_handler(0)).unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidThreshold")]
+    async fn reverts_when_above_owners() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let call = AdminCall::from_call_handler(&contract.set_threshold_call_handler(4)).unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+mod success {
+
+    use crate::setup::{
+        approved_proposal, deploy_market_with_protocol_fee, setup, BASE_ASSET, QUOTE_ASSET,
+    };
+    use fuels::accounts::ViewOnlyAccount;
+    use spark_market_sdk::{AssetType, OrderType, ProtocolFee};
+    use spark_multisig_sdk::{AdminCall, TransferEvent};
+
+    #[tokio::test]
+    async fn transfers_protocol_fee_withdrawn_from_market() -> anyhow::Result<()> {
+        let (contract, owners, user) = setup().await?;
+        let protocol_fee = vec![ProtocolFee {
+            maker_fee: 0,
+            taker_fee: 15,
+            volume_threshold: 0,
+        }];
+        let market = deploy_market_with_protocol_fee(&contract, &owners[0], protocol_fee).await?;
+        let provider = owners[0].wallet.provider().unwrap();
+
+        let price = 70_000_000_000_000;
+        let base_amount = 100_000_000;
+        let quote_amount = 7_000_000_000;
+        let taker_protocol_fee = quote_amount * 15 / 10_000;
+        market
+            .with_account(&user.wallet)
+            .deposit(base_amount, BASE_ASSET)
+            .await?;
+        market
+            .with_account(&owners[1].wallet)
+            .deposit(quote_amount + taker_protocol_fee, QUOTE_ASSET)
+            .await?;
+        let id0 = market
+            .with_account(&user.wallet)
+            .open_order(base_amount, OrderType::Sell, price)
+            .await?
+            .value;
+        let id1 = market
+            .with_account(&owners[1].wallet)
+            .open_order(base_amount, OrderType::Buy, price)
+            .await?
+            .value;
+        market.match_order_pair(id0, id1).await?;
+
+        // The protocol fee is credited to the multisig as the market owner
+        assert_eq!(
+            market
+                .account(contract.identity())
+                .await?
+                .value
+                .liquid
+                .quote,
+            taker_protocol_fee
+        );
+
+        let call = AdminCall::from_call_handler(
+            &market.withdraw_call_handler(taker_protocol_fee, AssetType::Quote),
+        )?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+        contract.execute(id).await?;
+
+        assert_eq!(
+            provider
+                .get_contract_asset_balance(contract.contract_id(), QUOTE_ASSET)
+                .await?,
+            taker_protocol_fee
+        );
+
+        let balance = owners[2].wallet.get_asset_balance(&QUOTE_ASSET).await?;
+        let call = AdminCall::from_call_handler(&contract.transfer_call_handler(
+            QUOTE_ASSET,
+            taker_protocol_fee,
+            owners[2].identity(),
+        ))?;
+        let id = approved_proposal(&contract, &owners, call).await?;
+
+        let response = contract.execute(id).await?;
+
+        let log = response.decode_logs_with_type::<TransferEvent>().unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            TransferEvent {
+                asset: QUOTE_ASSET,
+                amount: taker_protocol_fee,
+                recipient: owners[2].identity(),
+            }
+        );
+        assert_eq!(
+            provider
+                .get_contract_asset_balance(contract.contract_id(), QUOTE_ASSET)
+                .await?,
+            0
+        );
+        assert_eq!(
+            owners[2].wallet.get_asset_balance(&QUOTE_ASSET).await?,
+            balance + taker_protocol_fee
+        );
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{approved_proposal, setup, QUOTE_ASSET};
+    use spark_multisig_sdk::AdminCall;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotMultisig")]
+    async fn reverts_when_not_proposed() {
+        let (contract, owners, _) = setup().await.unwrap();
+
+        // Reverts, assets are transferred by proposals only
+        contract
+            .transfer_call_handler(QUOTE_ASSET, 1, owners[0].identity())
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn reverts_when_zero_amount() {
+        let (contract, owners, _) = setup().await.unwrap();
+        let call = AdminCall::from_call_handler(&contract.transfer_call_handler(
+            QUOTE_ASSET,
+            0,
+            owners[0].identity(),
+        ))
+        .unwrap();
+        let id = approved_proposal(&contract, &owners, call).await.unwrap();
+
+        // Reverts
+        contract.execute(id).await.unwrap();
+    }
+}
@@ -0,0 +1,2 @@
+mod owners;
+mod proposal;
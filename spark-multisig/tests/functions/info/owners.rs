@@ -0,0 +1,20 @@
+mod success {
+
+    use crate::setup::setup;
+
+    #[tokio::test]
+    async fn returns_owners_and_threshold() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+
+        assert_eq!(
+            contract.owners().await?.value,
+            owners
+                .iter()
+                .map(|owner| owner.identity())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(contract.threshold().await?.value, 2);
+        assert_eq!(contract.config().await?.value, 0xFAFBFC);
+        Ok(())
+    }
+}
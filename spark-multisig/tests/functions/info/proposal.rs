@@ -0,0 +1,42 @@
+mod success {
+
+    use crate::setup::{deploy_market, setup};
+    use spark_multisig_sdk::{AdminCall, ProposalInfo, ProposalStatus};
+
+    #[tokio::test]
+    async fn returns_none_when_not_found() -> anyhow::Result<()> {
+        let (contract, _, _) = setup().await?;
+
+        assert_eq!(contract.proposal(0).await?.value, None);
+        assert_eq!(contract.proposals_count().await?.value, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_proposal() -> anyhow::Result<()> {
+        let (contract, owners, _) = setup().await?;
+        let market = deploy_market(&contract, &owners[0]).await?;
+        let call = AdminCall::from_call_handler(&market.set_matcher_fee_call_handler(5))?;
+        let id = contract
+            .with_account(&owners[1].wallet)
+            .propose(call.clone())
+            .await?
+            .value;
+
+        assert_eq!(
+            contract.proposal(id).await?.value,
+            Some(ProposalInfo {
+                id,
+                target: call.target,
+                function_selector: fuels::types::Bytes(call.function_selector),
+                calldata: fuels::types::Bytes(call.calldata),
+                proposer: owners[1].identity(),
+                status: ProposalStatus::Pending,
+                approvals: 1,
+            })
+        );
+        assert!(contract.approved(id, owners[1].identity()).await?.value);
+        assert!(!contract.approved(id, owners[0].identity()).await?.value);
+        Ok(())
+    }
+}
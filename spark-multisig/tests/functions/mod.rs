@@ -0,0 +1,2 @@
+mod core;
+mod info;
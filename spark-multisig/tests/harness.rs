@@ -0,0 +1,2 @@
+mod functions;
+mod setup;
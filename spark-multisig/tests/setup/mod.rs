@@ -0,0 +1,124 @@
+use fuels::{
+    prelude::{
+        launch_custom_provider_and_get_wallets, Address, AssetConfig, AssetId, Contract,
+        LoadConfiguration, StorageConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
+    },
+    types::Identity,
+};
+use spark_market_sdk::{ProtocolFee, SparkMarketContract};
+use spark_multisig_sdk::{AdminCall, SparkMultisigConfigurables, SparkMultisigContract};
+use std::path::PathBuf;
+
+pub(crate) const BASE_ASSET: AssetId = AssetId::new([0; 32]);
+pub(crate) const QUOTE_ASSET: AssetId = AssetId::new([1; 32]);
+
+pub(crate) struct User {
+    pub(crate) wallet: WalletUnlocked,
+}
+
+impl User {
+    pub(crate) fn address(&self) -> Address {
+        Address::from(self.wallet.address())
+    }
+
+    pub(crate) fn identity(&self) -> Identity {
+        Identity::Address(self.address())
+    }
+}
+
+/// Deploys a 2 of 3 multisig, returns the owners and a non-owner user
+pub(crate) async fn setup() -> anyhow::Result<(SparkMultisigContract, Vec<User>, User)> {
+    let number_of_wallets = 4;
+    let num_coins = 1;
+    let coin_amount = 1_000_000_000_000;
+
+    // The base asset of the chain is the base asset of the market, the quote asset is minted
+    let assets = [BASE_ASSET, QUOTE_ASSET]
+        .into_iter()
+        .map(|id| AssetConfig {
+            id,
+            num_coins,
+            coin_amount,
+        })
+        .collect();
+    let config = WalletsConfig::new_multiple_assets(number_of_wallets, assets);
+
+    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None).await?;
+    let user = User {
+        wallet: wallets.pop().unwrap(),
+    };
+    let owners = wallets
+        .into_iter()
+        .map(|wallet| User { wallet })
+        .collect::<Vec<_>>();
+
+    let contract = SparkMultisigContract::deploy(
+        owners[0].wallet.clone(),
+        owners.iter().map(User::identity).collect(),
+        2,
+        0xFAFBFC,
+    )
+    .await?;
+
+    Ok((contract, owners, user))
+}
+
+/// Deploys the multisig built in `out/release` without initializing it
+pub(crate) async fn deploy_uninitialized(deployer: &User) -> anyhow::Result<SparkMultisigContract> {
+    let out = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("out/release");
+    let storage_configuration = StorageConfiguration::default()
+        .add_slot_overrides_from_file(out.join("spark-multisig-storage_slots.json"))?;
+    let configurables = SparkMultisigConfigurables::default()
+        .with_VERSION(0xFAFBFC)?
+        .with_DEPLOYER(deployer.identity())?;
+    let configuration = LoadConfiguration::default()
+        .with_storage_configuration(storage_configuration)
+        .with_configurables(configurables);
+
+    let contract_id = Contract::load_from(out.join("spark-multisig.bin"), configuration)?
+        .deploy(&deployer.wallet, TxPolicies::default())
+        .await?;
+    Ok(SparkMultisigContract::new(contract_id.into(), deployer.wallet.clone()).await)
+}
+
+/// Deploys a market owned by the multisig
+pub(crate) async fn deploy_market(
+    contract: &SparkMultisigContract,
+    deployer: &User,
+) -> anyhow::Result<SparkMarketContract> {
+    deploy_market_with_protocol_fee(contract, deployer, vec![]).await
+}
+
+/// Deploys a market owned by the multisig, charging the protocol fee set by the deployer
+pub(crate) async fn deploy_market_with_protocol_fee(
+    contract: &SparkMultisigContract,
+    deployer: &User,
+    protocol_fee: Vec<ProtocolFee>,
+) -> anyhow::Result<SparkMarketContract> {
+    let market = SparkMarketContract::deploy(
+        BASE_ASSET,
+        9,
+        QUOTE_ASSET,
+        6,
+        deployer.wallet.clone(),
+        9,
+        0xFAFBFC,
+    )
+    .await?;
+    if !protocol_fee.is_empty() {
+        market.set_protocol_fee(protocol_fee).await?;
+    }
+    market.transfer_ownership(contract.identity()).await?;
+    Ok(market)
+}
+
+/// Proposes the call by the first owner and approves it by the second one
+pub(crate) async fn approved_proposal(
+    contract: &SparkMultisigContract,
+    owners: &[User],
+    call: AdminCall,
+) -> anyhow::Result<u64> {
+    let id = contract.propose(call).await?.value;
+    contract.with_account(&owners[1].wallet).approve(id).await?;
+    Ok(id)
+}
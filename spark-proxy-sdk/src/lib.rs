@@ -1,9 +1,13 @@
 use fuels::{
+    accounts::{impersonated_account::ImpersonatedAccount, Account},
     prelude::{
-        abigen, Address, Bech32ContractId, Contract, ContractId, LoadConfiguration,
+        abigen, Address, Bech32ContractId, Contract, ContractId, LoadConfiguration, Provider,
         StorageConfiguration, TxPolicies, WalletUnlocked,
     },
-    programs::{calls::Execution, responses::CallResponse},
+    programs::{
+        calls::{CallHandler, ContractCall, Execution},
+        responses::CallResponse,
+    },
     tx::StorageSlot,
     types::Bytes32,
 };
@@ -20,12 +24,77 @@ const SPARK_PROXY_CONTRACT_BINARY_PATH: &str = "spark-proxy/out/release/spark-pr
 //const SPARK_PROXY_CONTRACT_STORAGE_PATH: &str =
 //    "spark-proxy/out/release/spark-proxy-storage_slots.json";
 
-pub struct SparkProxyContract {
-    instance: SparkProxy<WalletUnlocked>,
+pub struct SparkProxyContract<A = WalletUnlocked> {
+    instance: SparkProxy<A>,
 }
 
-impl SparkProxyContract {
-    pub async fn deploy(target: ContractId, owner: WalletUnlocked) -> anyhow::Result<Self> {
+/// Proxy client for the read methods that does not hold any private key
+pub type SparkProxyReader = SparkProxyContract<ImpersonatedAccount>;
+
+impl<A: Account> SparkProxyContract<A> {
+    pub async fn new(contract_id: ContractId, wallet: A) -> Self {
+        Self {
+            instance: SparkProxy::new(contract_id, wallet),
+        }
+    }
+
+    pub fn id(&self) -> Bytes32 {
+        self.instance.contract_id().hash
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        self.instance.contract_id()
+    }
+
+    pub async fn proxy_target(&self) -> anyhow::Result<CallResponse<Option<ContractId>>> {
+        Ok(self
+            .instance
+            .methods()
+            .proxy_target()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn proxy_owner(&self) -> anyhow::Result<CallResponse<State>> {
+        Ok(self
+            .instance
+            .methods()
+            .proxy_owner()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn pending_proxy_target(
+        &self,
+    ) -> anyhow::Result<CallResponse<Option<PendingTarget>>> {
+        Ok(self
+            .instance
+            .methods()
+            .pending_proxy_target()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn upgrade_delay(&self) -> anyhow::Result<CallResponse<u64>> {
+        Ok(self
+            .instance
+            .methods()
+            .upgrade_delay()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+}
+
+impl SparkProxyReader {
+    /// Connects to the proxy for the read methods with the provider only
+    pub async fn read_only(contract_id: ContractId, provider: Provider) -> Self {
+        let account = ImpersonatedAccount::new(Address::zeroed().into(), Some(provider));
+        Self::new(contract_id, account).await
+    }
+}
+
+impl<A: Account> SparkProxyContract<A> {
+    pub async fn deploy(target: ContractId, owner: A) -> anyhow::Result<Self> {
         let mut rng = rand::thread_rng();
         let salt = rng.gen::<[u8; 32]>();
 
@@ -94,45 +163,68 @@ impl SparkProxyContract {
         Ok(Self { instance: proxy })
     }
 
-    pub async fn new(contract_id: ContractId, wallet: WalletUnlocked) -> Self {
-        Self {
-            instance: SparkProxy::new(contract_id, wallet),
-        }
-    }
-
-    pub fn with_account(&self, account: &WalletUnlocked) -> Self {
-        Self {
+    pub fn with_account<B: Account>(&self, account: &B) -> SparkProxyContract<B> {
+        SparkProxyContract {
             instance: self.instance.clone().with_account(account.clone()),
         }
     }
 
-    pub fn id(&self) -> Bytes32 {
-        self.instance.contract_id().hash
+    pub async fn set_proxy_target(
+        &self,
+        new_target: ContractId,
+    ) -> anyhow::Result<CallResponse<()>> {
+        Ok(self
+            .set_proxy_target_call_handler(new_target)
+            .call()
+            .await?)
     }
 
-    pub fn contract_id(&self) -> &Bech32ContractId {
-        self.instance.contract_id()
+    pub fn set_proxy_target_call_handler(
+        &self,
+        new_target: ContractId,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_proxy_target(new_target)
     }
 
-    pub async fn set_proxy_target(
+    pub async fn propose_proxy_target(
         &self,
         new_target: ContractId,
     ) -> anyhow::Result<CallResponse<()>> {
         Ok(self
-            .instance
-            .methods()
-            .set_proxy_target(new_target)
+            .propose_proxy_target_call_handler(new_target)
             .call()
             .await?)
     }
 
-    pub async fn proxy_target(&self) -> anyhow::Result<CallResponse<Option<ContractId>>> {
-        Ok(self
-            .instance
-            .methods()
-            .proxy_target()
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub fn propose_proxy_target_call_handler(
+        &self,
+        new_target: ContractId,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().propose_proxy_target(new_target)
+    }
+
+    pub async fn execute_proxy_target(&self) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.execute_proxy_target_call_handler().call().await?)
+    }
+
+    pub fn execute_proxy_target_call_handler(&self) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().execute_proxy_target()
+    }
+
+    pub async fn cancel_proxy_target(&self) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.cancel_proxy_target_call_handler().call().await?)
+    }
+
+    pub fn cancel_proxy_target_call_handler(&self) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().cancel_proxy_target()
+    }
+
+    pub async fn set_upgrade_delay(&self, delay: u64) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.set_upgrade_delay_call_handler(delay).call().await?)
+    }
+
+    pub fn set_upgrade_delay_call_handler(&self, delay: u64) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_upgrade_delay(delay)
     }
 
     pub async fn set_proxy_owner(
@@ -140,19 +232,15 @@ impl SparkProxyContract {
         new_proxy_owner: State,
     ) -> anyhow::Result<CallResponse<()>> {
         Ok(self
-            .instance
-            .methods()
-            .set_proxy_owner(new_proxy_owner)
+            .set_proxy_owner_call_handler(new_proxy_owner)
             .call()
             .await?)
     }
 
-    pub async fn proxy_owner(&self) -> anyhow::Result<CallResponse<State>> {
-        Ok(self
-            .instance
-            .methods()
-            .proxy_owner()
-            .simulate(Execution::StateReadOnly)
-            .await?)
+    pub fn set_proxy_owner_call_handler(
+        &self,
+        new_proxy_owner: State,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_proxy_owner(new_proxy_owner)
     }
 }
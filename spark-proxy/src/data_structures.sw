@@ -0,0 +1,17 @@
+library;
+
+/// An upgrade of the proxy target waiting for its delay.
+pub struct PendingTarget {
+    /// The proposed target contract.
+    pub target: ContractId,
+    /// The block timestamp from which the upgrade can be executed.
+    pub executable_at: u64,
+}
+
+/// A decrease of the upgrade delay waiting for the current delay.
+pub struct PendingDelay {
+    /// The new upgrade delay in seconds.
+    pub delay: u64,
+    /// The block timestamp from which the new delay applies.
+    pub effective_at: u64,
+}
@@ -0,0 +1,12 @@
+library;
+
+pub enum ProxyUpgradeError {
+    /// The target can only be changed by a proposal while an upgrade delay is set.
+    UpgradeDelayActive: (),
+    UpgradeAlreadyProposed: (),
+    UpgradeNotProposed: (),
+    /// The upgrade delay of the proposal has not elapsed, carries the executable timestamp.
+    UpgradeNotReady: u64,
+    /// The upgrade delay is greater than 30 days.
+    UpgradeDelayTooLong: (),
+}
@@ -0,0 +1,19 @@
+library;
+
+pub struct ProposeProxyTargetEvent {
+    pub target: ContractId,
+    pub executable_at: u64,
+}
+
+pub struct ExecuteProxyTargetEvent {
+    pub target: ContractId,
+}
+
+pub struct CancelProxyTargetEvent {
+    pub target: ContractId,
+}
+
+pub struct SetUpgradeDelayEvent {
+    pub delay: u64,
+    pub effective_at: u64,
+}
@@ -1,5 +1,13 @@
 contract;
 
+mod data_structures;
+mod errors;
+mod events;
+
+use data_structures::*;
+use errors::*;
+use events::*;
+
 use sway_libs::upgradability::{
     _proxy_owner,
     _proxy_target,
@@ -8,7 +16,10 @@ use sway_libs::upgradability::{
     only_proxy_owner,
 };
 use standards::{src14::{SRC14, SRC14Extension}, src5::State};
-use std::execution::run_external;
+use std::{block::timestamp as block_timestamp, execution::run_external};
+
+/// The longest upgrade delay, 30 days, so that a mistaken delay can not lock the upgrades for good
+const MAX_UPGRADE_DELAY: u64 = 2_592_000;
 
 storage {
     SRC14 {
@@ -25,6 +36,26 @@ storage {
         /// `proxy_owner` is stored at sha256("storage_SRC14_1")
         proxy_owner in 0xbb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754: State = State::Uninitialized,
     },
+    SparkProxy {
+        /// The delay in seconds between the proposal and the execution of an upgrade.
+        ///
+        /// # Additional Information
+        ///
+        /// `upgrade_delay` is stored at sha256("storage_SparkProxy_0")
+        upgrade_delay in 0xcbbcee54b5a79b7f19b1e0f5546819be73ade69def43be29b1597b2da07def3e: u64 = 0,
+        /// The decrease of the upgrade delay waiting for the current delay.
+        ///
+        /// # Additional Information
+        ///
+        /// `pending_delay` is stored at sha256("storage_SparkProxy_1")
+        pending_delay in 0xc65a881d7fd2199b6e80ddd8d147cefdfdaf564c8e17e89ecc1557ac72a803f5: Option<PendingDelay> = None,
+        /// The proposed upgrade of the target.
+        ///
+        /// # Additional Information
+        ///
+        /// `pending_target` is stored at sha256("storage_SparkProxy_2")
+        pending_target in 0x7a295821397c45e9721a824928b55d7e9b389a65a53eb83967e4c360e5fb82df: Option<PendingTarget> = None,
+    },
 }
 
 abi ProxyOwner {
@@ -32,10 +63,40 @@ abi ProxyOwner {
     fn set_proxy_owner(new_proxy_owner: State);
 }
 
+abi ProxyUpgrade {
+    #[storage(read, write)]
+    fn propose_proxy_target(new_target: ContractId);
+
+    #[storage(read, write)]
+    fn execute_proxy_target();
+
+    #[storage(read, write)]
+    fn cancel_proxy_target();
+
+    #[storage(read, write)]
+    fn set_upgrade_delay(delay: u64);
+
+    #[storage(read)]
+    fn pending_proxy_target() -> Option<PendingTarget>;
+
+    #[storage(read)]
+    fn upgrade_delay() -> u64;
+}
+
 impl SRC14 for Contract {
+    /// Changes the target immediately.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When an upgrade delay is set, the target is changed by a proposal then.
     #[storage(read, write)]
     fn set_proxy_target(new_target: ContractId) {
         only_proxy_owner();
+        require(
+            read_upgrade_delay() == 0,
+            ProxyUpgradeError::UpgradeDelayActive,
+        );
         _set_proxy_target(new_target);
     }
 
@@ -54,6 +115,142 @@ impl ProxyOwner for Contract {
     }
 }
 
+impl ProxyUpgrade for Contract {
+    /// Proposes a new target executable once the upgrade delay has elapsed.
+    ///
+    /// ### Additional Information
+    ///
+    /// The users get notice of the new implementation by the 'ProposeProxyTargetEvent' before it manages their funds.
+    ///
+    /// ### Arguments
+    ///
+    /// * `new_target`: [ContractId] - The new target contract.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When an upgrade is already proposed.
+    #[storage(read, write)]
+    fn propose_proxy_target(new_target: ContractId) {
+        only_proxy_owner();
+        require(
+            read_pending_target().is_none(),
+            ProxyUpgradeError::UpgradeAlreadyProposed,
+        );
+
+        let executable_at = block_timestamp() + read_upgrade_delay();
+        storage::SparkProxy
+            .pending_target
+            .write(Some(PendingTarget {
+                target: new_target,
+                executable_at,
+            }));
+        log(ProposeProxyTargetEvent {
+            target: new_target,
+            executable_at,
+        });
+    }
+
+    /// Changes the target to the proposed one.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When no upgrade is proposed.
+    /// * When the upgrade delay of the proposal has not elapsed.
+    #[storage(read, write)]
+    fn execute_proxy_target() {
+        only_proxy_owner();
+        let pending = read_pending_target();
+        require(pending.is_some(), ProxyUpgradeError::UpgradeNotProposed);
+        let pending = pending.unwrap();
+        require(
+            block_timestamp() >= pending.executable_at,
+            ProxyUpgradeError::UpgradeNotReady(pending.executable_at),
+        );
+
+        storage::SparkProxy.pending_target.write(None);
+        _set_proxy_target(pending.target);
+        log(ExecuteProxyTargetEvent {
+            target: pending.target,
+        });
+    }
+
+    /// Cancels the proposed upgrade.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When no upgrade is proposed.
+    #[storage(read, write)]
+    fn cancel_proxy_target() {
+        only_proxy_owner();
+        let pending = read_pending_target();
+        require(pending.is_some(), ProxyUpgradeError::UpgradeNotProposed);
+
+        storage::SparkProxy.pending_target.write(None);
+        log(CancelProxyTargetEvent {
+            target: pending.unwrap().target,
+        });
+    }
+
+    /// Sets the delay between the proposal and the execution of an upgrade.
+    ///
+    /// ### Additional Information
+    ///
+    /// An increase applies immediately, a decrease applies once the current delay has elapsed,
+    /// so that the notice period can not be skipped by lowering it first. A proposed upgrade keeps its executable timestamp.
+    ///
+    /// ### Arguments
+    ///
+    /// * `delay`: [u64] - The new delay in seconds.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When the delay is greater than `MAX_UPGRADE_DELAY`.
+    #[storage(read, write)]
+    fn set_upgrade_delay(delay: u64) {
+        only_proxy_owner();
+        require(
+            delay <= MAX_UPGRADE_DELAY,
+            ProxyUpgradeError::UpgradeDelayTooLong,
+        );
+        let current = read_upgrade_delay();
+        let now = block_timestamp();
+
+        let effective_at = if delay >= current {
+            storage::SparkProxy.upgrade_delay.write(delay);
+            storage::SparkProxy.pending_delay.write(None);
+            now
+        } else {
+            storage::SparkProxy.upgrade_delay.write(current);
+            let effective_at = now + current;
+            storage::SparkProxy
+                .pending_delay
+                .write(Some(PendingDelay {
+                    delay,
+                    effective_at,
+                }));
+            effective_at
+        };
+        log(SetUpgradeDelayEvent {
+            delay,
+            effective_at,
+        });
+    }
+
+    #[storage(read)]
+    fn pending_proxy_target() -> Option<PendingTarget> {
+        read_pending_target()
+    }
+
+    #[storage(read)]
+    fn upgrade_delay() -> u64 {
+        read_upgrade_delay()
+    }
+}
+
 impl SRC14Extension for Contract {
     #[storage(read)]
     fn proxy_owner() -> State {
@@ -61,6 +258,30 @@ impl SRC14Extension for Contract {
     }
 }
 
+/// The slots of the proxies deployed before the upgrade delay are unset and read as the defaults
+#[storage(read)]
+fn read_upgrade_delay() -> u64 {
+    let delay = storage::SparkProxy.upgrade_delay.try_read().unwrap_or(0);
+    match storage::SparkProxy.pending_delay.try_read() {
+        Some(Some(pending)) => {
+            if block_timestamp() >= pending.effective_at {
+                pending.delay
+            } else {
+                delay
+            }
+        }
+        _ => delay,
+    }
+}
+
+#[storage(read)]
+fn read_pending_target() -> Option<PendingTarget> {
+    match storage::SparkProxy.pending_target.try_read() {
+        Some(pending) => pending,
+        None => None,
+    }
+}
+
 #[fallback]
 #[storage(read)]
 fn fallback() {
@@ -0,0 +1,57 @@
+use crate::setup::setup_proxy;
+use fuels::types::ContractId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_proxy_target() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+        contract.set_upgrade_delay(3600).await?;
+        contract
+            .propose_proxy_target(contract.contract_id().into())
+            .await?;
+
+        contract.cancel_proxy_target().await?;
+
+        assert_eq!(contract.pending_proxy_target().await?.value, None);
+        assert_eq!(
+            contract.proxy_target().await?.value,
+            Some(ContractId::zeroed())
+        );
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, _, user) = setup_proxy().await.unwrap();
+        contract
+            .propose_proxy_target(contract.contract_id().into())
+            .await
+            .unwrap();
+
+        // Attempt to cancel with a non-owner user
+        contract
+            .with_account(&user.wallet)
+            .cancel_proxy_target()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UpgradeNotProposed")]
+    async fn reverts_when_not_proposed() {
+        let (contract, _, _) = setup_proxy().await.unwrap();
+
+        // Reverts
+        contract.cancel_proxy_target().await.unwrap();
+    }
+}
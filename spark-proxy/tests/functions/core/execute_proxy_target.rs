@@ -0,0 +1,81 @@
+use crate::setup::{advance_time, setup_proxy};
+use fuels::types::ContractId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn executes_without_delay() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+        let target: ContractId = contract.contract_id().into();
+        contract.propose_proxy_target(target).await?;
+
+        contract.execute_proxy_target().await?;
+
+        assert_eq!(contract.proxy_target().await?.value, Some(target));
+        assert_eq!(contract.pending_proxy_target().await?.value, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn executes_after_delay() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup_proxy().await?;
+        let target: ContractId = contract.contract_id().into();
+        contract.set_upgrade_delay(3600).await?;
+        contract.propose_proxy_target(target).await?;
+
+        advance_time(&owner, 7200).await?;
+        contract.execute_proxy_target().await?;
+
+        assert_eq!(contract.proxy_target().await?.value, Some(target));
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, _, user) = setup_proxy().await.unwrap();
+        contract
+            .propose_proxy_target(contract.contract_id().into())
+            .await
+            .unwrap();
+
+        // Attempt to execute with a non-owner user
+        contract
+            .with_account(&user.wallet)
+            .execute_proxy_target()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UpgradeNotProposed")]
+    async fn reverts_when_not_proposed() {
+        let (contract, _, _) = setup_proxy().await.unwrap();
+
+        // Reverts
+        contract.execute_proxy_target().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UpgradeNotReady")]
+    async fn reverts_when_delay_not_elapsed() {
+        let (contract, _, _) = setup_proxy().await.unwrap();
+        contract.set_upgrade_delay(3600).await.unwrap();
+        contract
+            .propose_proxy_target(contract.contract_id().into())
+            .await
+            .unwrap();
+
+        // Reverts
+        contract.execute_proxy_target().await.unwrap();
+    }
+}
@@ -1,2 +1,6 @@
+mod cancel_proxy_target;
+mod execute_proxy_target;
+mod propose_proxy_target;
 mod set_proxy_owner;
 mod set_proxy_target;
+mod set_upgrade_delay;
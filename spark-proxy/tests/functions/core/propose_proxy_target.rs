@@ -0,0 +1,60 @@
+use crate::setup::setup_proxy;
+use fuels::types::ContractId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn proposes_proxy_target() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+        let target: ContractId = contract.contract_id().into();
+        contract.set_upgrade_delay(3600).await?;
+
+        contract.propose_proxy_target(target).await?;
+
+        let pending = contract.pending_proxy_target().await?.value.unwrap();
+        assert_eq!(pending.target, target);
+        // The target changes only on execution
+        assert_eq!(
+            contract.proxy_target().await?.value,
+            Some(ContractId::zeroed())
+        );
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, _, user) = setup_proxy().await.unwrap();
+
+        // Attempt to propose a target with a non-owner user
+        contract
+            .with_account(&user.wallet)
+            .propose_proxy_target(contract.contract_id().into())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UpgradeAlreadyProposed")]
+    async fn reverts_when_proposed() {
+        let (contract, _, _) = setup_proxy().await.unwrap();
+        contract
+            .propose_proxy_target(contract.contract_id().into())
+            .await
+            .unwrap();
+
+        // Reverts
+        contract
+            .propose_proxy_target(ContractId::zeroed())
+            .await
+            .unwrap();
+    }
+}
@@ -37,4 +37,17 @@ mod revert {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "UpgradeDelayActive")]
+    async fn reverts_when_delay_set() {
+        let (contract, _, _) = setup_proxy().await.unwrap();
+        contract.set_upgrade_delay(3600).await.unwrap();
+
+        // The target is changed by a proposal while a delay is set
+        contract
+            .set_proxy_target(contract.contract_id().into())
+            .await
+            .unwrap();
+    }
 }
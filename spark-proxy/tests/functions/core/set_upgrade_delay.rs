@@ -0,0 +1,69 @@
+use crate::setup::{advance_time, setup_proxy};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn increases_immediately() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+
+        contract.set_upgrade_delay(3600).await?;
+
+        assert_eq!(contract.upgrade_delay().await?.value, 3600);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sets_max_delay() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+
+        contract.set_upgrade_delay(2_592_000).await?;
+
+        assert_eq!(contract.upgrade_delay().await?.value, 2_592_000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn decreases_after_current_delay() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup_proxy().await?;
+        contract.set_upgrade_delay(3600).await?;
+
+        contract.set_upgrade_delay(60).await?;
+        assert_eq!(contract.upgrade_delay().await?.value, 3600);
+
+        advance_time(&owner, 7200).await?;
+        assert_eq!(contract.upgrade_delay().await?.value, 60);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, _, user) = setup_proxy().await.unwrap();
+
+        // Attempt to set the delay with a non-owner user
+        contract
+            .with_account(&user.wallet)
+            .set_upgrade_delay(3600)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UpgradeDelayTooLong")]
+    async fn reverts_when_above_max_delay() {
+        let (contract, _, _) = setup_proxy().await.unwrap();
+
+        // Reverts, 30 days and 1 second
+        contract.set_upgrade_delay(2_592_001).await.unwrap();
+    }
+}
@@ -1,2 +1,4 @@
+mod pending_proxy_target;
 mod proxy_owner;
 mod proxy_target;
+mod upgrade_delay;
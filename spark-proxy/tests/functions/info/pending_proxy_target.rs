@@ -0,0 +1,13 @@
+mod success {
+
+    use crate::setup::setup_proxy;
+
+    #[tokio::test]
+    async fn returns_no_proposal() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+
+        assert_eq!(contract.pending_proxy_target().await?.value, None);
+
+        Ok(())
+    }
+}
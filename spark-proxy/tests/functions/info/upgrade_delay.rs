@@ -0,0 +1,13 @@
+mod success {
+
+    use crate::setup::setup_proxy;
+
+    #[tokio::test]
+    async fn returns_no_delay() -> anyhow::Result<()> {
+        let (contract, _, _) = setup_proxy().await?;
+
+        assert_eq!(contract.upgrade_delay().await?.value, 0);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
+use chrono::{Duration, Utc};
 use fuels::{
+    accounts::ViewOnlyAccount,
     prelude::{launch_custom_provider_and_get_wallets, Address, WalletUnlocked, WalletsConfig},
     types::{ContractId, Identity},
 };
@@ -37,3 +39,12 @@ pub(crate) async fn setup_proxy() -> anyhow::Result<(SparkProxyContract, User, U
 
     Ok((contract, owner, user))
 }
+
+/// Produces a block `seconds` ahead so that the following calls see the later timestamp
+pub(crate) async fn advance_time(user: &User, seconds: i64) -> anyhow::Result<()> {
+    let provider = user.wallet.provider().unwrap();
+    provider
+        .produce_blocks(1, Some(Utc::now() + Duration::seconds(seconds)))
+        .await?;
+    Ok(())
+}
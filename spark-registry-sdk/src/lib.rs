@@ -1,13 +1,20 @@
 use fuels::{
+    accounts::{impersonated_account::ImpersonatedAccount, Account},
     prelude::{
-        abigen, AssetId, Bech32ContractId, Contract, ContractId, LoadConfiguration,
-        StorageConfiguration, TxPolicies, WalletUnlocked,
+        abigen, Address, AssetId, Bech32ContractId, Contract, ContractId, LoadConfiguration,
+        Provider, StorageConfiguration, TxPolicies, WalletUnlocked,
     },
-    programs::{calls::Execution, responses::CallResponse},
-    types::{Bytes32, Identity},
+    programs::{
+        calls::{CallHandler, ContractCall, Execution},
+        responses::CallResponse,
+    },
+    types::{errors::Error as FuelsError, Bytes32, Identity},
 };
 use rand::Rng;
-use std::path::PathBuf;
+use spark_market_sdk::str_version;
+use std::{fmt, path::PathBuf};
+
+pub use spark_market_sdk::VersionPolicy;
 
 abigen!(
     Contract(
@@ -24,12 +31,238 @@ const SPARK_REGISTRY_CONTRACT_BINARY_PATH: &str = "spark-registry/out/release/sp
 const SPARK_REGISTRY_CONTRACT_STORAGE_PATH: &str =
     "spark-registry/out/release/spark-registry-storage_slots.json";
 
-pub struct SparkRegistryContract {
-    instance: SparkRegistry<WalletUnlocked>,
+/// Number of the asset pairs looked up per call
+const PAIRS_PER_CALL: usize = 50;
+
+/// Error returned on the registry connection
+#[derive(Debug)]
+pub enum SparkRegistryError {
+    VersionMismatch { contract: u32, sdk: u32 },
+    Fuels(FuelsError),
 }
 
-impl SparkRegistryContract {
-    pub async fn deploy(owner: WalletUnlocked, version: u32) -> anyhow::Result<Self> {
+impl fmt::Display for SparkRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionMismatch { contract, sdk } => write!(
+                f,
+                "SparkRegistry contract version {} mismatch with SDK version {}",
+                str_version(*contract),
+                str_version(*sdk)
+            ),
+            Self::Fuels(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SparkRegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Fuels(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<FuelsError> for SparkRegistryError {
+    fn from(error: FuelsError) -> Self {
+        Self::Fuels(error)
+    }
+}
+
+pub struct SparkRegistryContract<A = WalletUnlocked> {
+    instance: SparkRegistry<A>,
+}
+
+/// Registry client for the read methods that does not hold any private key
+pub type SparkRegistryReader = SparkRegistryContract<ImpersonatedAccount>;
+
+impl<A: Account> SparkRegistryContract<A> {
+    pub async fn new(contract_id: ContractId, wallet: A) -> Result<Self, SparkRegistryError> {
+        Self::new_with_policy(contract_id, wallet, VersionPolicy::default()).await
+    }
+
+    pub async fn new_with_policy(
+        contract_id: ContractId,
+        wallet: A,
+        policy: VersionPolicy,
+    ) -> Result<Self, SparkRegistryError> {
+        let registry = Self::connect_unchecked(contract_id, wallet);
+        let (_, contract) = registry
+            .instance
+            .methods()
+            .config()
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value;
+        let sdk = SparkRegistryContract::sdk_version();
+        if !policy.is_compatible(contract, sdk) {
+            return Err(SparkRegistryError::VersionMismatch { contract, sdk });
+        }
+        Ok(registry)
+    }
+
+    /// Connects to the registry without the contract version check
+    pub fn connect_unchecked(contract_id: ContractId, wallet: A) -> Self {
+        Self {
+            instance: SparkRegistry::new(contract_id, wallet),
+        }
+    }
+
+    pub fn id(&self) -> Bytes32 {
+        self.instance.contract_id().hash
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        self.instance.contract_id()
+    }
+
+    pub async fn contract_version(&self) -> anyhow::Result<u32> {
+        let (_, version) = self.config().await?.value;
+        Ok(version)
+    }
+
+    pub async fn contract_str_version(&self) -> anyhow::Result<String> {
+        Ok(str_version(self.contract_version().await?))
+    }
+
+    pub async fn owner(&self) -> anyhow::Result<CallResponse<State>> {
+        Ok(self
+            .instance
+            .methods()
+            .owner()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn config(&self) -> anyhow::Result<CallResponse<(Option<Identity>, u32)>> {
+        Ok(self
+            .instance
+            .methods()
+            .config()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn markets(
+        &self,
+        assets: Vec<(AssetId, AssetId)>,
+    ) -> anyhow::Result<CallResponse<Vec<(AssetId, AssetId, Option<ContractId>)>>> {
+        Ok(self
+            .instance
+            .methods()
+            .markets(assets)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn pair_markets(
+        &self,
+        base: AssetId,
+        quote: AssetId,
+    ) -> anyhow::Result<CallResponse<Vec<ContractId>>> {
+        Ok(self
+            .instance
+            .methods()
+            .pair_markets(base, quote)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn markets_count(&self) -> anyhow::Result<CallResponse<u64>> {
+        Ok(self
+            .instance
+            .methods()
+            .markets_count()
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn markets_paged(
+        &self,
+        offset: u64,
+        limit: u64,
+    ) -> anyhow::Result<CallResponse<Vec<MarketInfo>>> {
+        Ok(self
+            .instance
+            .methods()
+            .markets_paged(offset, limit)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    /// Loads every registered market, `page_size` markets per call
+    pub async fn markets_all(&self, page_size: u64) -> anyhow::Result<Vec<MarketInfo>> {
+        if page_size == 0 {
+            anyhow::bail!("Page size must be greater than zero");
+        }
+        let mut markets = Vec::new();
+        loop {
+            let page = self
+                .markets_paged(markets.len() as u64, page_size)
+                .await?
+                .value;
+            let done = (page.len() as u64) < page_size;
+            markets.extend(page);
+            if done {
+                return Ok(markets);
+            }
+        }
+    }
+
+    pub async fn market_info(
+        &self,
+        market: ContractId,
+    ) -> anyhow::Result<CallResponse<Option<MarketInfo>>> {
+        Ok(self
+            .instance
+            .methods()
+            .market_info(market)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    /// Looks up every ordered pair of the assets and returns the registered markets
+    pub async fn markets_by_assets(
+        &self,
+        assets: &[AssetId],
+    ) -> anyhow::Result<Vec<(AssetId, AssetId, ContractId)>> {
+        let pairs = assets
+            .iter()
+            .flat_map(|base| {
+                assets
+                    .iter()
+                    .filter(move |quote| *quote != base)
+                    .map(move |quote| (*base, *quote))
+            })
+            .collect::<Vec<_>>();
+
+        let mut markets = Vec::new();
+        for chunk in pairs.chunks(PAIRS_PER_CALL) {
+            let values = self.markets(chunk.to_vec()).await?.value;
+            markets.extend(
+                values
+                    .into_iter()
+                    .filter_map(|(base, quote, market)| market.map(|market| (base, quote, market))),
+            );
+        }
+        Ok(markets)
+    }
+}
+
+impl SparkRegistryReader {
+    /// Connects to the registry for the read methods with the provider only
+    pub async fn read_only(
+        contract_id: ContractId,
+        provider: Provider,
+    ) -> Result<Self, SparkRegistryError> {
+        let account = ImpersonatedAccount::new(Address::zeroed().into(), Some(provider));
+        Self::new(contract_id, account).await
+    }
+}
+
+impl<A: Account> SparkRegistryContract<A> {
+    pub async fn deploy(owner: A, version: u32) -> anyhow::Result<Self> {
         let mut rng = rand::thread_rng();
         let salt = rng.gen::<[u8; 32]>();
 
@@ -66,64 +299,12 @@ impl SparkRegistryContract {
         })
     }
 
-    pub async fn new(contract_id: ContractId, wallet: WalletUnlocked) -> Self {
-        let _self = Self {
-            instance: SparkRegistry::new(contract_id, wallet),
-        };
-        assert!(
-            _self.contract_version().await.unwrap() & 0xFF0000 == Self::sdk_version() & 0xFF0000,
-            "SparkRegistry contract version mismatch with SDK version"
-        );
-        _self
-    }
-
-    pub fn with_account(&self, account: &WalletUnlocked) -> Self {
-        Self {
+    pub fn with_account<B: Account>(&self, account: &B) -> SparkRegistryContract<B> {
+        SparkRegistryContract {
             instance: self.instance.clone().with_account(account.clone()),
         }
     }
 
-    pub fn id(&self) -> Bytes32 {
-        self.instance.contract_id().hash
-    }
-
-    pub fn contract_id(&self) -> &Bech32ContractId {
-        self.instance.contract_id()
-    }
-
-    pub async fn contract_version(&self) -> anyhow::Result<u32> {
-        let (_, version) = self.config().await?.value;
-        Ok(version)
-    }
-
-    pub async fn contract_str_version(&self) -> anyhow::Result<String> {
-        let version = self.contract_version().await?;
-        Ok(format!(
-            "{}.{}.{}",
-            (version & 0xFF0000) >> 16,
-            (version & 0xFF00) >> 8,
-            version & 0xFF
-        ))
-    }
-
-    pub fn sdk_version() -> u32 {
-        let s_version = Self::sdk_str_version();
-        // Converts "0.1.1" string version to 257u32 (0x000101)
-        let version = s_version.split('.').collect::<Vec<&str>>();
-        let len = version.len();
-        version
-            .iter()
-            .enumerate()
-            .map(|(i, &x)| x.parse::<u32>().unwrap() << (8 * (len - i - 1)))
-            .collect::<Vec<u32>>()
-            .iter()
-            .sum()
-    }
-
-    pub fn sdk_str_version() -> String {
-        env!("CARGO_PKG_VERSION").into()
-    }
-
     async fn market_implementation(&self, market: ContractId) -> ContractId {
         let proxy = SparkProxy::new(market, self.instance.account().clone());
         let result = proxy
@@ -136,89 +317,175 @@ impl SparkRegistryContract {
             Err(_) => market,
         }
     }
+
     pub async fn register_market(&self, market: ContractId) -> anyhow::Result<CallResponse<()>> {
+        Ok(self
+            .register_market_call_handler(market)
+            .await
+            .call()
+            .await?)
+    }
+
+    pub async fn register_market_call_handler(
+        &self,
+        market: ContractId,
+    ) -> CallHandler<A, ContractCall, ()> {
         let implementation = self.market_implementation(market).await;
         let contract_ids = if implementation == market {
             vec![market.into()]
         } else {
             vec![market.into(), implementation.into()]
         };
-        Ok(self
-            .instance
+        self.instance
             .methods()
             .register_market(market)
             .with_contract_ids(&contract_ids)
+    }
+
+    pub async fn unregister_market(&self, market: ContractId) -> anyhow::Result<CallResponse<()>> {
+        Ok(self
+            .unregister_market_call_handler(market)
+            .await
             .call()
             .await?)
     }
 
-    pub async fn unregister_market(&self, market: ContractId) -> anyhow::Result<CallResponse<()>> {
+    pub async fn unregister_market_call_handler(
+        &self,
+        market: ContractId,
+    ) -> CallHandler<A, ContractCall, ()> {
         let implementation = self.market_implementation(market).await;
         let contract_ids = if implementation == market {
             vec![market.into()]
         } else {
             vec![market.into(), implementation.into()]
         };
-        Ok(self
-            .instance
+        self.instance
             .methods()
             .unregister_market(market)
             .with_contract_ids(&contract_ids)
-            .call()
-            .await?)
     }
 
-    pub async fn initialize_ownership(
+    pub async fn set_market_symbols(
         &self,
-        new_owner: Identity,
+        market: ContractId,
+        base_symbol: &str,
+        quote_symbol: &str,
     ) -> anyhow::Result<CallResponse<()>> {
         Ok(self
-            .instance
-            .methods()
-            .initialize_ownership(new_owner)
+            .set_market_symbols_call_handler(market, base_symbol, quote_symbol)
             .call()
             .await?)
     }
 
-    pub async fn transfer_ownership(
+    pub fn set_market_symbols_call_handler(
         &self,
-        new_owner: Identity,
+        market: ContractId,
+        base_symbol: &str,
+        quote_symbol: &str,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_market_symbols(
+            market,
+            base_symbol.to_string(),
+            quote_symbol.to_string(),
+        )
+    }
+
+    pub async fn set_market_status(
+        &self,
+        market: ContractId,
+        status: MarketStatus,
     ) -> anyhow::Result<CallResponse<()>> {
         Ok(self
-            .instance
-            .methods()
-            .transfer_ownership(new_owner)
+            .set_market_status_call_handler(market, status)
             .call()
             .await?)
     }
 
-    pub async fn owner(&self) -> anyhow::Result<CallResponse<State>> {
+    pub fn set_market_status_call_handler(
+        &self,
+        market: ContractId,
+        status: MarketStatus,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_market_status(market, status)
+    }
+
+    pub async fn refresh_market(&self, market: ContractId) -> anyhow::Result<CallResponse<()>> {
         Ok(self
-            .instance
-            .methods()
-            .owner()
-            .simulate(Execution::StateReadOnly)
+            .refresh_market_call_handler(market)
+            .await
+            .call()
             .await?)
     }
 
-    pub async fn config(&self) -> anyhow::Result<CallResponse<(Option<Identity>, u32)>> {
+    pub async fn refresh_market_call_handler(
+        &self,
+        market: ContractId,
+    ) -> CallHandler<A, ContractCall, ()> {
+        let implementation = self.market_implementation(market).await;
+        let contract_ids = if implementation == market {
+            vec![market.into()]
+        } else {
+            vec![market.into(), implementation.into()]
+        };
+        self.instance
+            .methods()
+            .refresh_market(market)
+            .with_contract_ids(&contract_ids)
+    }
+
+    pub async fn set_primary_market(&self, market: ContractId) -> anyhow::Result<CallResponse<()>> {
+        Ok(self.set_primary_market_call_handler(market).call().await?)
+    }
+
+    pub fn set_primary_market_call_handler(
+        &self,
+        market: ContractId,
+    ) -> CallHandler<A, ContractCall, ()> {
+        self.instance.methods().set_primary_market(market)
+    }
+
+    pub async fn initialize_ownership(
+        &self,
+        new_owner: Identity,
+    ) -> anyhow::Result<CallResponse<()>> {
         Ok(self
             .instance
             .methods()
-            .config()
-            .simulate(Execution::StateReadOnly)
+            .initialize_ownership(new_owner)
+            .call()
             .await?)
     }
 
-    pub async fn markets(
+    pub async fn transfer_ownership(
         &self,
-        assets: Vec<(AssetId, AssetId)>,
-    ) -> anyhow::Result<CallResponse<Vec<(AssetId, AssetId, Option<ContractId>)>>> {
+        new_owner: Identity,
+    ) -> anyhow::Result<CallResponse<()>> {
         Ok(self
             .instance
             .methods()
-            .markets(assets)
-            .simulate(Execution::StateReadOnly)
+            .transfer_ownership(new_owner)
+            .call()
             .await?)
     }
 }
+
+impl SparkRegistryContract {
+    pub fn sdk_version() -> u32 {
+        let s_version = Self::sdk_str_version();
+        // Converts "0.1.1" string version to 257u32 (0x000101)
+        let version = s_version.split('.').collect::<Vec<&str>>();
+        let len = version.len();
+        version
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x.parse::<u32>().unwrap() << (8 * (len - i - 1)))
+            .collect::<Vec<u32>>()
+            .iter()
+            .sum()
+    }
+
+    pub fn sdk_str_version() -> String {
+        env!("CARGO_PKG_VERSION").into()
+    }
+}
@@ -0,0 +1,47 @@
+library;
+
+use std::string::String;
+
+pub enum MarketStatus {
+    Active: (),
+    Deprecated: (),
+}
+
+impl core::ops::Eq for MarketStatus {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Active, Self::Active) => true,
+            (Self::Deprecated, Self::Deprecated) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Metadata stored per registered market, the symbols are stored apart as strings
+pub struct MarketMetadata {
+    pub base: AssetId,
+    pub base_decimals: u32,
+    pub quote: AssetId,
+    pub quote_decimals: u32,
+    pub price_decimals: u32,
+    pub status: MarketStatus,
+    pub version: u32,
+    /// Position of the market in the registered market list
+    pub index: u64,
+}
+
+/// Registered market with its metadata
+pub struct MarketInfo {
+    pub market: ContractId,
+    pub base: AssetId,
+    pub base_symbol: String,
+    pub base_decimals: u32,
+    pub quote: AssetId,
+    pub quote_symbol: String,
+    pub quote_decimals: u32,
+    pub price_decimals: u32,
+    pub status: MarketStatus,
+    pub version: u32,
+    /// Whether the market is the primary market of its asset pair
+    pub primary: bool,
+}
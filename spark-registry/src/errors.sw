@@ -3,4 +3,6 @@ library;
 pub enum MarketRegistryError {
     MarketAlreadyRegistered: (),
     MarketNotRegistered: (),
+    MarketConfigAlreadyRegistered: (),
+    MarketAssetsChanged: (),
 }
@@ -1,5 +1,8 @@
 library;
 
+use ::data_structures::MarketStatus;
+use std::string::String;
+
 pub struct MarketRegisterEvent {
     pub base: AssetId,
     pub quote: AssetId,
@@ -11,3 +14,26 @@ pub struct MarketUnregisterEvent {
     pub quote: AssetId,
     pub market: ContractId,
 }
+
+pub struct SetMarketSymbolsEvent {
+    pub market: ContractId,
+    pub base_symbol: String,
+    pub quote_symbol: String,
+}
+
+pub struct SetMarketStatusEvent {
+    pub market: ContractId,
+    pub status: MarketStatus,
+}
+
+pub struct SetPrimaryMarketEvent {
+    pub base: AssetId,
+    pub quote: AssetId,
+    pub market: ContractId,
+}
+
+pub struct RefreshMarketEvent {
+    pub market: ContractId,
+    pub price_decimals: u32,
+    pub version: u32,
+}
@@ -1,12 +1,24 @@
 contract;
 
+mod data_structures;
 mod errors;
 mod events;
 
+use data_structures::*;
 use errors::*;
 use events::*;
 
-use std::hash::{Hash, sha256};
+use std::{
+    hash::{
+        Hash,
+        sha256,
+    },
+    storage::{
+        storage_string::*,
+        storage_vec::*,
+    },
+    string::String,
+};
 use standards::src5::{AccessError, SRC5, State};
 use sway_libs::{
     ownership::{
@@ -22,7 +34,14 @@ configurable {
 }
 
 storage {
+    /// Primary market by asset pair id
     markets: StorageMap<b256, ContractId> = StorageMap {},
+    /// All the markets by asset pair id
+    pair_markets: StorageMap<b256, StorageVec<ContractId>> = StorageMap {},
+    market_list: StorageVec<ContractId> = StorageVec {},
+    market_metadata: StorageMap<ContractId, MarketMetadata> = StorageMap {},
+    market_base_symbols: StorageMap<ContractId, StorageString> = StorageMap {},
+    market_quote_symbols: StorageMap<ContractId, StorageString> = StorageMap {},
 }
 
 abi Ownership {
@@ -35,19 +54,43 @@ abi Ownership {
 
 abi SparkMarketInfoConfig {
     #[storage(read)]
-    fn config() -> (AssetId, u32, AssetId, u32, Identity, u32, u32);
+    fn config() -> (AssetId, u32, AssetId, u32, Option<Identity>, u32, u32);
 }
 
 abi SparkRegistry {
     #[storage(read, write)]
     fn register_market(market: ContractId);
 
-    #[storage(write)]
+    #[storage(read, write)]
     fn unregister_market(market: ContractId);
 
+    #[storage(read, write)]
+    fn set_market_symbols(market: ContractId, base_symbol: String, quote_symbol: String);
+
+    #[storage(read, write)]
+    fn set_market_status(market: ContractId, status: MarketStatus);
+
+    #[storage(read, write)]
+    fn set_primary_market(market: ContractId);
+
+    #[storage(read, write)]
+    fn refresh_market(market: ContractId);
+
     #[storage(read)]
     fn markets(market_assets: Vec<(AssetId, AssetId)>) -> Vec<(AssetId, AssetId, Option<ContractId>)>;
 
+    #[storage(read)]
+    fn pair_markets(base: AssetId, quote: AssetId) -> Vec<ContractId>;
+
+    #[storage(read)]
+    fn markets_count() -> u64;
+
+    #[storage(read)]
+    fn markets_paged(offset: u64, limit: u64) -> Vec<MarketInfo>;
+
+    #[storage(read)]
+    fn market_info(market: ContractId) -> Option<MarketInfo>;
+
     #[storage(read)]
     fn config() -> (Option<Identity>, u32);
 }
@@ -82,8 +125,10 @@ impl SparkRegistry for Contract {
     /// ### Additional Information
     ///
     /// This function allows the contract owner to register a new market. It retrieves the base and quote assets associated with the market,
-    /// generates a unique market ID, and checks if the market is already registered. If the market is not registered, it is stored in the
+    /// generates the market ID of the asset pair, and checks if the market is already registered. If the market is not registered, it is stored in the
     /// contract's storage and a 'MarketRegisterEvent' is logged. The function enforces that only the contract owner can call it.
+    /// The market is appended to the registered market list as active, with the decimals and version read from its config.
+    /// Several markets may be registered for a pair when they differ by version or price decimals, the first one becomes the primary market of the pair.
     ///
     /// ### Arguments
     ///
@@ -92,22 +137,42 @@ impl SparkRegistry for Contract {
     /// ### Reverts
     ///
     /// * When called by non-owner.
-    /// * When a token pair market already registered
+    /// * When the market is already registered
+    /// * When a market of the pair with the same version and price decimals is already registered
     #[storage(read, write)]
     fn register_market(market: ContractId) {
         ownership_only_owner();
 
-        let (base, quote) = market_assets(market);
-        let id = market_id(base, quote);
         require(
             storage
-                .markets
-                .get(id)
+                .market_metadata
+                .get(market)
                 .try_read()
                 .is_none(),
             MarketRegistryError::MarketAlreadyRegistered,
         );
-        storage.markets.insert(id, market);
+        let (base, base_decimals, quote, quote_decimals, _, price_decimals, version) = market_config(market);
+        let id = market_id(base, quote);
+        require_unique_pair_config(id, market, price_decimals, version);
+
+        if storage.markets.get(id).try_read().is_none() {
+            storage.markets.insert(id, market);
+        }
+        storage.pair_markets.get(id).push(market);
+        storage.market_list.push(market);
+        storage.market_metadata.insert(
+            market,
+            MarketMetadata {
+                base,
+                base_decimals,
+                quote,
+                quote_decimals,
+                price_decimals,
+                status: MarketStatus::Active,
+                version,
+                index: storage.market_list.len() - 1,
+            },
+        );
         log(MarketRegisterEvent {
             base: base,
             quote: quote,
@@ -119,9 +184,11 @@ impl SparkRegistry for Contract {
     ///
     /// ### Additional Information
     ///
-    /// This function allows the contract owner to unregister a market. It retrieves the base and quote assets associated with the market,
-    /// generates the market ID, and checks if the market is currently registered. If the market is registered, it is removed from the
-    /// contract's storage and a 'MarketUnregisterEvent' is logged. The function enforces that only the contract owner can call it.
+    /// This function allows the contract owner to unregister a market. It checks if the market is currently registered.
+    /// If the market is registered, it is removed from the contract's storage and a 'MarketUnregisterEvent' is logged.
+    /// The function enforces that only the contract owner can call it.
+    /// The market and its metadata are removed from the registered market list, the last market of the list takes its position.
+    /// When the primary market of a pair is unregistered, the first remaining market of the pair becomes the primary one.
     ///
     /// ### Arguments
     ///
@@ -131,18 +198,17 @@ impl SparkRegistry for Contract {
     ///
     /// * When called by non-owner.
     /// * When a market is not registered
-    #[storage(write)]
+    #[storage(read, write)]
     fn unregister_market(market: ContractId) {
         ownership_only_owner();
 
-        let (base, quote) = market_assets(market);
-        let id = market_id(base, quote);
-        require(
-            storage
-                .markets
-                .remove(id),
-            MarketRegistryError::MarketNotRegistered,
-        );
+        let metadata = storage.market_metadata.get(market).try_read();
+        require(metadata.is_some(), MarketRegistryError::MarketNotRegistered);
+        let metadata = metadata.unwrap();
+        let (base, quote) = (metadata.base, metadata.quote);
+
+        remove_pair_market(base, quote, market);
+        remove_market_metadata(market, metadata.index);
         log(MarketUnregisterEvent {
             base: base,
             quote: quote,
@@ -150,11 +216,160 @@ impl SparkRegistry for Contract {
         });
     }
 
+    /// Sets the base and quote asset symbols of a registered market.
+    ///
+    /// ### Additional Information
+    ///
+    /// Only callable by the contract owner. The symbols are informational, e.g. "ETH" and "USDC", and are returned with the market metadata.
+    ///
+    /// ### Arguments
+    ///
+    /// * `market`: [ContractId] - The 'ContractId' of the registered market.
+    /// * `base_symbol`: [String] - The symbol of the base asset.
+    /// * `quote_symbol`: [String] - The symbol of the quote asset.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When a market is not registered
+    #[storage(read, write)]
+    fn set_market_symbols(market: ContractId, base_symbol: String, quote_symbol: String) {
+        ownership_only_owner();
+
+        require(
+            storage
+                .market_metadata
+                .get(market)
+                .try_read()
+                .is_some(),
+            MarketRegistryError::MarketNotRegistered,
+        );
+        storage.market_base_symbols.get(market).write_slice(base_symbol);
+        storage.market_quote_symbols.get(market).write_slice(quote_symbol);
+        log(SetMarketSymbolsEvent {
+            market,
+            base_symbol,
+            quote_symbol,
+        });
+    }
+
+    /// Sets the status of a registered market.
+    ///
+    /// ### Additional Information
+    ///
+    /// Only callable by the contract owner. A deprecated market stays registered and enumerable so the users can find it and withdraw.
+    ///
+    /// ### Arguments
+    ///
+    /// * `market`: [ContractId] - The 'ContractId' of the registered market.
+    /// * `status`: [MarketStatus] - The new status of the market.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When a market is not registered
+    #[storage(read, write)]
+    fn set_market_status(market: ContractId, status: MarketStatus) {
+        ownership_only_owner();
+
+        let metadata = storage.market_metadata.get(market).try_read();
+        require(metadata.is_some(), MarketRegistryError::MarketNotRegistered);
+        let mut metadata = metadata.unwrap();
+        metadata.status = status;
+        storage.market_metadata.insert(market, metadata);
+        log(SetMarketStatusEvent { market, status });
+    }
+
+    /// Designates a registered market as the primary market of its asset pair.
+    ///
+    /// ### Additional Information
+    ///
+    /// Only callable by the contract owner. The primary market is the one returned by 'markets' for the pair,
+    /// designating a newer market lets the liquidity migrate from an older implementation gradually.
+    ///
+    /// ### Arguments
+    ///
+    /// * `market`: [ContractId] - The 'ContractId' of the registered market.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When a market is not registered
+    #[storage(read, write)]
+    fn set_primary_market(market: ContractId) {
+        ownership_only_owner();
+
+        let metadata = storage.market_metadata.get(market).try_read();
+        require(metadata.is_some(), MarketRegistryError::MarketNotRegistered);
+        let metadata = metadata.unwrap();
+        storage.markets.insert(market_id(metadata.base, metadata.quote), market);
+        log(SetPrimaryMarketEvent {
+            base: metadata.base,
+            quote: metadata.quote,
+            market,
+        });
+    }
+
+    /// Updates the registry metadata of a market from its config.
+    ///
+    /// ### Additional Information
+    ///
+    /// Only callable by the contract owner. The decimals and the version are read again from the market config,
+    /// after the implementation behind a market proxy has been upgraded. The symbols, the status and the primary market are kept.
+    /// A market registered before the registered market list was added is only stored as the primary market of its pair,
+    /// it is appended to the list as active with its metadata and a 'MarketRegisterEvent' is logged.
+    ///
+    /// ### Arguments
+    ///
+    /// * `market`: [ContractId] - The 'ContractId' of the registered market.
+    ///
+    /// ### Reverts
+    ///
+    /// * When called by non-owner.
+    /// * When a market is not registered
+    /// * When the assets of the market changed
+    /// * When another market of the pair with the same version and price decimals is registered
+    #[storage(read, write)]
+    fn refresh_market(market: ContractId) {
+        ownership_only_owner();
+
+        let metadata = storage.market_metadata.get(market).try_read();
+        let (base, base_decimals, quote, quote_decimals, _, price_decimals, version) = market_config(market);
+        let id = market_id(base, quote);
+        match metadata {
+            Some(metadata) => require(
+                base == metadata.base && quote == metadata.quote,
+                MarketRegistryError::MarketAssetsChanged,
+            ),
+            None => require(
+                storage.markets.get(id).try_read() == Some(market),
+                MarketRegistryError::MarketNotRegistered,
+            ),
+        }
+        require_unique_pair_config(id, market, price_decimals, version);
+
+        let mut metadata = match metadata {
+            Some(metadata) => metadata,
+            None => backfill_market(id, market, base, quote),
+        };
+
+        metadata.base_decimals = base_decimals;
+        metadata.quote_decimals = quote_decimals;
+        metadata.price_decimals = price_decimals;
+        metadata.version = version;
+        storage.market_metadata.insert(market, metadata);
+        log(RefreshMarketEvent {
+            market,
+            price_decimals,
+            version,
+        });
+    }
+
     /// Retrieves the contract IDs of markets for a given list of base and quote asset pairs.
     ///
     /// ### Additional Information
     ///
-    /// This function takes a list of asset pairs and returns a vector containing each pair along with the corresponding market's contract ID if it is registered.
+    /// This function takes a list of asset pairs and returns a vector containing each pair along with the contract ID of its primary market if one is registered.
     /// If a market is not registered, 'None' is returned for the contract ID.
     ///
     /// ### Arguments
@@ -177,6 +392,76 @@ impl SparkRegistry for Contract {
         markets
     }
 
+    /// Retrieves the contract IDs of all the markets registered for an asset pair.
+    ///
+    /// ### Arguments
+    ///
+    /// * `base`: [AssetId] - The base asset of the pair.
+    /// * `quote`: [AssetId] - The quote asset of the pair.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<ContractId>] - The markets of the pair, empty when none is registered.
+    #[storage(read)]
+    fn pair_markets(base: AssetId, quote: AssetId) -> Vec<ContractId> {
+        storage.pair_markets.get(market_id(base, quote)).load_vec()
+    }
+
+    /// Retrieves the number of registered markets.
+    ///
+    /// ### Returns
+    ///
+    /// * [u64] - The length of the registered market list.
+    #[storage(read)]
+    fn markets_count() -> u64 {
+        storage.market_list.len()
+    }
+
+    /// Retrieves a page of the registered markets with their metadata.
+    ///
+    /// ### Additional Information
+    ///
+    /// Unregistering a market moves the last market of the list to its position, so the pages are not stable across unregistrations.
+    ///
+    /// ### Arguments
+    ///
+    /// * `offset`: [u64] - The position of the first market in the registered market list.
+    /// * `limit`: [u64] - The maximum number of markets to return.
+    ///
+    /// ### Returns
+    ///
+    /// * [Vec<MarketInfo>] - The markets from 'offset', empty when 'offset' is past the end of the list.
+    #[storage(read)]
+    fn markets_paged(offset: u64, limit: u64) -> Vec<MarketInfo> {
+        let len = storage.market_list.len();
+        let mut markets = Vec::new();
+        if offset >= len {
+            return markets;
+        }
+        let end = offset + min(limit, len - offset);
+        let mut idx = offset;
+        while idx < end {
+            let market = storage.market_list.get(idx).unwrap().read();
+            markets.push(read_market_info(market).unwrap());
+            idx += 1;
+        }
+        markets
+    }
+
+    /// Retrieves the metadata of a registered market.
+    ///
+    /// ### Arguments
+    ///
+    /// * `market`: [ContractId] - The 'ContractId' of the market.
+    ///
+    /// ### Returns
+    ///
+    /// * [Option<MarketInfo>] - The market metadata, 'None' if the market is not registered.
+    #[storage(read)]
+    fn market_info(market: ContractId) -> Option<MarketInfo> {
+        read_market_info(market)
+    }
+
     /// Retrieves the contract's configuration details, including the owner address and version number.
     ///
     /// ### Additional Information
@@ -192,11 +477,119 @@ impl SparkRegistry for Contract {
     }
 }
 
-fn market_assets(market: ContractId) -> (AssetId, AssetId) {
-    let (base, _, quote, _, _, _, _) = abi(SparkMarketInfoConfig, market.into()).config();
-    (base, quote)
+fn market_config(market: ContractId) -> (AssetId, u32, AssetId, u32, Option<Identity>, u32, u32) {
+    abi(SparkMarketInfoConfig, market.into()).config()
 }
 
 fn market_id(base: AssetId, quote: AssetId) -> b256 {
     sha256((base, quote))
 }
+
+fn min(a: u64, b: u64) -> u64 {
+    if a < b { a } else { b }
+}
+
+/// Markets of a pair are told apart by their version or price decimals
+#[storage(read)]
+fn require_unique_pair_config(id: b256, market: ContractId, price_decimals: u32, version: u32) {
+    let pair_markets = storage.pair_markets.get(id);
+    let mut idx = 0;
+    while idx < pair_markets.len() {
+        let other = pair_markets.get(idx).unwrap().read();
+        let metadata = storage.market_metadata.get(other).read();
+        require(
+            other == market || metadata.version != version || metadata.price_decimals != price_decimals,
+            MarketRegistryError::MarketConfigAlreadyRegistered,
+        );
+        idx += 1;
+    }
+}
+
+/// Appends a market stored only as the primary market of its pair to the pair markets and the registered market list
+#[storage(read, write)]
+fn backfill_market(id: b256, market: ContractId, base: AssetId, quote: AssetId) -> MarketMetadata {
+    storage.pair_markets.get(id).push(market);
+    storage.market_list.push(market);
+    log(MarketRegisterEvent {
+        base: base,
+        quote: quote,
+        market: market,
+    });
+    MarketMetadata {
+        base,
+        base_decimals: 0,
+        quote,
+        quote_decimals: 0,
+        price_decimals: 0,
+        status: MarketStatus::Active,
+        version: 0,
+        index: storage.market_list.len() - 1,
+    }
+}
+
+#[storage(read)]
+fn read_market_info(market: ContractId) -> Option<MarketInfo> {
+    let metadata = match storage.market_metadata.get(market).try_read() {
+        Some(metadata) => metadata,
+        None => return None,
+    };
+    Some(MarketInfo {
+        market,
+        base: metadata.base,
+        base_symbol: storage.market_base_symbols.get(market).read_slice().unwrap_or(String::new()),
+        base_decimals: metadata.base_decimals,
+        quote: metadata.quote,
+        quote_symbol: storage.market_quote_symbols.get(market).read_slice().unwrap_or(String::new()),
+        quote_decimals: metadata.quote_decimals,
+        price_decimals: metadata.price_decimals,
+        status: metadata.status,
+        version: metadata.version,
+        primary: storage.markets.get(market_id(metadata.base, metadata.quote)).try_read() == Some(market),
+    })
+}
+
+#[storage(read, write)]
+fn remove_pair_market(base: AssetId, quote: AssetId, market: ContractId) {
+    let id = market_id(base, quote);
+    let pair_markets = storage.pair_markets.get(id);
+    let mut idx = 0;
+    while pair_markets.get(idx).unwrap().read() != market {
+        idx += 1;
+    }
+    let _ = storage.pair_markets.get(id).swap_remove(idx);
+
+    if storage.markets.get(id).read() != market {
+        return;
+    }
+    match storage.pair_markets.get(id).first() {
+        Some(primary) => {
+            let primary = primary.read();
+            storage.markets.insert(id, primary);
+            log(SetPrimaryMarketEvent {
+                base,
+                quote,
+                market: primary,
+            });
+        }
+        None => {
+            let _ = storage.markets.remove(id);
+        }
+    }
+}
+
+#[storage(read, write)]
+fn remove_market_metadata(market: ContractId, index: u64) {
+    let _ = storage.market_metadata.remove(market);
+    let _ = storage.market_base_symbols.get(market).clear();
+    let _ = storage.market_quote_symbols.get(market).clear();
+
+    // The last market takes the position of the removed one
+    let last = storage.market_list.len() - 1;
+    if index != last {
+        let moved = storage.market_list.get(last).unwrap().read();
+        let mut metadata = storage.market_metadata.get(moved).read();
+        metadata.index = index;
+        storage.market_metadata.insert(moved, metadata);
+    }
+    let _ = storage.market_list.swap_remove(index);
+}
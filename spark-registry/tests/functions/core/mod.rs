@@ -1,4 +1,8 @@
 mod initialize_ownership;
+mod refresh_market;
 mod register_market;
+mod set_market_status;
+mod set_market_symbols;
+mod set_primary_market;
 mod transfer_ownership;
 mod unregister_market;
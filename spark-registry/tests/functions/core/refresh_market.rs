@@ -0,0 +1,233 @@
+mod success {
+
+    use crate::setup::{
+        deploy_baseline_market, deploy_baseline_registry, deploy_market, random_asset_id, setup,
+    };
+    use fuels::{
+        programs::calls::Execution,
+        types::{ContractId, Identity},
+    };
+    use spark_market_sdk::{MarketSettings, ProtocolFee, SparkMarketContract};
+    use spark_proxy_sdk::SparkProxyContract;
+    use spark_registry_sdk::{
+        MarketRegisterEvent, MarketStatus, SparkRegistry, SparkRegistryContract,
+    };
+
+    #[tokio::test]
+    async fn updates_upgraded_market() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+
+        let implementation = SparkMarketContract::deploy(
+            base_asset,
+            1,
+            quote_asset,
+            1,
+            admin.wallet.clone(),
+            9,
+            0xFAFBFC,
+        )
+        .await?;
+        let proxy =
+            SparkProxyContract::deploy(implementation.contract_id().into(), admin.wallet.clone())
+                .await?;
+        let market: ContractId = proxy.contract_id().into();
+        contract.register_market(market).await?;
+        contract.set_market_symbols(market, "ETH", "USDC").await?;
+
+        let next_implementation = SparkMarketContract::deploy(
+            base_asset,
+            1,
+            quote_asset,
+            1,
+            admin.wallet.clone(),
+            9,
+            0xFAFBFD,
+        )
+        .await?;
+        proxy
+            .set_proxy_target(next_implementation.contract_id().into())
+            .await?;
+
+        contract.refresh_market(market).await?;
+
+        let info = contract.market_info(market).await?.value.unwrap();
+        assert_eq!(info.version, 0xFAFBFD);
+        // The owner settings of the registry are kept
+        assert_eq!(info.base_symbol, "ETH");
+        assert_eq!(info.quote_symbol, "USDC");
+        assert!(info.primary);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn updates_market_migrated_from_baseline_build() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+
+        let implementation =
+            deploy_baseline_market(&admin, base_asset, quote_asset, 0xFAFBFC).await?;
+        let proxy = SparkProxyContract::deploy(implementation, admin.wallet.clone()).await?;
+        let market_id: ContractId = proxy.contract_id().into();
+        let market =
+            SparkMarketContract::connect_unchecked(market_id, admin.wallet.clone()).await?;
+        let _ = market
+            .initialize_ownership(Identity::from(admin.address()))
+            .await?;
+        let _ = market.set_matcher_fee(1_000).await?;
+        let _ = market
+            .set_protocol_fee(vec![ProtocolFee {
+                maker_fee: 10,
+                taker_fee: 15,
+                volume_threshold: 0,
+            }])
+            .await?;
+        let _ = market.set_min_order_size(100).await?;
+        contract.register_market(market_id).await?;
+
+        // The getters of the settings added since the baseline are skipped
+        let settings = MarketSettings::read(&market).await?;
+        assert_eq!(settings.matcher_fee, 1_000);
+        assert_eq!(settings.protocol_fee.len(), 1);
+        assert_eq!(settings.min_order_size, 100);
+        assert_eq!(settings.rolling_volume, None);
+        assert_eq!(settings.maker_rebate, None);
+
+        let next_implementation = SparkMarketContract::deploy(
+            base_asset,
+            1,
+            quote_asset,
+            1,
+            admin.wallet.clone(),
+            9,
+            0xFAFBFD,
+        )
+        .await?;
+        proxy
+            .set_proxy_target(next_implementation.contract_id().into())
+            .await?;
+
+        let market =
+            SparkMarketContract::connect_unchecked(market_id, admin.wallet.clone()).await?;
+        let _ = settings.replay(&market).await?;
+        assert!(settings.is_kept_by(&MarketSettings::read(&market).await?));
+
+        contract.refresh_market(market_id).await?;
+
+        let info = contract.market_info(market_id).await?.value.unwrap();
+        assert_eq!(info.version, 0xFAFBFD);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn backfills_market_registered_before_market_list() -> anyhow::Result<()> {
+        let (_, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market(&admin, base_asset, quote_asset).await?;
+
+        // The baseline registry stores the market as the market of its pair only
+        let (proxy, baseline) = deploy_baseline_registry(&admin).await?;
+        let registry = SparkRegistry::new(proxy.contract_id().clone(), admin.wallet.clone());
+        let _ = registry
+            .methods()
+            .register_market(market)
+            .with_contract_ids(&[baseline.into(), market.into()])
+            .call()
+            .await?;
+
+        let implementation: ContractId =
+            SparkRegistryContract::deploy(admin.wallet.clone(), 0xFAFBFC)
+                .await?
+                .contract_id()
+                .into();
+        proxy.set_proxy_target(implementation).await?;
+
+        let info = registry
+            .methods()
+            .market_info(market)
+            .with_contract_ids(&[implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value;
+        assert!(info.is_none());
+
+        let response = registry
+            .methods()
+            .refresh_market(market)
+            .with_contract_ids(&[implementation.into(), market.into()])
+            .call()
+            .await?;
+
+        let log = response
+            .decode_logs_with_type::<MarketRegisterEvent>()
+            .unwrap();
+        assert_eq!(
+            *log.first().unwrap(),
+            MarketRegisterEvent {
+                base: base_asset,
+                quote: quote_asset,
+                market,
+            }
+        );
+        let info = registry
+            .methods()
+            .market_info(market)
+            .with_contract_ids(&[implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value
+            .unwrap();
+        assert_eq!(info.base_decimals, 1);
+        assert_eq!(info.quote_decimals, 1);
+        assert_eq!(info.price_decimals, 9);
+        assert_eq!(info.version, 0xFAFBFC);
+        assert_eq!(info.status, MarketStatus::Active);
+        assert!(info.primary);
+        let count = registry
+            .methods()
+            .markets_count()
+            .with_contract_ids(&[implementation.into()])
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value;
+        assert_eq!(count, 1);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, admin, user) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+        contract.register_market(market).await.unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .refresh_market(market)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MarketNotRegistered")]
+    async fn reverts_when_not_registered() {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+
+        // Reverts
+        contract.refresh_market(market).await.unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 mod success {
 
-    use crate::setup::{random_asset_id, setup};
+    use crate::setup::{deploy_market_version, random_asset_id, setup};
     use fuels::types::ContractId;
     use spark_market_sdk::SparkMarketContract;
 
@@ -35,11 +35,45 @@ mod success {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn registers_several_markets_per_pair() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFC).await?;
+        let next_market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFD).await?;
+
+        contract.register_market(market).await?;
+        contract.register_market(next_market).await?;
+
+        // The first market of the pair stays the primary one
+        assert_eq!(
+            contract
+                .markets(vec![(base_asset, quote_asset)])
+                .await?
+                .value,
+            vec![(base_asset, quote_asset, Some(market))]
+        );
+        assert_eq!(
+            contract.pair_markets(base_asset, quote_asset).await?.value,
+            vec![market, next_market]
+        );
+        assert!(
+            !contract
+                .market_info(next_market)
+                .await?
+                .value
+                .unwrap()
+                .primary
+        );
+        Ok(())
+    }
 }
 
 mod revert {
 
-    use crate::setup::{random_asset_id, setup};
+    use crate::setup::{deploy_market, random_asset_id, setup};
     use fuels::types::ContractId;
     use spark_market_sdk::SparkMarketContract;
 
@@ -107,4 +141,22 @@ mod revert {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "MarketConfigAlreadyRegistered")]
+    async fn reverts_when_pair_config_registered() {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market(&admin, base_asset, quote_asset)
+            .await
+            .unwrap();
+        let same_market = deploy_market(&admin, base_asset, quote_asset)
+            .await
+            .unwrap();
+        contract.register_market(market).await.unwrap();
+
+        // Reverts
+        contract.register_market(same_market).await.unwrap();
+    }
 }
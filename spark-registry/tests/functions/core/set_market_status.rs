@@ -0,0 +1,67 @@
+mod success {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+    use spark_registry_sdk::MarketStatus;
+
+    #[tokio::test]
+    async fn deprecates_market() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21)).await?;
+        contract.register_market(market).await?;
+
+        contract
+            .set_market_status(market, MarketStatus::Deprecated)
+            .await?;
+
+        let info = contract.market_info(market).await?.value.unwrap();
+        assert_eq!(info.status, MarketStatus::Deprecated);
+        // A deprecated market stays registered
+        assert_eq!(contract.markets_count().await?.value, 1);
+
+        contract
+            .set_market_status(market, MarketStatus::Active)
+            .await?;
+
+        let info = contract.market_info(market).await?.value.unwrap();
+        assert_eq!(info.status, MarketStatus::Active);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+    use spark_registry_sdk::MarketStatus;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, admin, user) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+        contract.register_market(market).await.unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .set_market_status(market, MarketStatus::Deprecated)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MarketNotRegistered")]
+    async fn reverts_when_not_registered() {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+
+        // Reverts
+        contract
+            .set_market_status(market, MarketStatus::Deprecated)
+            .await
+            .unwrap();
+    }
+}
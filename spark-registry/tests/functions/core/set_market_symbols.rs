@@ -0,0 +1,70 @@
+mod success {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+
+    #[tokio::test]
+    async fn succeeds_for_admin() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21)).await?;
+        contract.register_market(market).await?;
+
+        contract.set_market_symbols(market, "ETH", "USDC").await?;
+
+        let info = contract.market_info(market).await?.value.unwrap();
+        assert_eq!(info.base_symbol, "ETH");
+        assert_eq!(info.quote_symbol, "USDC");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn overwrites_symbols() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21)).await?;
+        contract.register_market(market).await?;
+
+        contract.set_market_symbols(market, "WETH", "USDC").await?;
+        contract.set_market_symbols(market, "ETH", "USDT").await?;
+
+        let info = contract.market_info(market).await?.value.unwrap();
+        assert_eq!(info.base_symbol, "ETH");
+        assert_eq!(info.quote_symbol, "USDT");
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, admin, user) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+        contract.register_market(market).await.unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .set_market_symbols(market, "ETH", "USDC")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MarketNotRegistered")]
+    async fn reverts_when_not_registered() {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+
+        // Reverts
+        contract
+            .set_market_symbols(market, "ETH", "USDC")
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+mod success {
+
+    use crate::setup::{deploy_market_version, random_asset_id, setup};
+
+    #[tokio::test]
+    async fn sets_primary_market() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFC).await?;
+        let next_market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFD).await?;
+        contract.register_market(market).await?;
+        contract.register_market(next_market).await?;
+
+        contract.set_primary_market(next_market).await?;
+
+        assert_eq!(
+            contract
+                .markets(vec![(base_asset, quote_asset)])
+                .await?
+                .value,
+            vec![(base_asset, quote_asset, Some(next_market))]
+        );
+        assert!(!contract.market_info(market).await?.value.unwrap().primary);
+        assert!(
+            contract
+                .market_info(next_market)
+                .await?
+                .value
+                .unwrap()
+                .primary
+        );
+        // Both markets stay registered
+        assert_eq!(contract.markets_count().await?.value, 2);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn reverts_when_non_owner() {
+        let (contract, admin, user) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+        contract.register_market(market).await.unwrap();
+
+        // Reverts
+        contract
+            .with_account(&user.wallet)
+            .set_primary_market(market)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MarketNotRegistered")]
+    async fn reverts_when_not_registered() {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21))
+            .await
+            .unwrap();
+
+        // Reverts
+        contract.set_primary_market(market).await.unwrap();
+    }
+}
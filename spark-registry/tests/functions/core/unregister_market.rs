@@ -1,6 +1,6 @@
 mod success {
 
-    use crate::setup::{random_asset_id, setup};
+    use crate::setup::{deploy_market_version, random_asset_id, setup};
     use fuels::types::ContractId;
     use spark_market_sdk::SparkMarketContract;
 
@@ -42,6 +42,32 @@ mod success {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn promotes_next_primary_market() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFC).await?;
+        let next_market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFD).await?;
+        contract.register_market(market).await?;
+        contract.register_market(next_market).await?;
+
+        contract.unregister_market(market).await?;
+
+        assert_eq!(
+            contract
+                .markets(vec![(base_asset, quote_asset)])
+                .await?
+                .value,
+            vec![(base_asset, quote_asset, Some(next_market))]
+        );
+        assert_eq!(
+            contract.pair_markets(base_asset, quote_asset).await?.value,
+            vec![next_market]
+        );
+        Ok(())
+    }
 }
 
 mod revert {
@@ -0,0 +1,43 @@
+mod success {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+    use spark_registry_sdk::{MarketInfo, MarketStatus};
+
+    #[tokio::test]
+    async fn returns_none() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market = deploy_market(&admin, random_asset_id(20), random_asset_id(21)).await?;
+        assert_eq!(contract.market_info(market).await?.value, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_market_metadata() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market(&admin, base_asset, quote_asset).await?;
+        contract.register_market(market).await?;
+
+        assert_eq!(
+            contract.market_info(market).await?.value,
+            Some(MarketInfo {
+                market,
+                base: base_asset,
+                base_symbol: String::new(),
+                base_decimals: 1,
+                quote: quote_asset,
+                quote_symbol: String::new(),
+                quote_decimals: 1,
+                price_decimals: 9,
+                status: MarketStatus::Active,
+                version: 0xFAFBFC,
+                primary: true,
+            })
+        );
+
+        contract.unregister_market(market).await?;
+        assert_eq!(contract.market_info(market).await?.value, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+mod success {
+
+    use crate::setup::{random_asset_id, setup};
+    use fuels::types::ContractId;
+    use spark_market_sdk::SparkMarketContract;
+
+    #[tokio::test]
+    async fn returns_registered_pairs() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let asset0 = random_asset_id(20);
+        let asset1 = random_asset_id(21);
+        let asset2 = random_asset_id(22);
+
+        let mut expected = Vec::new();
+        for (base_asset, quote_asset) in [(asset0, asset1), (asset2, asset1)] {
+            let market = SparkMarketContract::deploy(
+                base_asset,
+                1,
+                quote_asset,
+                1,
+                admin.wallet.clone(),
+                9,
+                0xFAFBFC,
+            )
+            .await?;
+            let contract_id: ContractId = market.contract_id().into();
+            contract.register_market(contract_id).await?;
+            expected.push((base_asset, quote_asset, contract_id));
+        }
+
+        assert_eq!(
+            contract
+                .markets_by_assets(&[asset0, asset1, asset2])
+                .await?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_empty_when_not_registered() -> anyhow::Result<()> {
+        let (contract, _, _) = setup().await.unwrap();
+        assert!(contract
+            .markets_by_assets(&[random_asset_id(20), random_asset_id(21)])
+            .await?
+            .is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+mod success {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+
+    #[tokio::test]
+    async fn returns_zero() -> anyhow::Result<()> {
+        let (contract, _, _) = setup().await.unwrap();
+        assert_eq!(contract.markets_count().await?.value, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn counts_registered_markets() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let market0 = deploy_market(&admin, random_asset_id(20), random_asset_id(21)).await?;
+        let market1 = deploy_market(&admin, random_asset_id(22), random_asset_id(21)).await?;
+
+        contract.register_market(market0).await?;
+        contract.register_market(market1).await?;
+        assert_eq!(contract.markets_count().await?.value, 2);
+
+        contract.unregister_market(market0).await?;
+        assert_eq!(contract.markets_count().await?.value, 1);
+        Ok(())
+    }
+}
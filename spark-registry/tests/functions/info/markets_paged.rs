@@ -0,0 +1,79 @@
+mod success {
+
+    use crate::setup::{deploy_market, random_asset_id, setup};
+    use fuels::types::ContractId;
+    use spark_registry_sdk::MarketInfo;
+
+    fn ids(markets: &[MarketInfo]) -> Vec<ContractId> {
+        markets.iter().map(|info| info.market).collect()
+    }
+
+    #[tokio::test]
+    async fn returns_empty() -> anyhow::Result<()> {
+        let (contract, _, _) = setup().await.unwrap();
+        assert!(contract.markets_paged(0, 10).await?.value.is_empty());
+        assert!(contract.markets_all(10).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_pages() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let quote_asset = random_asset_id(30);
+        let mut markets = Vec::new();
+        for base in 20..23 {
+            let market = deploy_market(&admin, random_asset_id(base), quote_asset).await?;
+            contract.register_market(market).await?;
+            markets.push(market);
+        }
+
+        assert_eq!(
+            ids(&contract.markets_paged(0, 2).await?.value),
+            markets[0..2].to_vec()
+        );
+        assert_eq!(
+            ids(&contract.markets_paged(2, 2).await?.value),
+            markets[2..].to_vec()
+        );
+        assert!(contract.markets_paged(3, 2).await?.value.is_empty());
+        assert_eq!(ids(&contract.markets_all(2).await?), markets);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn moves_last_market_on_unregister() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let quote_asset = random_asset_id(30);
+        let mut markets = Vec::new();
+        for base in 20..23 {
+            let market = deploy_market(&admin, random_asset_id(base), quote_asset).await?;
+            contract.register_market(market).await?;
+            markets.push(market);
+        }
+
+        contract.unregister_market(markets[0]).await?;
+        assert_eq!(
+            ids(&contract.markets_all(10).await?),
+            vec![markets[2], markets[1]]
+        );
+
+        // The moved market is still removable from its new position
+        contract.unregister_market(markets[2]).await?;
+        assert_eq!(ids(&contract.markets_all(10).await?), vec![markets[1]]);
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use crate::setup::setup;
+
+    #[tokio::test]
+    #[should_panic(expected = "Page size must be greater than zero")]
+    async fn when_page_size_is_zero() {
+        let (contract, _, _) = setup().await.unwrap();
+
+        // Revert
+        contract.markets_all(0).await.unwrap();
+    }
+}
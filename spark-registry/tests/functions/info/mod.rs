@@ -1,2 +1,7 @@
 mod config;
+mod market_info;
 mod markets;
+mod markets_by_assets;
+mod markets_count;
+mod markets_paged;
+mod pair_markets;
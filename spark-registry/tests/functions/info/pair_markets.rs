@@ -0,0 +1,41 @@
+mod success {
+
+    use crate::setup::{deploy_market_version, random_asset_id, setup};
+
+    #[tokio::test]
+    async fn returns_empty() -> anyhow::Result<()> {
+        let (contract, _, _) = setup().await.unwrap();
+        assert!(contract
+            .pair_markets(random_asset_id(20), random_asset_id(21))
+            .await?
+            .value
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_markets_of_pair() -> anyhow::Result<()> {
+        let (contract, admin, _) = setup().await.unwrap();
+        let base_asset = random_asset_id(20);
+        let quote_asset = random_asset_id(21);
+        let market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFC).await?;
+        let next_market = deploy_market_version(&admin, base_asset, quote_asset, 0xFAFBFD).await?;
+        let other_market =
+            deploy_market_version(&admin, base_asset, random_asset_id(22), 0xFAFBFC).await?;
+        contract.register_market(market).await?;
+        contract.register_market(next_market).await?;
+        contract.register_market(other_market).await?;
+
+        assert_eq!(
+            contract.pair_markets(base_asset, quote_asset).await?.value,
+            vec![market, next_market]
+        );
+        // The pair is ordered
+        assert!(contract
+            .pair_markets(quote_asset, base_asset)
+            .await?
+            .value
+            .is_empty());
+        Ok(())
+    }
+}
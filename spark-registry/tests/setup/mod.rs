@@ -1,8 +1,20 @@
-use fuels::prelude::{
-    launch_custom_provider_and_get_wallets, Address, AssetConfig, AssetId, WalletUnlocked,
-    WalletsConfig,
+use fuels::{
+    core::{codec::ABIEncoder, traits::Tokenizable, Configurables},
+    prelude::{
+        launch_custom_provider_and_get_wallets, Address, AssetConfig, AssetId, Contract,
+        LoadConfiguration, StorageConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
+    },
+    types::{ContractId, Identity, Token},
 };
-use spark_registry_sdk::SparkRegistryContract;
+use serde_json::Value;
+use spark_market_sdk::SparkMarketContract;
+use spark_proxy_sdk::SparkProxyContract;
+use spark_registry_sdk::{SparkRegistry, SparkRegistryContract};
+use std::{fs, path::PathBuf};
+
+/// Build outputs of the market and the registry released before the registry metadata and the
+/// fee tier settings, deployed by the tests migrating from them
+const BASELINE_PATH: &str = "tests/artifacts";
 
 pub(crate) struct User {
     pub(crate) wallet: WalletUnlocked,
@@ -18,6 +30,107 @@ pub(crate) fn random_asset_id(random: u8) -> AssetId {
     AssetId::new([random; 32])
 }
 
+/// Deploys a market of the pair with 1 base and 1 quote decimals
+pub(crate) async fn deploy_market(
+    owner: &User,
+    base_asset: AssetId,
+    quote_asset: AssetId,
+) -> anyhow::Result<ContractId> {
+    deploy_market_version(owner, base_asset, quote_asset, 0xFAFBFC).await
+}
+
+/// Deploys a market of the pair with the given version, the markets of a pair differ by version
+pub(crate) async fn deploy_market_version(
+    owner: &User,
+    base_asset: AssetId,
+    quote_asset: AssetId,
+    version: u32,
+) -> anyhow::Result<ContractId> {
+    let market = SparkMarketContract::deploy(
+        base_asset,
+        1,
+        quote_asset,
+        1,
+        owner.wallet.clone(),
+        9,
+        version,
+    )
+    .await?;
+    Ok(market.contract_id().into())
+}
+
+/// Deploys the baseline market build of the pair with 1 base and 1 quote decimals
+pub(crate) async fn deploy_baseline_market(
+    owner: &User,
+    base_asset: AssetId,
+    quote_asset: AssetId,
+    version: u32,
+) -> anyhow::Result<ContractId> {
+    deploy_baseline(owner, "spark-market", |name| {
+        Ok(match name {
+            "BASE_ASSET" => base_asset.into_token(),
+            "QUOTE_ASSET" => quote_asset.into_token(),
+            "BASE_ASSET_DECIMALS" | "QUOTE_ASSET_DECIMALS" => 1u32.into_token(),
+            "PRICE_DECIMALS" => 9u32.into_token(),
+            "VERSION" => version.into_token(),
+            name => anyhow::bail!("Unknown baseline configurable {}", name),
+        })
+    })
+    .await
+}
+
+/// Deploys the baseline registry build behind a proxy owned by the owner, returns the proxy
+/// and the baseline implementation
+pub(crate) async fn deploy_baseline_registry(
+    owner: &User,
+) -> anyhow::Result<(SparkProxyContract, ContractId)> {
+    let implementation = deploy_baseline(owner, "spark-registry", |name| match name {
+        "VERSION" => Ok(0xFAFBFCu32.into_token()),
+        name => anyhow::bail!("Unknown baseline configurable {}", name),
+    })
+    .await?;
+    let proxy = SparkProxyContract::deploy(implementation, owner.wallet.clone()).await?;
+    let _ = SparkRegistry::new(proxy.contract_id().clone(), owner.wallet.clone())
+        .methods()
+        .initialize_ownership(Identity::from(owner.address()))
+        .with_contract_ids(&[implementation.into()])
+        .call()
+        .await?;
+    Ok((proxy, implementation))
+}
+
+/// Deploys a baseline build from `BASELINE_PATH`, the configurables are encoded at the offsets
+/// of the baseline ABI
+async fn deploy_baseline(
+    owner: &User,
+    contract: &str,
+    configurable: impl Fn(&str) -> anyhow::Result<Token>,
+) -> anyhow::Result<ContractId> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(BASELINE_PATH)
+        .join(format!("{}-baseline", contract));
+    let abi: Value = serde_json::from_str(&fs::read_to_string(
+        dir.join(format!("{}-abi.json", contract)),
+    )?)?;
+
+    let mut offsets_with_data = Vec::new();
+    for entry in abi["configurables"].as_array().unwrap() {
+        let token = configurable(entry["name"].as_str().unwrap())?;
+        let offset = entry["offset"].as_u64().unwrap();
+        offsets_with_data.push((offset, ABIEncoder::default().encode(&[token])?));
+    }
+
+    let storage_configuration = StorageConfiguration::default()
+        .add_slot_overrides_from_file(dir.join(format!("{}-storage_slots.json", contract)))?;
+    let configuration = LoadConfiguration::default()
+        .with_storage_configuration(storage_configuration)
+        .with_configurables(Configurables::new(offsets_with_data));
+    let contract_id = Contract::load_from(dir.join(format!("{}.bin", contract)), configuration)?
+        .deploy(&owner.wallet, TxPolicies::default())
+        .await?;
+    Ok(contract_id.into())
+}
+
 pub(crate) async fn setup() -> anyhow::Result<(SparkRegistryContract, User, User)> {
     let number_of_wallets = 2;
     let coins_per_wallet = 1;